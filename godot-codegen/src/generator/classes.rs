@@ -7,7 +7,9 @@
 use crate::context::{Context, NotificationEnum};
 use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions};
 use crate::generator::method_tables::MethodTableKey;
-use crate::generator::{constants, docs, enums, functions_common, notifications, virtual_traits};
+use crate::generator::{
+    constants, docs, enums, functions_common, notifications, signals, virtual_traits,
+};
 use crate::models::domain::{
     ApiView, Class, ClassLike, ClassMethod, ExtensionApi, FnDirection, FnQualifier, Function,
     ModName, TyName,
@@ -125,6 +127,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
     let constants = constants::make_constants(&class.constants);
     let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", class_name.rust_ty);
     let deref_impl = make_deref_impl(class_name, &base_ty);
+    let signal_collection = signals::make_signal_collection(class, &cfg_attributes);
 
     let all_bases = ctx.inheritance_tree().collect_all_bases(class_name);
     let (notification_enum, notification_enum_name) =
@@ -152,6 +155,9 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
         view,
     );
 
+    let virtual_method_names = virtual_traits::make_virtual_method_names(class, &all_bases, view);
+    let constant_names = constants::make_constant_names(class, &all_bases, view);
+
     // notify() and notify_reversed() are added after other methods, to list others first in docs.
     let notify_methods = notifications::make_notify_methods(class_name, ctx);
 
@@ -230,6 +236,12 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                 type Declarer = crate::obj::bounds::DeclEngine;
                 type Exportable = crate::obj::bounds::#is_exportable;
             }
+            impl crate::private::EngineVirtualMethods for #class_name {
+                const VIRTUAL_METHOD_NAMES: &'static [&'static str] = &[ #( #virtual_method_names ),* ];
+            }
+            impl crate::private::EngineConstants for #class_name {
+                const CONSTANT_NAMES: &'static [&'static str] = &[ #( #constant_names ),* ];
+            }
 
             #(
                 // SAFETY: #all_bases is a list of classes provided by Godot such that #class_name is guaranteed a subclass of all of them.
@@ -238,6 +250,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
             #godot_default_impl
             #deref_impl
+            #signal_collection
 
             /// # Safety
             ///
@@ -273,6 +286,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> TokenStream {
     let mut class_decls = Vec::new();
     let mut notify_decls = Vec::new();
+    let mut signal_decls = Vec::new();
 
     for m in classes_and_modules.iter() {
         let GeneratedClassModule {
@@ -300,6 +314,11 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
 
             notify_decls.push(notify_decl);
         }
+
+        let collection_name = signals::make_collection_name(class_name);
+        signal_decls.push(quote! {
+            pub use super::#module_name::re_export::#collection_name;
+        });
     }
 
     let macros = classes_and_modules.iter().map(|m| {
@@ -323,6 +342,11 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
             #( #notify_decls )*
         }
 
+        /// Signal collections for all classes, returned by [`Gd::signals()`][crate::obj::Gd::signals].
+        pub mod signals {
+            #( #signal_decls )*
+        }
+
         #[doc(hidden)]
         pub mod class_macros {
             pub use crate::*;
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::models::domain::{ClassConstant, ClassConstantValue};
+use crate::models::domain::{ApiView, Class, ClassConstant, ClassConstantValue, TyName};
 use crate::util;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -18,6 +18,31 @@ pub fn make_constants(constants: &[ClassConstant]) -> TokenStream {
     }
 }
 
+/// Godot names of all integer constants declared by `class` or one of its bases, including enumerators (e.g. `"NOTIFICATION_READY"`).
+pub fn make_constant_names(
+    class: &Class,
+    all_base_names: &[TyName],
+    view: &ApiView,
+) -> Vec<String> {
+    let base_classes = all_base_names
+        .iter()
+        .map(|base_name| view.get_engine_class(base_name));
+
+    std::iter::once(class)
+        .chain(base_classes)
+        .flat_map(|class| {
+            let constants = class.constants.iter().map(|constant| constant.name.clone());
+            let enumerators = class
+                .enums
+                .iter()
+                .flat_map(|enum_| enum_.enumerators.iter())
+                .map(|enumerator| enumerator.godot_name.clone());
+
+            constants.chain(enumerators)
+        })
+        .collect()
+}
+
 fn make_constant_definition(constant: &ClassConstant) -> TokenStream {
     let constant_name = &constant.name;
     let ident = util::ident(constant_name);
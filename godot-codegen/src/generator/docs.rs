@@ -84,6 +84,10 @@ pub fn make_virtual_trait_doc(trait_name_str: &str, class_name: &TyName) -> Stri
         \n\n\
         These methods represent constructors (`init`) or callbacks invoked by the engine.\
         \n\n\
+        To override them, implement this trait for your class inside a `#[godot_api]` block. The compiler then checks the exact \
+        signature of each overridden method, and forgetting the attribute results in a compile error instead of a silently ignored \
+        override.\
+        \n\n\
         See also [Godot docs for `{godot_ty}` methods]({online_link}).\n\n{notes}"
     )
 }
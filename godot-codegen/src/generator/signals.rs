@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::models::domain::{Class, TyName};
+use crate::util::safe_ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Name of the struct holding all signals of a class, e.g. `SignalsOfNode`.
+pub fn make_collection_name(class_name: &TyName) -> Ident {
+    format_ident!("SignalsOf{}", class_name.rust_ty)
+}
+
+/// Generates the signal collection of `class`, returned by `Gd::signals()`.
+///
+/// Each collection has one method per signal declared by the class itself, returning a `TypedSignal` with the signal's parameter
+/// types. It derefs to the collection of the base class.
+/// Only the root collection (of `Object`) stores the object.
+pub fn make_signal_collection(class: &Class, cfg_attributes: &TokenStream) -> TokenStream {
+    let class_name = class.name();
+    let rust_ty = &class_name.rust_ty;
+    let collection_name = make_collection_name(class_name);
+
+    let online_link = format!(
+        "https://docs.godotengine.org/en/stable/classes/class_{}.html",
+        class_name.godot_ty.to_ascii_lowercase()
+    );
+
+    let signal_methods = class.signals.iter().map(|signal| {
+        let signal_name = &signal.name;
+        let method_name = safe_ident(signal_name);
+        let mut doc = format!(
+            "Signal [`{signal_name}`]({online_link}#class-{class}-signal-{anchor}).",
+            class = class_name.godot_ty.to_ascii_lowercase(),
+            anchor = signal_name.replace('_', "-"),
+        );
+        if !signal.params.is_empty() {
+            let param_names: Vec<String> = signal
+                .params
+                .iter()
+                .map(|p| format!("`{}`", p.name))
+                .collect();
+            doc.push_str(&format!("\n\nParameters: {}.", param_names.join(", ")));
+        }
+
+        let param_types = signal.params.iter().map(|p| &p.type_);
+
+        quote! {
+            #[doc = #doc]
+            pub fn #method_name(&self) -> crate::builtin::TypedSignal<( #( #param_types, )* )> {
+                crate::builtin::TypedSignal::__new(self.__object(), #signal_name)
+            }
+        }
+    });
+
+    let (fields, construction, object_getter, deref_impl);
+    if let Some(base) = class.inherits.as_deref() {
+        let base_collection = make_collection_name(&TyName::from_godot(base));
+        let base_collection = quote! { crate::classes::signals::#base_collection };
+
+        fields = quote! { __base: #base_collection, };
+        construction = quote! { __base: #base_collection::__new(object), };
+        object_getter = TokenStream::new();
+        deref_impl = quote! {
+            impl std::ops::Deref for #collection_name {
+                type Target = #base_collection;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.__base
+                }
+            }
+        };
+    } else {
+        fields = quote! { __object: Gd<Object>, };
+        construction = quote! { __object: object, };
+        object_getter = quote! {
+            #[doc(hidden)]
+            pub fn __object(&self) -> &Gd<Object> {
+                &self.__object
+            }
+        };
+        deref_impl = TokenStream::new();
+    }
+
+    let collection_doc = format!(
+        "Signals of [`{rust_ty}`][crate::classes::{rust_ty}], returned by [`Gd::signals()`][crate::obj::Gd::signals].\n\n\
+        Signals of base classes are available through `Deref`."
+    );
+
+    quote! {
+        #[doc = #collection_doc]
+        #cfg_attributes
+        pub struct #collection_name {
+            #fields
+        }
+
+        impl #collection_name {
+            #[doc(hidden)]
+            pub fn __new(object: Gd<Object>) -> Self {
+                Self { #construction }
+            }
+
+            #object_getter
+            #( #signal_methods )*
+        }
+
+        #deref_impl
+
+        impl crate::obj::WithSignals for #rust_ty {
+            type SignalCollection = #collection_name;
+
+            fn __signals_from(object: &Gd<Self>) -> Self::SignalCollection {
+                #collection_name::__new(object.clone().upcast())
+            }
+        }
+    }
+}
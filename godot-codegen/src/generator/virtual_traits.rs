@@ -42,6 +42,24 @@ pub fn make_virtual_methods_trait(
     }
 }
 
+/// Godot names of all virtual methods declared by `class` or one of its bases, e.g. `"_process"`.
+pub fn make_virtual_method_names(
+    class: &Class,
+    all_base_names: &[TyName],
+    view: &ApiView,
+) -> Vec<String> {
+    let own_methods = class.methods.iter();
+    let base_methods = all_base_names
+        .iter()
+        .flat_map(|base_name| view.get_engine_class(base_name).methods.iter());
+
+    own_methods
+        .chain(base_methods)
+        .filter(|method| method.is_virtual())
+        .map(|method| method.godot_name().to_string())
+        .collect()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -166,6 +166,7 @@ pub struct Class {
     pub constants: Vec<ClassConstant>,
     pub enums: Vec<Enum>,
     pub methods: Vec<ClassMethod>,
+    pub signals: Vec<ClassSignal>,
 }
 
 impl ClassLike for Class {
@@ -174,6 +175,12 @@ impl ClassLike for Class {
     }
 }
 
+/// Signal declared by an engine class (not including inherited ones).
+pub struct ClassSignal {
+    pub name: String,
+    pub params: Vec<FnParam>,
+}
+
 pub struct NativeStructure {
     pub name: String,
     pub format: String,
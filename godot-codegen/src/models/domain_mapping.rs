@@ -8,14 +8,16 @@
 use crate::context::Context;
 use crate::models::domain::{
     BuildConfiguration, BuiltinClass, BuiltinMethod, BuiltinSize, BuiltinVariant, Class,
-    ClassCommons, ClassConstant, ClassConstantValue, ClassMethod, Constructor, Enum, Enumerator,
-    EnumeratorValue, ExtensionApi, FnDirection, FnParam, FnQualifier, FnReturn, FunctionCommon,
-    GodotApiVersion, ModName, NativeStructure, Operator, Singleton, TyName, UtilityFunction,
+    ClassCommons, ClassConstant, ClassConstantValue, ClassMethod, ClassSignal, Constructor, Enum,
+    Enumerator, EnumeratorValue, ExtensionApi, FnDirection, FnParam, FnQualifier, FnReturn,
+    FunctionCommon, GodotApiVersion, ModName, NativeStructure, Operator, Singleton, TyName,
+    UtilityFunction,
 };
 use crate::models::json::{
     JsonBuiltinClass, JsonBuiltinMethod, JsonBuiltinSizes, JsonClass, JsonClassConstant,
     JsonClassMethod, JsonConstructor, JsonEnum, JsonEnumConstant, JsonExtensionApi, JsonHeader,
-    JsonMethodReturn, JsonNativeStructure, JsonOperator, JsonSingleton, JsonUtilityFunction,
+    JsonMethodReturn, JsonNativeStructure, JsonOperator, JsonSignal, JsonSingleton,
+    JsonUtilityFunction,
 };
 use crate::util::{get_api_level, ident, option_as_slice};
 use crate::{conv, special_cases};
@@ -113,6 +115,11 @@ impl Class {
             })
             .collect();
 
+        let signals = option_as_slice(&json.signals)
+            .iter()
+            .filter_map(|s| ClassSignal::from_json(s, ctx))
+            .collect();
+
         Some(Self {
             common: ClassCommons {
                 name: ty_name,
@@ -126,6 +133,7 @@ impl Class {
             constants,
             enums,
             methods,
+            signals,
         })
     }
 }
@@ -624,6 +632,22 @@ impl ClassConstant {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Signals
+
+impl ClassSignal {
+    pub fn from_json(json: &JsonSignal, ctx: &mut Context) -> Option<Self> {
+        if special_cases::is_class_signal_deleted(json, ctx) {
+            return None;
+        }
+
+        Some(Self {
+            name: json.name.clone(),
+            params: FnParam::new_range_no_defaults(&json.arguments, ctx),
+        })
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Native structures
 
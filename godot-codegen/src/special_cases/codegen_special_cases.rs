@@ -10,7 +10,7 @@
 // TODO make this file private and only accessed by special_cases.rs.
 
 use crate::context::Context;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases;
 
 pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
@@ -86,6 +86,16 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
     false
 }
 
+pub(crate) fn is_class_signal_excluded(signal: &JsonSignal, ctx: &mut Context) -> bool {
+    // Same rules as for method arguments.
+    signal.arguments.as_ref().map_or(false, |args| {
+        args.iter().any(|arg| {
+            special_cases::is_godot_type_deleted(arg.type_.as_str())
+                || is_type_excluded(arg.type_.as_str(), ctx)
+        })
+    })
+}
+
 #[cfg(feature = "codegen-full")]
 pub(crate) fn is_utility_function_excluded(
     _function: &JsonUtilityFunction,
@@ -122,6 +132,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "BaseButton",
     "BoxMesh",
     "Button",
+    "CallbackTweener",
     "Camera2D",
     "Camera3D",
     "CanvasItem",
@@ -141,10 +152,12 @@ const SELECTED_CLASSES: &[&str] = &[
     "Input",
     "InputEvent",
     "InputEventAction",
+    "IntervalTweener",
     "Label",
     "MainLoop",
     "Marker2D",
     "Mesh",
+    "MethodTweener",
     "Node",
     "Node2D",
     "Node3D",
@@ -155,6 +168,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "PathFollow2D",
     "PhysicsBody2D",
     "PrimitiveMesh",
+    "PropertyTweener",
     "RefCounted",
     "RenderingServer",
     "Resource",
@@ -176,6 +190,8 @@ const SELECTED_CLASSES: &[&str] = &[
     "TextureLayered",
     "Time",
     "Timer",
+    "Tween",
+    "Tweener",
     "Viewport",
     "Window",
 ];
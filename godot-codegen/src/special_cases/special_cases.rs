@@ -25,7 +25,7 @@
 
 use crate::conv::to_enum_type_uncached;
 use crate::models::domain::{Enum, RustTy, TyName};
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::Context;
 // Deliberately private -- all checks must go through `special_cases`.
@@ -73,6 +73,11 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     }
 }
 
+/// True if a signal's parameters refer to types that are not available, so its typed accessor cannot be generated.
+pub fn is_class_signal_deleted(signal: &JsonSignal, ctx: &mut Context) -> bool {
+    codegen_special_cases::is_class_signal_excluded(signal, ctx)
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
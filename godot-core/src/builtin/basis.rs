@@ -157,13 +157,46 @@ impl Basis {
         }
     }
 
+    /// Creates a rotation basis with the forward axis pointing in the direction of `target`, and the `up` vector as close as possible to up.
+    ///
     /// If `use_model_front` is true, the +Z axis (asset front) is treated as forward (implies +X is left)
     /// and points toward the target position. By default, the -Z axis (camera forward) is treated as forward
     /// (implies +X is right).
     ///
+    /// The result is orthonormal.
+    ///
+    /// # Degenerate input
+    /// If `target` or `up` is zero, or both are parallel, there is no unique rotation. Like in Godot, an error is printed and
+    /// [`Basis::IDENTITY`] is returned.
+    ///
     /// _Godot equivalent: `Basis.looking_at()`_
     pub fn new_looking_at(target: Vector3, up: Vector3, use_model_front: bool) -> Self {
-        super::inner::InnerBasis::looking_at(target, up, use_model_front)
+        if target.is_zero_approx() {
+            crate::godot_error!("new_looking_at(): target vector can't be zero");
+            return Self::IDENTITY;
+        }
+        if up.is_zero_approx() {
+            crate::godot_error!("new_looking_at(): up vector can't be zero");
+            return Self::IDENTITY;
+        }
+
+        let mut z = target.normalized();
+        if !use_model_front {
+            z = -z;
+        }
+
+        let x = up.cross(z);
+        if x.is_zero_approx() {
+            crate::godot_error!(
+                "new_looking_at(): target {target:?} and up {up:?} vectors can't be parallel"
+            );
+            return Self::IDENTITY;
+        }
+
+        let x = x.normalized();
+        let y = z.cross(x);
+
+        Self::from_cols(x, y, z)
     }
 
     /// Creates a `[Vector3; 3]` with the columns of the `Basis`.
@@ -176,7 +209,15 @@ impl Basis {
     /// _Godot equivalent: `Basis.get_rotation_quaternion()`_
     #[doc(alias = "get_rotation_quaternion")]
     pub fn to_quat(&self) -> Quaternion {
-        RQuat::from_mat3(&self.orthonormalized().to_glam()).to_front()
+        let mut rotation = self.orthonormalized();
+
+        // A negative determinant means the basis contains a reflection, which a quaternion cannot represent. Like Godot,
+        // flip all axes to obtain a proper rotation.
+        if rotation.determinant() < 0.0 {
+            rotation = rotation.scaled(Vector3::new(-1.0, -1.0, -1.0));
+        }
+
+        RQuat::from_mat3(&rotation.to_glam()).to_front()
     }
 
     const fn to_rows_array(self) -> [real; 9] {
@@ -840,6 +881,90 @@ mod test {
         );
     }
 
+    fn assert_orthonormal(basis: Basis) {
+        let [a, b, c] = basis.to_cols();
+
+        assert!(a.is_normalized(), "column a not normalized: {a}");
+        assert!(b.is_normalized(), "column b not normalized: {b}");
+        assert!(c.is_normalized(), "column c not normalized: {c}");
+        assert!(a.dot(b).is_zero_approx(), "columns a, b not orthogonal");
+        assert!(a.dot(c).is_zero_approx(), "columns a, c not orthogonal");
+        assert!(b.dot(c).is_zero_approx(), "columns b, c not orthogonal");
+        assert!(basis.determinant().approx_eq(&1.0), "not right-handed");
+    }
+
+    #[test]
+    fn looking_at_orientation() {
+        // Camera convention: -Z points towards target.
+        let basis = Basis::new_looking_at(Vector3::new(1.0, 0.0, -1.0), Vector3::UP, false);
+        assert_orthonormal(basis);
+        assert_eq_approx!(
+            basis * Vector3::FORWARD,
+            Vector3::new(1.0, 0.0, -1.0).normalized()
+        );
+        assert_eq_approx!(basis.col_b(), Vector3::UP);
+
+        // Model convention: +Z points towards target.
+        let basis = Basis::new_looking_at(Vector3::new(1.0, 0.0, -1.0), Vector3::UP, true);
+        assert_orthonormal(basis);
+        assert_eq_approx!(
+            basis * Vector3::BACK,
+            Vector3::new(1.0, 0.0, -1.0).normalized()
+        );
+
+        // Identity when looking along -Z with +Y up.
+        assert_eq_approx!(
+            Basis::new_looking_at(Vector3::FORWARD, Vector3::UP, false),
+            Basis::IDENTITY
+        );
+
+        // Up vector not perpendicular to target: still orthonormal, up is projected.
+        let basis = Basis::new_looking_at(Vector3::new(0.0, -1.0, -1.0), Vector3::UP, false);
+        assert_orthonormal(basis);
+        assert!(basis.col_b().dot(Vector3::UP) > 0.0);
+    }
+
+    #[test]
+    fn looking_at_degenerate() {
+        // Prints an error, but does not panic.
+        assert_eq!(
+            Basis::new_looking_at(Vector3::UP, Vector3::UP, false),
+            Basis::IDENTITY
+        );
+        assert_eq!(
+            Basis::new_looking_at(Vector3::DOWN * 3.0, Vector3::UP, false),
+            Basis::IDENTITY
+        );
+        assert_eq!(
+            Basis::new_looking_at(Vector3::ZERO, Vector3::UP, false),
+            Basis::IDENTITY
+        );
+        assert_eq!(
+            Basis::new_looking_at(Vector3::FORWARD, Vector3::ZERO, false),
+            Basis::IDENTITY
+        );
+    }
+
+    #[test]
+    fn orthonormalized_gram_schmidt() {
+        let basis = Basis::from_cols(
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(1.0, 3.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.5),
+        );
+        let ortho = basis.orthonormalized();
+        assert_orthonormal(ortho);
+
+        // Gram-Schmidt keeps the direction of the first column and the plane of the first two.
+        assert_eq_approx!(ortho.col_a(), Vector3::RIGHT);
+        assert_eq_approx!(ortho.col_b(), Vector3::UP);
+        assert_eq_approx!(ortho.col_c(), Vector3::BACK);
+
+        // Already orthonormal bases are unchanged.
+        let rotation = Basis::from_euler(EulerOrder::YXZ, Vector3::new(0.3, -1.2, 2.0));
+        assert_eq_approx!(rotation.orthonormalized(), rotation);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
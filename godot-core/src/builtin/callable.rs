@@ -179,11 +179,32 @@ impl Callable {
 
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
+    /// When the returned callable is invoked, the bound arguments are passed **after** the arguments supplied at call time
+    /// (e.g. by a signal emission). See [`bind()`][Self::bind] for an example.
+    ///
     /// _Godot equivalent: `bindv`_
     pub fn bindv(&self, arguments: VariantArray) -> Self {
         self.as_inner().bindv(arguments)
     }
 
+    /// Returns a copy of this Callable with one or more arguments bound.
+    ///
+    /// Like in Godot, bound arguments are appended to the arguments supplied at call time. This is useful to distinguish multiple
+    /// signal connections to the same handler by an extra argument:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # let receiver = Object::new_alloc();
+    /// // Handler signature: on_pressed(emit_arg: i64, button_id: i64).
+    /// let callable = receiver.callable("on_pressed").bind(&[7.to_variant()]);
+    ///
+    /// // A signal emitted with argument 100 now calls `on_pressed(100, 7)`.
+    /// ```
+    ///
+    /// _Godot equivalent: `bind`_
+    pub fn bind(&self, arguments: &[Variant]) -> Self {
+        self.bindv(arguments.iter().cloned().collect())
+    }
+
     /// Returns the name of the method represented by this callable. If the callable is a lambda function,
     /// returns the function's name.
     ///
@@ -394,7 +415,7 @@ mod custom_callable {
 
         let c: &mut C = CallableUserdata::inner_from_raw(callable_userdata);
 
-        let result = c.invoke(arg_refs);
+        let result = invoke_catching_panic(|| "custom callable", || c.invoke(arg_refs));
         crate::meta::varcall_return_checked(result, r_return, r_error);
     }
 
@@ -409,12 +430,30 @@ mod custom_callable {
     {
         let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
 
-        let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+        let FnWrapper {
+            rust_function,
+            name,
+        }: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
 
-        let result = (w.rust_function)(arg_refs);
+        let result =
+            invoke_catching_panic(|| format!("callable `{name}`"), || rust_function(arg_refs));
         crate::meta::varcall_return_checked(result, r_return, r_error);
     }
 
+    /// Panics must not unwind across the FFI boundary; a panicking callable is reported to Godot as a failed call instead.
+    fn invoke_catching_panic<E, S>(
+        error_context: E,
+        invoke: impl FnOnce() -> Result<Variant, ()>,
+    ) -> Result<Variant, ()>
+    where
+        E: FnOnce() -> S,
+        S: fmt::Display,
+    {
+        let invoke = std::panic::AssertUnwindSafe(invoke);
+
+        crate::private::handle_panic(error_context, invoke).unwrap_or(Err(()))
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
@@ -5,14 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
 
 use crate::builtin::*;
 use crate::meta::error::{ConvertError, FromGodotError, FromVariantError};
 use crate::meta::{
-    ArrayElement, ArrayTypeInfo, FromGodot, GodotConvert, GodotFfiVariant, GodotType,
-    PropertyHintInfo, ToGodot,
+    ArrayElement, ArrayTypeInfo, FromGodot, FromVariantTuple, GodotConvert, GodotFfiVariant,
+    GodotType, PropertyHintInfo, ToGodot,
 };
 use crate::registry::property::{Export, Var};
 use godot_ffi as sys;
@@ -46,6 +48,11 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 /// If you want to create a copy of the data, use [`duplicate_shallow()`][Self::duplicate_shallow]
 /// or [`duplicate_deep()`][Self::duplicate_deep].
 ///
+/// These correspond to GDScript's `duplicate(false)` and `duplicate(true)`. A shallow duplicate is a new array, but nested `Array`
+/// and `Dictionary` elements still refer to the same data, so mutating them through the duplicate affects the original. A deep
+/// duplicate recursively copies nested arrays and dictionaries. In both modes, objects are not duplicated: `Gd` elements refer to the
+/// same instances.
+///
 /// # Typed array example
 ///
 /// ```no_run
@@ -100,6 +107,14 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 /// // ...and so on.
 /// ```
 ///
+/// # Indexing
+///
+/// `Array` deliberately does not implement [`Index`][std::ops::Index] or [`IndexMut`][std::ops::IndexMut]; use [`at()`][Self::at],
+/// [`get()`][Self::get] and [`set()`][Self::set] instead, which work with values. Because of the reference semantics described above,
+/// a `&T` into the array's storage cannot be handed out soundly: a clone of the array (or GDScript code holding the same array) can
+/// push or remove elements while the reference is alive, reallocating the storage under it. In typed arrays, elements are moreover
+/// stored as `Variant`, so no `&T` exists in memory for types like `i64`.
+///
 /// # Thread safety
 ///
 /// Usage is safe if the `Array` is used on a single thread only. Concurrent reads on
@@ -348,6 +363,55 @@ impl<T: ArrayElement> Array<T> {
         T::from_variant(&variant)
     }
 
+    /// ⚠️ Removes the elements in `range` from the array and returns them as an iterator, in order.
+    ///
+    /// Similar to [`Vec::drain()`], but the elements are removed immediately, even if the iterator is not consumed. The remaining elements
+    /// are moved together in one bulk operation, so the array stays contiguous. The tail is thus moved only once, instead of once per
+    /// removed element as with repeated [`remove()`][Self::remove]. Reading out the removed elements still takes one engine call
+    /// per element.
+    ///
+    /// # Panics
+    ///
+    /// If the start of the range is greater than the end, or if the end is greater than the length of the array.
+    pub fn drain<R>(&mut self, range: R) -> impl Iterator<Item = T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let begin = match range.start_bound() {
+            Bound::Included(&begin) => begin,
+            Bound::Excluded(&begin) => begin.checked_add(1).expect("drain: range start overflows"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("drain: range end overflows"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            begin <= end,
+            "drain: range start {begin} is greater than end {end}"
+        );
+        assert!(
+            end <= len,
+            "drain: range end {end} is out of bounds: length is {len}"
+        );
+
+        let removed: Vec<T> = self
+            .subarray_shallow(begin, end, None)
+            .iter_shared()
+            .collect();
+
+        if begin < end {
+            let tail = self.subarray_shallow(end, len, None);
+            self.shrink(begin);
+            self.extend_array(tail);
+        }
+
+        removed.into_iter()
+    }
+
     /// Removes the first occurrence of a value from the array.
     ///
     /// If the value does not exist in the array, nothing happens. To remove an element by index, use [`remove()`][Self::remove] instead.
@@ -359,6 +423,47 @@ impl<T: ArrayElement> Array<T> {
         unsafe { self.as_inner_mut() }.erase(value.to_variant());
     }
 
+    /// Retains only the elements for which `keep` returns `true`, removing all others.
+    ///
+    /// Like [`Vec::retain()`], this operates in place and preserves the order of the retained elements. Kept elements are moved
+    /// towards the front, after which the array is shrunk once, instead of removing elements one by one.
+    ///
+    /// If `keep` panics, the array remains valid: elements already rejected are removed, and all remaining elements (including
+    /// the one being examined) are kept in their original order.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut array: Array<i32> = array![1, 2, 3, 4];
+    /// array.retain(|x| x % 2 == 0);
+    /// assert_eq!(array, array![2, 4]);
+    /// ```
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let mut guard = RetainGuard {
+            array: self,
+            read: 0,
+            write: 0,
+            len,
+        };
+
+        while guard.read < len {
+            let element = guard.array.at(guard.read);
+            if keep(&element) {
+                if guard.write != guard.read {
+                    guard.array.set(guard.write, element);
+                }
+                guard.write += 1;
+            }
+            guard.read += 1;
+        }
+
+        // Dropping the guard shrinks the array.
+    }
+
     /// Assigns the given value to all elements in the array. This can be used together with
     /// `resize` to create an array with a given size and initialized elements.
     pub fn fill(&mut self, value: &T) {
@@ -413,6 +518,33 @@ impl<T: ArrayElement> Array<T> {
         inner_self.append_array(other);
     }
 
+    /// Appends all elements of `slice` to the end of this array.
+    ///
+    /// This is faster than calling [`push()`][Self::push] for each element: the array is resized only once, after which the new
+    /// elements are written in place. Useful for bulk-loading primitive data such as mesh indices or tile maps.
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Copy,
+    {
+        if slice.is_empty() {
+            return;
+        }
+
+        let start = self.len();
+
+        // SAFETY: The new slots temporarily hold `Variant::nil()`, but are all overwritten with values of type `T` below.
+        unsafe { self.as_inner_mut() }.resize(to_i64(start + slice.len()));
+
+        // Godot stores array elements contiguously, so the pointer to the first new element gives access to all others.
+        let first: *mut Variant = self.ptr_mut(start).cast();
+        for (i, value) in slice.iter().enumerate() {
+            // SAFETY: `start + i` is within the resized bounds, and the array is not reallocated during the loop.
+            unsafe {
+                value.to_variant().move_into_var_ptr(first.add(i).cast());
+            }
+        }
+    }
+
     /// Returns a shallow copy of the array. All array elements are copied, but any reference types
     /// (such as `Array`, `Dictionary` and `Object`) will still refer to the same value.
     ///
@@ -450,8 +582,7 @@ impl<T: ArrayElement> Array<T> {
     ///
     /// Array elements are copied to the slice, but any reference types (such as `Array`,
     /// `Dictionary` and `Object`) will still refer to the same value. To create a deep copy, use
-    /// [`subarray_deep()`][Self::subarray_deep] instead.
-    #[doc(alias = "slice")]
+    /// [`subarray_deep()`][Self::subarray_deep] instead. For negative indices, see [`slice()`][Self::slice].
     pub fn subarray_shallow(&self, begin: usize, end: usize, step: Option<isize>) -> Self {
         self.subarray_impl(begin, end, step, false)
     }
@@ -472,6 +603,41 @@ impl<T: ArrayElement> Array<T> {
         self.subarray_impl(begin, end, step, true)
     }
 
+    /// Returns every `step`-th element in `begin..end`, as a new array, with the exact semantics of GDScript's `Array.slice()`.
+    ///
+    /// Negative `begin` and `end` count from the back: `-1` is the last element. Both bounds are clamped to the array, so for example
+    /// `slice(-3, isize::MAX, 1)` returns the last three elements (or fewer). A negative `step` walks backwards, which requires
+    /// `begin > end`; to include the first element, pass `end = -(len + 1)` or lower. If the bounds run opposite to `step`, Godot prints an
+    /// error and returns an empty array.
+    ///
+    /// Elements are shallow-copied, like with [`subarray_shallow()`][Self::subarray_shallow], which is the counterpart for non-negative
+    /// indices.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![0, 1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(array.slice(-2, 6, 1), array![4, 5]);
+    /// assert_eq!(array.slice(0, 6, 2), array![0, 2, 4]);
+    /// assert_eq!(array.slice(-1, -7, -1), array![5, 4, 3, 2, 1, 0]);
+    /// ```
+    ///
+    /// # Panics
+    /// If `step` is zero.
+    pub fn slice(&self, begin: isize, end: isize, step: isize) -> Self {
+        assert_ne!(step, 0, "slice: step cannot be zero");
+
+        // SAFETY: The type of the array is `T` and we convert the returned array to an `Array<T>` immediately.
+        let slice: VariantArray = unsafe {
+            self.as_inner()
+                .slice(begin as i64, end as i64, step as i64, false)
+        };
+
+        // SAFETY: slice() returns a typed array with the same type as Self.
+        unsafe { slice.assume_type() }
+    }
+
     fn subarray_impl(&self, begin: usize, end: usize, step: Option<isize>, deep: bool) -> Self {
         assert_ne!(step, Some(0), "subarray: step cannot be zero");
 
@@ -584,6 +750,48 @@ impl<T: ArrayElement> Array<T> {
         )
     }
 
+    /// Binary-searches a sorted array for the given value, using Rust's [`PartialOrd`] comparison.
+    ///
+    /// Mirrors [`slice::binary_search()`]: returns `Ok(index)` of a matching element, or `Err(index)` with the position
+    /// where `value` could be inserted while maintaining sorting order. If multiple elements match, any one of them may be returned.
+    ///
+    /// Unlike [`bsearch()`](Self::bsearch), elements are compared in Rust, not through Godot's `Variant` comparison.
+    ///
+    /// If the array is not sorted (or contains incomparable values such as NaN), the result is unspecified but safe.
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize>
+    where
+        T: PartialOrd,
+    {
+        self.binary_search_by(|element| element.partial_cmp(value).unwrap_or(Ordering::Less))
+    }
+
+    /// Binary-searches a sorted array with a comparator function.
+    ///
+    /// Mirrors [`slice::binary_search_by()`]: the comparator receives an element and returns whether it is less, equal or greater
+    /// than the target. Returns `Ok(index)` of a matching element, or `Err(index)` with the position where a matching element could be
+    /// inserted while maintaining sorting order.
+    ///
+    /// If the array is not sorted according to the comparator, the result is unspecified but safe.
+    pub fn binary_search_by<F>(&self, mut func: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match func(&self.at(mid)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
     /// Reverses the order of the elements in the array.
     pub fn reverse(&mut self) {
         // SAFETY: We do not write any values that don't already exist in the array, so all values have the correct type.
@@ -612,6 +820,36 @@ impl<T: ArrayElement> Array<T> {
         unsafe { self.as_inner_mut() }.sort_custom(func);
     }
 
+    /// Sorts the array with a Rust comparator function.
+    ///
+    /// Mirrors [`slice::sort_unstable_by()`]. Useful to sort by computed keys that Godot's `Variant` ordering cannot express. Unlike
+    /// [`sort_unstable_custom()`](Self::sort_unstable_custom), the comparator is a Rust closure and no `Callable` is involved.
+    ///
+    /// The elements are copied into a temporary `Vec<T>` (one allocation of `len` elements), sorted there in `O(n log n)` comparisons,
+    /// and then written back to the array in `O(n)`.
+    ///
+    /// Note: The sorting algorithm used is not [stable](https://en.wikipedia.org/wiki/Sorting_algorithm#Stability).
+    /// This means that values considered equal may have their order changed when using `sort_unstable_by`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut distances: Array<i32> = array![-3, 1, -2];
+    /// distances.sort_unstable_by(|a, b| a.abs().cmp(&b.abs()));
+    /// assert_eq!(distances, array![1, -2, -3]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut elements: Vec<T> = self.iter_shared().collect();
+        elements.sort_unstable_by(compare);
+
+        for (index, element) in elements.into_iter().enumerate() {
+            self.set(index, element);
+        }
+    }
+
     /// Shuffles the array such that the items will have a random order. This method uses the
     /// global random number generator common to methods such as `randi`. Call `randomize` to
     /// ensure that a new seed will be used each time if you want non-reproducible shuffling.
@@ -839,6 +1077,26 @@ impl<T: ArrayElement> Array<T> {
 }
 
 impl VariantArray {
+    /// Converts the elements to a tuple of Rust types, checking arity and each element's type.
+    ///
+    /// Useful to unpack signal arguments or results of dynamic calls. Supports tuples with up to 8 elements.
+    ///
+    /// # Errors
+    /// If the array length differs from the tuple's arity, or if an element cannot be converted. The error names the failing element.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = varray![7, "seven"];
+    /// let (number, name) = array.to_tuple::<(i32, GString)>().unwrap();
+    /// assert_eq!(number, 7);
+    /// assert_eq!(name, "seven".into());
+    /// ```
+    pub fn to_tuple<T: FromVariantTuple>(&self) -> Result<T, ConvertError> {
+        let variants: Vec<Variant> = self.iter_shared().collect();
+        T::try_from_variant_slice(&variants)
+    }
+
     /// # Safety
     /// - Variant must have type `VariantType::ARRAY`.
     /// - Subsequent operations on this array must not rely on the type of the array.
@@ -1140,11 +1398,28 @@ impl<T: ArrayElement + ToGodot> FromIterator<T> for Array<T> {
 impl<T: ArrayElement + ToGodot> Extend<T> for Array<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`.
-        // Otherwise, we could use it to pre-allocate based on `iter.size_hint()`.
-        //
-        // A faster implementation using `resize()` and direct pointer writes might still be
-        // possible.
-        for item in iter.into_iter() {
+        // Instead, allocate the lower bound of `iter.size_hint()` in one `resize()` call, and overwrite the new slots in place.
+        let mut iter = iter.into_iter();
+        let (reserved, _) = iter.size_hint();
+        let start = self.len();
+
+        if reserved > 0 {
+            // SAFETY: The new slots temporarily hold default values, which are overwritten with values of type `T` below. If the
+            // iterator yields fewer elements than its size hint promised, the remaining slots are truncated again.
+            unsafe { self.as_inner_mut() }.resize(to_i64(start + reserved));
+        }
+
+        let mut written = 0;
+        for item in iter.by_ref().take(reserved) {
+            self.set(start + written, item);
+            written += 1;
+        }
+
+        if written < reserved {
+            self.shrink(start + written);
+        }
+
+        for item in iter {
             self.push(item);
         }
     }
@@ -1168,6 +1443,34 @@ impl<T: ArrayElement + FromGodot> From<&Array<T>> for Vec<T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Compacts the array when [`Array::retain()`] completes or its predicate panics.
+struct RetainGuard<'a, T: ArrayElement> {
+    array: &'a mut Array<T>,
+    /// Index of the next element to examine.
+    read: usize,
+    /// Index to which the next retained element is moved.
+    write: usize,
+    /// Original length of the array.
+    len: usize,
+}
+
+impl<T: ArrayElement> Drop for RetainGuard<'_, T> {
+    fn drop(&mut self) {
+        // After a panic, elements from `read` onward have not been examined; move them down to keep them.
+        let remaining = self.len - self.read;
+        if self.write != self.read {
+            for offset in 0..remaining {
+                let element = self.array.at(self.read + offset);
+                self.array.set(self.write + offset, element);
+            }
+        }
+
+        self.array.shrink(self.write + remaining);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// An iterator over typed elements of an [`Array`].
 pub struct Iter<'a, T: ArrayElement> {
     array: &'a Array<T>,
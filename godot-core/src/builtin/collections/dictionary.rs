@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Variant, VariantArray};
+use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, ToGodot};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -254,10 +255,33 @@ impl Dictionary {
     ///
     /// If `overwrite` is true, it will overwrite pre-existing keys.
     ///
-    /// _Godot equivalent: `merge`_
-    #[doc(alias = "merge")]
+    /// Equivalent to [`merge()`][Self::merge], but takes `other` by value.
     pub fn extend_dictionary(&mut self, other: Self, overwrite: bool) {
-        self.as_inner().merge(other, overwrite)
+        self.merge(&other, overwrite)
+    }
+
+    /// Copies all keys and values from `other` into `self`.
+    ///
+    /// If `overwrite` is true, values of keys present in both dictionaries are replaced with the ones from `other`. Otherwise,
+    /// existing entries are kept and only new keys are added.
+    ///
+    /// Keys are compared like in Godot, e.g. the integer `1` and the float `1.0` are distinct keys.
+    ///
+    /// _Godot equivalent: `merge`_
+    pub fn merge(&mut self, other: &Dictionary, overwrite: bool) {
+        self.as_inner().merge(other.clone(), overwrite)
+    }
+
+    /// Returns a shallow copy of `self`, with all keys and values from `other` merged into it.
+    ///
+    /// See [`merge()`][Self::merge] for the meaning of `overwrite`. `self` is not modified.
+    ///
+    /// _Godot equivalent: `merged`_
+    #[must_use]
+    pub fn merged(&self, other: &Dictionary, overwrite: bool) -> Self {
+        let mut result = self.duplicate_shallow();
+        result.merge(other, overwrite);
+        result
     }
 
     /// Deep copy, duplicating nested collections.
@@ -312,6 +336,48 @@ impl Dictionary {
         Keys::new(self)
     }
 
+    /// Returns an iterator over the values in a `Dictionary`, in the same order as [`keys_shared()`][Self::keys_shared].
+    ///
+    /// The values are each of type `Variant`. Like the other iterators, this walks the dictionary once and returns a (cheap, shallow)
+    /// copy of each value, rather than a `&`-reference.
+    ///
+    /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
+    /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
+    ///
+    /// Use `dict.values_shared().typed::<V>()` to iterate over `V` values instead.
+    pub fn values_shared(&self) -> Values<'_> {
+        Values::new(self)
+    }
+
+    /// Returns a view of this dictionary with statically typed keys `K` and values `V`.
+    ///
+    /// The view references the same dictionary data (like [`clone()`][Clone::clone]), so changes through it are visible in `self`.
+    /// Since the dictionary itself stays untyped, entries of other types can still exist; [`TypedDictionary`] reports them as
+    /// [`ConvertError`] instead of panicking.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let dict = dict! { "width": 1280, "height": 720 };
+    ///
+    /// let mut config = dict.typed::<GString, i64>();
+    /// assert_eq!(config.get("width".into()).unwrap(), Some(1280));
+    /// assert_eq!(config.get("depth".into()).unwrap(), None);
+    ///
+    /// config.set("depth".into(), 32);
+    /// assert_eq!(dict.at("depth"), 32.to_variant());
+    /// ```
+    pub fn typed<K, V>(&self) -> TypedDictionary<K, V>
+    where
+        K: ToGodot + FromGodot,
+        V: ToGodot + FromGodot,
+    {
+        TypedDictionary {
+            dict: self.clone(),
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerDictionary {
         inner::InnerDictionary::from_outer(self)
@@ -628,6 +694,48 @@ impl<'a> Iterator for Keys<'a> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Iterator over values in a [`Dictionary`].
+///
+/// See [`Dictionary::values_shared()`] for more information about iteration over dictionaries.
+pub struct Values<'a> {
+    iter: DictionaryIter<'a>,
+}
+
+impl<'a> Values<'a> {
+    fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            iter: DictionaryIter::new(dictionary),
+        }
+    }
+
+    /// Creates an iterator that will convert each `Variant` value into a value of type `V`,
+    /// panicking upon failure to convert.
+    pub fn typed<V: FromGodot>(self) -> TypedValues<'a, V> {
+        TypedValues::from_untyped(self)
+    }
+
+    /// Returns an array of the values.
+    pub fn array(self) -> VariantArray {
+        // Can only be called before iteration has started.
+        assert!(self.iter.is_first);
+        self.iter.dictionary.values_array()
+    }
+}
+
+impl<'a> Iterator for Values<'a> {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_key_value().map(|(_key, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// [`Dictionary`] iterator that converts each key-value pair into a typed `(K, V)`.
 ///
 /// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
@@ -692,6 +800,157 @@ impl<'a, K: FromGodot> Iterator for TypedKeys<'a, K> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// [`Dictionary`] iterator that converts each value into a typed `V`.
+///
+/// See [`Dictionary::values_shared()`] for more information about iteration over dictionaries.
+pub struct TypedValues<'a, V> {
+    iter: DictionaryIter<'a>,
+    _v: PhantomData<V>,
+}
+
+impl<'a, V> TypedValues<'a, V> {
+    fn from_untyped(value: Values<'a>) -> Self {
+        Self {
+            iter: value.iter,
+            _v: PhantomData,
+        }
+    }
+}
+
+impl<'a, V: FromGodot> Iterator for TypedValues<'a, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_key_value()
+            .map(|(_key, value)| V::from_variant(&value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Typed view of a [`Dictionary`], with keys of type `K` and values of type `V`.
+///
+/// Created by [`Dictionary::typed()`]. Keys and values are converted on each access; entries whose key or value cannot be converted are
+/// reported as [`ConvertError`].
+pub struct TypedDictionary<K, V> {
+    dict: Dictionary,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<K, V> TypedDictionary<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    /// Returns the value for the given key, `Ok(None)` if the key is absent, or an error if the value is not of type `V`.
+    pub fn get(&self, key: K) -> Result<Option<V>, ConvertError> {
+        self.dict
+            .get(key)
+            .map(|value| V::try_from_variant(&value))
+            .transpose()
+    }
+
+    /// Returns `true` if the dictionary contains the given key.
+    pub fn contains_key(&self, key: K) -> bool {
+        self.dict.contains_key(key)
+    }
+
+    /// Sets a key to a given value.
+    ///
+    /// If you are interested in the previous value, use [`insert()`][Self::insert] instead.
+    pub fn set(&mut self, key: K, value: V) {
+        self.dict.set(key, value)
+    }
+
+    /// Inserts a value at the given key, returning the previous value for that key (if available).
+    ///
+    /// The value is always inserted; an error only means that the _previous_ value was not of type `V`.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, ConvertError> {
+        self.dict
+            .insert(key, value)
+            .map(|old_value| V::try_from_variant(&old_value))
+            .transpose()
+    }
+
+    /// Removes a key from the dictionary, and returns its previous value (if available).
+    ///
+    /// The key is always removed; an error only means that the value was not of type `V`.
+    pub fn remove(&mut self, key: K) -> Result<Option<V>, ConvertError> {
+        self.dict
+            .remove(key)
+            .map(|old_value| V::try_from_variant(&old_value))
+            .transpose()
+    }
+
+    /// Returns the number of entries in the dictionary, including those not matching `K` and `V`.
+    pub fn len(&self) -> usize {
+        self.dict.len()
+    }
+
+    /// Returns true if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.dict.is_empty()
+    }
+
+    /// Returns an iterator over the key-value pairs, converting each to `(K, V)`.
+    ///
+    /// Pairs that cannot be converted are yielded as `Err`, without ending the iteration. The same caveats as for
+    /// [`Dictionary::iter_shared()`] apply.
+    pub fn iter(&self) -> TryTypedIter<'_, K, V> {
+        TryTypedIter {
+            iter: DictionaryIter::new(&self.dict),
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+
+    /// Returns the underlying untyped dictionary.
+    pub fn untyped(&self) -> &Dictionary {
+        &self.dict
+    }
+}
+
+impl<K, V> fmt::Debug for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.dict, f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// [`TypedDictionary`] iterator that tries to convert each key-value pair into a typed `(K, V)`.
+///
+/// See [`TypedDictionary::iter()`] for more information.
+pub struct TryTypedIter<'a, K, V> {
+    iter: DictionaryIter<'a>,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<'a, K: FromGodot, V: FromGodot> Iterator for TryTypedIter<'a, K, V> {
+    type Item = Result<(K, V), ConvertError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_key_value().map(|(key, value)| {
+            let key = K::try_from_variant(&key)?;
+            let value = V::try_from_variant(&value)?;
+            Ok((key, value))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
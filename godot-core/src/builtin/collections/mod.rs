@@ -12,7 +12,7 @@ mod packed_array;
 // Re-export in godot::builtin.
 pub(crate) mod containers {
     pub use super::array::{Array, VariantArray};
-    pub use super::dictionary::Dictionary;
+    pub use super::dictionary::{Dictionary, TypedDictionary};
     pub use super::packed_array::*;
 }
 
@@ -21,6 +21,9 @@ pub(crate) mod iterators {
     pub use super::array::Iter as ArrayIter;
     pub use super::dictionary::Iter as DictIter;
     pub use super::dictionary::Keys as DictKeys;
+    pub use super::dictionary::TryTypedIter as DictTryTypedIter;
     pub use super::dictionary::TypedIter as DictTypedIter;
     pub use super::dictionary::TypedKeys as DictTypedKeys;
+    pub use super::dictionary::TypedValues as DictTypedValues;
+    pub use super::dictionary::Values as DictValues;
 }
@@ -188,6 +188,8 @@ macro_rules! impl_packed_array {
             }
 
             /// Converts this array to a Rust vector, making a copy of its contents.
+            ///
+            /// To read the elements without copying, use [`as_slice`][Self::as_slice] instead.
             pub fn to_vec(&self) -> Vec<$Element> {
                 let len = self.len();
                 let mut vec = Vec::with_capacity(len);
@@ -223,6 +225,13 @@ macro_rules! impl_packed_array {
             /// Returns a shared Rust slice of the array.
             ///
             /// The resulting slice can be further subdivided or converted into raw pointers.
+            /// No copy is made: the slice borrows Godot's underlying storage directly.
+            ///
+            /// # Aliasing
+            /// Any operation that resizes or reallocates the array invalidates the slice; the borrow checker prevents this, since
+            /// such operations take `&mut self`. Other clones of the array may share the same storage (copy-on-write). Modifying one
+            /// of those clones -- also from GDScript -- moves that clone to a new allocation, so the slice keeps pointing to the old,
+            /// unmodified contents.
             ///
             /// See also [`as_mut_slice`][Self::as_mut_slice] to get exclusive slices, and
             /// [`subarray`][Self::subarray] to get a sub-array as a copy.
@@ -243,6 +252,13 @@ macro_rules! impl_packed_array {
             /// Returns an exclusive Rust slice of the array.
             ///
             /// The resulting slice can be further subdivided or converted into raw pointers.
+            /// Writes through the slice go directly to Godot's underlying storage.
+            ///
+            /// # Aliasing
+            /// If the storage is shared with other clones of the array (copy-on-write), this method first copies it into a new
+            /// allocation that belongs to `self` alone. The returned slice is therefore never aliased, and changes through it are not
+            /// visible in other clones. As with [`as_slice`][Self::as_slice], resizing the array invalidates the slice, which the
+            /// borrow checker enforces.
             ///
             /// See also [`as_slice`][Self::as_slice] to get shared slices, and
             /// [`subarray`][Self::subarray] to get a sub-array as a copy.
@@ -260,6 +276,13 @@ macro_rules! impl_packed_array {
                 }
             }
 
+            /// Copies the elements into a fixed-size Rust array, or returns `None` if the length is not exactly `N`.
+            ///
+            /// Useful for reading fixed-layout data, e.g. the 16 components of a 4x4 matrix, without manual length checks.
+            pub fn try_to_array<const N: usize>(&self) -> Option<[$Element; N]> {
+                <&[$Element; N]>::try_from(self.as_slice()).ok().cloned()
+            }
+
             /// Searches the array for the first occurrence of a value and returns its index, or
             /// `None` if not found. Starts searching at index `from`; pass `None` to search the
             /// entire array.
@@ -595,6 +618,75 @@ macro_rules! impl_specific_packed_array_functions {
         pub fn to_int64_array(&self) -> PackedInt64Array {
             self.as_inner().to_int64_array()
         }
+
+        /// Returns a new array with the data compressed using `mode`.
+        ///
+        /// To decompress, the same mode must be used. [`decompress()`][Self::decompress] additionally needs the size of the original data,
+        /// which is not stored in the output -- keep track of it yourself (e.g. in a file header), or use GZIP/DEFLATE with
+        /// [`decompress_dynamic()`][Self::decompress_dynamic].
+        ///
+        /// _Godot equivalent: `PackedByteArray.compress()`_
+        pub fn compress(
+            &self,
+            mode: crate::classes::file_access::CompressionMode,
+        ) -> PackedByteArray {
+            use crate::obj::EngineEnum as _;
+
+            self.as_inner().compress(mode.ord() as i64)
+        }
+
+        /// Decompresses data that was compressed with `mode`, where `buffer_size` is the size of the **original** (uncompressed) data.
+        ///
+        /// `buffer_size` must not be smaller than the original size; a larger value is tolerated, and the result is truncated to the actual
+        /// size. Passing the compressed size by mistake is a common error.
+        ///
+        /// Returns an error if the data could not be decompressed. Since Godot signals failure with an empty result, decompressing to
+        /// an empty array is also reported as an error.
+        ///
+        /// _Godot equivalent: `PackedByteArray.decompress()`_
+        pub fn decompress(
+            &self,
+            buffer_size: usize,
+            mode: crate::classes::file_access::CompressionMode,
+        ) -> Result<PackedByteArray, crate::global::Error> {
+            use crate::obj::EngineEnum as _;
+
+            let decompressed = self
+                .as_inner()
+                .decompress(buffer_size as i64, mode.ord() as i64);
+            Self::check_decompressed(decompressed)
+        }
+
+        /// Decompresses GZIP or DEFLATE data without knowing the original size up front.
+        ///
+        /// The output buffer grows as needed, up to `max_output_size` bytes if specified. Other compression modes are not supported by Godot
+        /// and result in an error. This method is potentially slower than [`decompress()`][Self::decompress], and more memory-hungry for
+        /// untrusted input if no limit is given.
+        ///
+        /// _Godot equivalent: `PackedByteArray.decompress_dynamic()`_
+        pub fn decompress_dynamic(
+            &self,
+            max_output_size: Option<usize>,
+            mode: crate::classes::file_access::CompressionMode,
+        ) -> Result<PackedByteArray, crate::global::Error> {
+            use crate::obj::EngineEnum as _;
+
+            let max_output_size = max_output_size.map_or(-1, |size| size as i64);
+            let decompressed = self
+                .as_inner()
+                .decompress_dynamic(max_output_size, mode.ord() as i64);
+            Self::check_decompressed(decompressed)
+        }
+
+        fn check_decompressed(
+            decompressed: PackedByteArray,
+        ) -> Result<PackedByteArray, crate::global::Error> {
+            if decompressed.is_empty() {
+                Err(crate::global::Error::ERR_INVALID_DATA)
+            } else {
+                Ok(decompressed)
+            }
+        }
     };
     ($PackedArray:ident) => {
         /// Returns a `PackedByteArray` with each value encoded as bytes.
@@ -661,6 +753,68 @@ impl_packed_array!(
     },
 );
 
+macro_rules! impl_packed_int_bitset {
+    ($PackedArray:ident, $Element:ty) => {
+        /// Bit-level access, treating the array as a bitset.
+        ///
+        /// Bit `index` is stored in element `index / BITS`, at bit position `index % BITS` (least significant bit first), where `BITS`
+        /// is the width of the element type. The bitset has a fixed size of [`bit_len()`][Self::bit_len] bits; resize the array to
+        /// change it. Useful for compact grids such as tile or voxel occupancy maps.
+        impl $PackedArray {
+            /// Returns the number of bits in the array, i.e. `len() * BITS`.
+            pub fn bit_len(&self) -> usize {
+                self.len() * <$Element>::BITS as usize
+            }
+
+            /// Returns whether the bit at `index` is set.
+            ///
+            /// # Panics
+            /// If `index` is out of bounds, i.e. not less than [`bit_len()`][Self::bit_len].
+            pub fn get_bit(&self, index: usize) -> bool {
+                let (element, mask) = self.bit_position(index);
+                self[element] & mask != 0
+            }
+
+            /// Sets the bit at `index` to `value`.
+            ///
+            /// # Panics
+            /// If `index` is out of bounds, i.e. not less than [`bit_len()`][Self::bit_len].
+            pub fn set_bit(&mut self, index: usize, value: bool) {
+                let (element, mask) = self.bit_position(index);
+                if value {
+                    self[element] |= mask;
+                } else {
+                    self[element] &= !mask;
+                }
+            }
+
+            /// Returns the number of set bits in the whole array.
+            ///
+            /// Processes all elements through a single [`as_slice()`][Self::as_slice], without per-element FFI calls.
+            pub fn count_ones(&self) -> usize {
+                self.as_slice()
+                    .iter()
+                    .map(|element| element.count_ones() as usize)
+                    .sum()
+            }
+
+            /// Returns element index and bit mask for a bit index.
+            fn bit_position(&self, index: usize) -> (usize, $Element) {
+                let bits = <$Element>::BITS as usize;
+                let bit_len = self.bit_len();
+                if index >= bit_len {
+                    panic!("Bit index {index} is out of bounds: bit length is {bit_len}");
+                }
+
+                (index / bits, 1 << (index % bits))
+            }
+        }
+    };
+}
+
+impl_packed_int_bitset!(PackedInt32Array, i32);
+impl_packed_int_bitset!(PackedInt64Array, i64);
+
 impl_packed_array!(
     type_name: PackedFloat32Array,
     variant_type: PACKED_FLOAT32_ARRAY,
@@ -718,6 +872,32 @@ impl_packed_array!(
     },
 );
 
+/// Creates a `PackedStringArray` from Rust strings.
+///
+/// The array is allocated with its final size once, and each element converted in place.
+impl From<Vec<String>> for PackedStringArray {
+    fn from(strings: Vec<String>) -> Self {
+        if strings.is_empty() {
+            return Self::new();
+        }
+        let mut array = Self::default_with_size(strings.len());
+
+        for (dst, src) in array.as_mut_slice().iter_mut().zip(strings) {
+            *dst = GString::from(src);
+        }
+        array
+    }
+}
+
+/// Converts all elements to Rust strings.
+///
+/// Equivalent to calling `to_string()` on each element of [`PackedStringArray::as_slice()`], but allocates the result only once.
+impl From<&PackedStringArray> for Vec<String> {
+    fn from(array: &PackedStringArray) -> Self {
+        array.as_slice().iter().map(GString::to_string).collect()
+    }
+}
+
 impl_packed_array!(
     type_name: PackedVector2Array,
     variant_type: PACKED_VECTOR2_ARRAY,
@@ -756,6 +936,20 @@ impl_packed_array!(
     },
 );
 
+impl PackedVector3Array {
+    /// Transforms every point in place, i.e. replaces each element `v` with `xform * v`.
+    ///
+    /// The elements are accessed through a single [`as_mut_slice()`][Self::as_mut_slice], so the loop runs entirely in Rust. Indexing
+    /// each element with `array[i]` instead costs an FFI call per access (and `get()`/`set()` even two), which dominates the cost of
+    /// the multiplication itself. For large arrays, expect this to be several times faster; the `packed_vector3_transform_*`
+    /// benchmarks in the integration tests compare both approaches.
+    pub fn transform_all(&mut self, xform: &Transform3D) {
+        for point in self.as_mut_slice() {
+            *point = *xform * *point;
+        }
+    }
+}
+
 #[cfg(since_api = "4.3")]
 impl_packed_array!(
     type_name: PackedVector4Array,
@@ -92,10 +92,20 @@ impl Color {
     ///   Equivalent to `#RRGGBBAA`, i.e. each digit is repeated twice.
     /// - `#RGB` and `RGB`. Equivalent to `#RRGGBBff`.
     ///
-    /// Returns `None` if the format is invalid.
-    pub fn from_html<S: Into<GString>>(html: S) -> Option<Self> {
-        let html = html.into();
-        InnerColor::html_is_valid(html.clone()).then(|| InnerColor::html(html))
+    /// Parsing happens in Rust, without an engine round-trip. Returns [`ColorParseError`] if the format is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use godot::builtin::Color;
+    ///
+    /// let orange = Color::from_html("#ff8800").unwrap();
+    /// assert_eq!(orange, Color::from_rgba8(0xff, 0x88, 0x00, 0xff));
+    ///
+    /// assert!(Color::from_html("#ff88").is_ok()); // RGBA shorthand
+    /// assert!(Color::from_html("#ff880").is_err());
+    /// ```
+    pub fn from_html<S: AsRef<str>>(html: S) -> Result<Self, ColorParseError> {
+        parse_html(html.as_ref())
     }
 
     /// Constructs a `Color` from a string, which can be either:
@@ -467,6 +477,23 @@ impl ops::DivAssign<Color> for Color {
     }
 }
 
+impl ops::Div<f32> for Color {
+    type Output = Color;
+    fn div(mut self, rhs: f32) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl ops::DivAssign<f32> for Color {
+    fn div_assign(&mut self, f: f32) {
+        self.r /= f;
+        self.g /= f;
+        self.b /= f;
+        self.a /= f;
+    }
+}
+
 impl ops::Add<Color> for Color {
     type Output = Color;
     fn add(mut self, rhs: Color) -> Self::Output {
@@ -508,6 +535,26 @@ impl ops::Neg for Color {
     }
 }
 
+impl std::iter::Sum<Self> for Color {
+    /// Component-wise sum of all colors in the iterator, starting from transparent black.
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::TRANSPARENT_BLACK, |acc, c| acc + c)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Self> for Color {
+    /// Component-wise sum of all colors in the iterator, starting from transparent black.
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::TRANSPARENT_BLACK, |acc, c| acc + *c)
+    }
+}
+
 /// Converts a single channel byte to a float in the range 0 to 1.
 fn from_u8(byte: u8) -> f32 {
     byte as f32 / 255.0
@@ -552,6 +599,81 @@ fn to_be_words(mut u: u64) -> [u16; 4] {
     [x, y, z, w]
 }
 
+/// Parses an HTML color code as documented in [`Color::from_html`].
+fn parse_html(html: &str) -> Result<Color, ColorParseError> {
+    let digits = html.strip_prefix('#').unwrap_or(html);
+
+    let len = digits.chars().count();
+    if !matches!(len, 3 | 4 | 6 | 8) {
+        return Err(ColorParseError::InvalidLength {
+            html: html.to_string(),
+            len,
+        });
+    }
+
+    let mut nibbles = [0u8; 8];
+    for (position, digit) in digits.chars().enumerate() {
+        let Some(value) = digit.to_digit(16) else {
+            return Err(ColorParseError::InvalidDigit {
+                html: html.to_string(),
+                digit,
+                position,
+            });
+        };
+        nibbles[position] = value as u8;
+    }
+
+    // Short forms repeat each digit (0xa -> 0xaa); alpha defaults to 0xff.
+    let channel = |i: usize| -> u8 {
+        match len {
+            3 | 4 => nibbles[i] * 0x11,
+            _ => (nibbles[2 * i] << 4) | nibbles[2 * i + 1],
+        }
+    };
+
+    let alpha = if len == 4 || len == 8 {
+        channel(3)
+    } else {
+        0xff
+    };
+    Ok(Color::from_rgba8(channel(0), channel(1), channel(2), alpha))
+}
+
+/// Error returned by [`Color::from_html`] when the string is not a valid HTML color code.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ColorParseError {
+    /// The number of hex digits (without the optional `#` prefix) is not 3, 4, 6 or 8.
+    InvalidLength { html: String, len: usize },
+
+    /// A character is not a hex digit. `position` is counted without the optional `#` prefix.
+    InvalidDigit {
+        html: String,
+        digit: char,
+        position: usize,
+    },
+}
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength { html, len } => write!(
+                f,
+                "invalid HTML color `{html}`: expected 3, 4, 6 or 8 hex digits, found {len}"
+            ),
+            Self::InvalidDigit {
+                html,
+                digit,
+                position,
+            } => write!(
+                f,
+                "invalid HTML color `{html}`: `{digit}` at position {position} is not a hex digit"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
 impl std::fmt::Display for Color {
     /// Formats `Color` to match Godot's string representation.
     ///
@@ -568,6 +690,51 @@ impl std::fmt::Display for Color {
 
 #[cfg(test)]
 mod test {
+    use super::{Color, ColorParseError};
+
+    #[test]
+    fn from_html() {
+        let expected = Color::from_rgba8(0xaa, 0xbb, 0xcc, 0xdd);
+        assert_eq!(Color::from_html("#aabbccdd"), Ok(expected));
+        assert_eq!(Color::from_html("AABBCCDD"), Ok(expected));
+        assert_eq!(Color::from_html("#abcd"), Ok(expected));
+        assert_eq!(Color::from_html("abcd"), Ok(expected));
+
+        let opaque = Color::from_rgba8(0xff, 0x88, 0x00, 0xff);
+        assert_eq!(Color::from_html("#ff8800"), Ok(opaque));
+        assert_eq!(Color::from_html("#f80"), Ok(opaque));
+    }
+
+    #[test]
+    fn from_html_invalid() {
+        assert_eq!(
+            Color::from_html("#abcde"),
+            Err(ColorParseError::InvalidLength {
+                html: "#abcde".to_string(),
+                len: 5
+            })
+        );
+        assert_eq!(
+            Color::from_html("#abcg"),
+            Err(ColorParseError::InvalidDigit {
+                html: "#abcg".to_string(),
+                digit: 'g',
+                position: 3
+            })
+        );
+        assert!(Color::from_html("").is_err());
+        assert!(Color::from_html("#").is_err());
+        assert!(Color::from_html("##abc").is_err());
+        assert!(Color::from_html("+1234").is_err());
+        assert!(Color::from_html("äbc").is_err());
+
+        let err = Color::from_html("#12").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid HTML color `#12`: expected 3, 4, 6 or 8 hex digits, found 2"
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -576,4 +743,12 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&color, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_exact() {
+        let color = super::Color::from_rgba(0.1, 1.0 / 3.0, 0.7, f32::MIN_POSITIVE);
+
+        crate::builtin::test_utils::roundtrip_exact(&color);
+    }
 }
@@ -82,7 +82,7 @@ pub mod iter {
 
 /// Specialized types related to Godot's various string implementations.
 pub mod strings {
-    pub use super::string::TransientStringNameOrd;
+    pub use super::string::{GStringBuilder, TransientStringNameOrd};
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -153,4 +153,21 @@ pub(crate) mod test_utils {
             "value does not conform to expected JSON"
         );
     }
+
+    /// Checks that floats survive a JSON round-trip bit by bit.
+    ///
+    /// `Debug` prints the shortest representation that uniquely identifies a float, so equal output means identical bits.
+    pub(crate) fn roundtrip_exact<T>(value: &T)
+    where
+        T: for<'a> Deserialize<'a> + Serialize + PartialEq + std::fmt::Debug,
+    {
+        let json: String = serde_json::to_string(value).unwrap();
+        let back: T = serde_json::from_str(json.as_str()).unwrap();
+
+        assert_eq!(
+            format!("{back:?}"),
+            format!("{value:?}"),
+            "serde round-trip is not bit-identical: {json}"
+        );
+    }
 }
@@ -78,6 +78,12 @@ impl Quaternion {
         }
     }
 
+    /// Constructs a quaternion from Euler angles in radians, using the YXZ convention.
+    ///
+    /// This means the rotation around Z (roll) is applied first, then X (pitch), and Y (yaw) last. For other conventions, use
+    /// [`from_euler_with_order()`][Self::from_euler_with_order].
+    ///
+    /// _Godot equivalent: `Quaternion.from_euler(Vector3 euler)`_
     pub fn from_euler(euler: Vector3) -> Self {
         let half_a1 = euler.y * 0.5;
         let half_a2 = euler.x * 0.5;
@@ -97,6 +103,13 @@ impl Quaternion {
         )
     }
 
+    /// Constructs a quaternion from Euler angles in radians, applied in the given `order`.
+    ///
+    /// Inverse of [`to_euler()`][Self::to_euler] with the same order, for angles within the range that `to_euler()` returns.
+    pub fn from_euler_with_order(order: EulerOrder, euler: Vector3) -> Self {
+        Basis::from_euler(order, euler).to_quat()
+    }
+
     pub fn get_angle(self) -> real {
         2.0 * self.w.acos()
     }
@@ -113,6 +126,10 @@ impl Quaternion {
         }
     }
 
+    /// Returns the rotation as Euler angles in radians, in the given `order`.
+    ///
+    /// _Godot equivalent: `Quaternion.get_euler(int order)`_
+    #[doc(alias = "get_euler")]
     pub fn to_euler(self, order: EulerOrder) -> Vector3 {
         Basis::from_quat(self).to_euler(order)
     }
@@ -150,22 +167,62 @@ impl Quaternion {
         self / length
     }
 
+    /// Spherical linear interpolation between `self` and `to`, along the shortest path.
+    ///
+    /// Like in Godot, this falls back to linear interpolation if both quaternions are (nearly) equal or antipodal, as the arc is
+    /// not well-defined in that case.
+    ///
     /// # Panics
     /// If either quaternion is not normalized.
     pub fn slerp(self, to: Self, weight: real) -> Self {
         let normalized_inputs = self.ensure_normalized(&[&to]);
         assert!(normalized_inputs, "Slerp requires normalized quaternions");
 
-        self.as_inner().slerp(to, weight.as_f64())
+        // Take the shortest path: q and -q represent the same rotation.
+        let mut cos_omega = self.dot(to);
+        let to = if cos_omega < 0.0 {
+            cos_omega = -cos_omega;
+            -to
+        } else {
+            to
+        };
+
+        let (scale_from, scale_to) = if 1.0 - cos_omega > real::CMP_EPSILON {
+            let omega = cos_omega.acos();
+            let sin_omega = omega.sin();
+
+            (
+                ((1.0 - weight) * omega).sin() / sin_omega,
+                (weight * omega).sin() / sin_omega,
+            )
+        } else {
+            (1.0 - weight, weight)
+        };
+
+        self * scale_from + to * scale_to
     }
 
+    /// Spherical linear interpolation between `self` and `to`, without checking for the shortest path.
+    ///
+    /// Returns `self` unchanged if both quaternions are (nearly) equal or antipodal.
+    ///
     /// # Panics
     /// If either quaternion is not normalized.
     pub fn slerpni(self, to: Self, weight: real) -> Self {
         let normalized_inputs = self.ensure_normalized(&[&to]);
         assert!(normalized_inputs, "Slerpni requires normalized quaternions");
 
-        self.as_inner().slerpni(to, weight.as_f64())
+        let dot = self.dot(to);
+        if dot.abs() > 0.9999 {
+            return self;
+        }
+
+        let theta = dot.acos();
+        let inv_sin_theta = 1.0 / theta.sin();
+        let scale_to = (weight * theta).sin() * inv_sin_theta;
+        let scale_from = ((1.0 - weight) * theta).sin() * inv_sin_theta;
+
+        self * scale_from + to * scale_to
     }
 
     /// # Panics
@@ -392,6 +449,64 @@ impl Neg for Quaternion {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::builtin::math::assert_eq_approx;
+    use crate::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn slerp_midpoint() {
+        let a = Quaternion::default();
+        let b = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+        let mid = a.slerp(b, 0.5);
+        assert_eq_approx!(mid, Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_4));
+        assert_eq_approx!(a.slerp(b, 0.0), a);
+        assert_eq_approx!(a.slerp(b, 1.0), b);
+
+        // Without shortest-path correction, slerpni yields the same result for quaternions less than 180° apart.
+        assert_eq_approx!(a.slerpni(b, 0.5), mid);
+    }
+
+    #[test]
+    fn slerp_nearly_equal_and_antipodal() {
+        let a = Quaternion::from_axis_angle(Vector3::RIGHT, 0.3);
+        let nearly_a = Quaternion::from_axis_angle(Vector3::RIGHT, 0.3 + 1e-4);
+
+        // Falls back to linear interpolation.
+        assert_eq_approx!(a.slerp(nearly_a, 0.5), (a + nearly_a) * 0.5);
+        assert_eq_approx!(a.slerp(a, 0.5), a);
+
+        // -a represents the same rotation as a; the sign is flipped and then interpolated linearly.
+        assert_eq_approx!(a.slerp(-a, 0.5), a);
+
+        // slerpni returns self in both cases.
+        assert_eq!(a.slerpni(nearly_a, 0.5), a);
+        assert_eq!(a.slerpni(-a, 0.5), a);
+    }
+
+    #[test]
+    fn from_euler_orders() {
+        let euler = Vector3::new(0.3, -0.7, 1.1);
+
+        assert_eq_approx!(
+            Quaternion::from_euler_with_order(EulerOrder::YXZ, euler),
+            Quaternion::from_euler(euler)
+        );
+
+        for order in [
+            EulerOrder::XYZ,
+            EulerOrder::XZY,
+            EulerOrder::YXZ,
+            EulerOrder::YZX,
+            EulerOrder::ZXY,
+            EulerOrder::ZYX,
+        ] {
+            let quat = Quaternion::from_euler_with_order(order, euler);
+            assert!(quat.is_normalized(), "{order:?}");
+            assert_eq_approx!(quat.to_euler(order), euler, "{order:?}");
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -187,10 +187,13 @@ impl Rect2 {
         point.abs() == point && point.x < self.size.x && point.y < self.size.y
     }
 
-    /// Returns the intersection of this Rect2 and `b`. If the rectangles do not intersect, an empty Rect2 is returned.
+    /// Returns the intersection of this Rect2 and `b`.
+    ///
+    /// If the rectangles do not intersect, `None` is returned. Like in Godot, rectangles that only share a border or corner do not
+    /// intersect. See [`intersection_or_empty`][Self::intersection_or_empty] for Godot's behavior of returning an empty `Rect2` instead.
     #[inline]
     pub fn intersection(self, b: Self) -> Option<Self> {
-        if !self.intersects(b) {
+        if !self.intersects_exclude_borders(b) {
             return None;
         }
 
@@ -204,6 +207,17 @@ impl Rect2 {
         Some(rect)
     }
 
+    /// Returns the intersection of this Rect2 and `b`, or an empty Rect2 if they do not intersect.
+    ///
+    /// Same as [`intersection`][Self::intersection], except that `Rect2::default()` is returned for rectangles that are disjoint or only
+    /// share a border or corner.
+    ///
+    /// _Godot equivalent: `Rect2.intersection(Rect2 b)`_
+    #[inline]
+    pub fn intersection_or_empty(self, b: Self) -> Self {
+        self.intersection(b).unwrap_or_default()
+    }
+
     /// Checks whether two rectangles have at least one point in common.
     ///
     /// Also returns `true` if the rects only touch each other (share a point/edge).
@@ -308,6 +322,52 @@ impl std::fmt::Display for Rect2 {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    #[test]
+    fn intersection_touching() {
+        let a = Rect2::from_components(0.0, 0.0, 2.0, 2.0);
+        let edge = Rect2::from_components(2.0, 0.0, 1.0, 1.0);
+        let corner = Rect2::from_components(2.0, 2.0, 1.0, 1.0);
+        let disjoint = Rect2::from_components(5.0, 5.0, 1.0, 1.0);
+        let overlap = Rect2::from_components(1.0, 1.0, 2.0, 2.0);
+
+        // Touching rects intersect, but have no common area.
+        assert!(a.intersects(edge));
+        assert!(a.intersects(corner));
+        assert_eq!(a.intersection(edge), None);
+        assert_eq!(a.intersection(corner), None);
+        assert_eq!(a.intersection(disjoint), None);
+        assert_eq!(a.intersection_or_empty(edge), Rect2::default());
+        assert_eq!(a.intersection_or_empty(corner), Rect2::default());
+        assert_eq!(a.intersection_or_empty(disjoint), Rect2::default());
+
+        assert_eq!(
+            a.intersection(overlap),
+            Some(Rect2::from_components(1.0, 1.0, 1.0, 1.0))
+        );
+        assert_eq!(a.intersection(a), Some(a));
+        assert_eq!(a.intersection_or_empty(a), a);
+    }
+
+    #[test]
+    fn merge_and_encloses() {
+        let a = Rect2::from_components(0.0, 0.0, 2.0, 2.0);
+        let corner = Rect2::from_components(2.0, 2.0, 1.0, 1.0);
+        let inner = Rect2::from_components(0.5, 0.5, 1.0, 1.0);
+
+        let merged = a.merge(corner);
+        assert_eq!(merged, Rect2::from_components(0.0, 0.0, 3.0, 3.0));
+        assert!(merged.encloses(a));
+        assert!(merged.encloses(corner));
+        assert!(a.encloses(inner));
+        assert!(a.encloses(a));
+        assert!(!a.encloses(corner));
+
+        assert_eq!(a.grow(1.0), Rect2::from_components(-1.0, -1.0, 4.0, 4.0));
+        assert_eq!(a.grow(-1.0), Rect2::from_components(1.0, 1.0, 0.0, 0.0));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -316,4 +376,15 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&rect, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_exact() {
+        let rect = super::Rect2::new(
+            crate::builtin::Vector2::new(0.1, -0.2),
+            crate::builtin::Vector2::new(1.0 / 3.0, 1e-30),
+        );
+
+        crate::builtin::test_utils::roundtrip_exact(&rect);
+    }
 }
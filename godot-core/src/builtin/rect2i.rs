@@ -219,9 +219,8 @@ impl Rect2i {
 
     /// Returns the intersection of this `Rect2i` and `b`.
     ///
-    /// If the rectangles do not intersect, `None` is returned.
-    ///
-    /// Note that rectangles that only share a border do not intersect.
+    /// If the rectangles do not intersect, `None` is returned. Like in Godot, rectangles that only share a border or corner do not
+    /// intersect. See [`intersection_or_empty`][Self::intersection_or_empty] for Godot's behavior of returning an empty `Rect2i` instead.
     #[inline]
     pub fn intersection(self, b: Self) -> Option<Self> {
         self.assert_nonnegative();
@@ -243,6 +242,17 @@ impl Rect2i {
         Some(Self::from_corners(new_pos, new_end))
     }
 
+    /// Returns the intersection of this `Rect2i` and `b`, or an empty `Rect2i` if they do not intersect.
+    ///
+    /// Same as [`intersection`][Self::intersection], except that `Rect2i::default()` is returned for rectangles that are disjoint or only
+    /// share a border or corner.
+    ///
+    /// _Godot equivalent: `Rect2i.intersection(Rect2i b)`_
+    #[inline]
+    pub fn intersection_or_empty(self, b: Self) -> Self {
+        self.intersection(b).unwrap_or_default()
+    }
+
     /// Returns `true` if the `Rect2i` overlaps with `b` (i.e. they have at least one
     /// point in common)
     #[inline]
@@ -567,6 +577,25 @@ mod test {
 
         assert!(c.intersects(d));
         assert_eq!(c.intersection(d), Some(c));
+
+        assert_eq!(a.intersection_or_empty(b), b);
+        assert_eq!(b.intersection_or_empty(c), Rect2i::default());
+    }
+
+    #[test]
+    fn intersection_touching() {
+        let a = Rect2i::from_components(0, 0, 2, 2);
+        let edge = Rect2i::from_components(2, 0, 1, 1);
+        let corner = Rect2i::from_components(2, 2, 1, 1);
+
+        // Unlike Rect2::intersects(), touching Rect2i never intersect, same as in Godot.
+        assert!(!a.intersects(edge));
+        assert!(!a.intersects(corner));
+        assert_eq!(a.intersection(edge), None);
+        assert_eq!(a.intersection(corner), None);
+        assert_eq!(a.intersection_or_empty(edge), Rect2i::default());
+        assert_eq!(a.intersection_or_empty(corner), Rect2i::default());
+        assert_eq!(a.intersection_or_empty(a), a);
     }
 
     #[test]
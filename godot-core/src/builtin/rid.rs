@@ -100,6 +100,14 @@ impl Rid {
     }
 }
 
+impl Default for Rid {
+    /// Returns [`Rid::Invalid`], like Godot's default-constructed `RID()`.
+    #[inline]
+    fn default() -> Self {
+        Rid::Invalid
+    }
+}
+
 impl std::fmt::Display for Rid {
     /// Formats `Rid` to match Godot's string representation.
     ///
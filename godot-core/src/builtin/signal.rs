@@ -6,22 +6,27 @@
  */
 
 use std::fmt;
+use std::marker::PhantomData;
 use std::ptr;
 
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::classes::object::ConnectFlags;
 use crate::classes::Object;
 use crate::global::Error;
 use crate::meta::{FromGodot, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
 ///
 /// Signals are composed of a reference to an `Object` and the name of the signal on this object.
 ///
+/// Like in GDScript, a `Signal` is a value: it can be converted to and from [`Variant`], stored in containers such as
+/// [`Dictionary`], or passed to and returned from `#[func]` methods. Connecting or emitting through any copy acts on the same signal.
+///
 /// # Godot docs
 ///
 /// [`Signal` (stable)](https://docs.godotengine.org/en/stable/classes/class_signal.html)
@@ -98,6 +103,15 @@ impl Signal {
         object.emit_signal(self.name(), varargs);
     }
 
+    /// Returns a future that resolves with the arguments of the next emission of this signal.
+    ///
+    /// The future connects a one-shot callable to this signal; see [`SignalFuture`] for details on polling, cancellation
+    /// and freed objects.
+    #[cfg(since_api = "4.2")]
+    pub fn to_future(&self) -> SignalFuture {
+        SignalFuture::new(self)
+    }
+
     /// Returns an [`Array`] of connections for this signal.
     ///
     /// Each connection is represented as a Dictionary that contains three entries:
@@ -157,6 +171,181 @@ impl Signal {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A signal of a specific object with parameter types `Ps`, as returned by the methods of a signal collection (see [`Gd::signals()`]).
+///
+/// `Ps` is a tuple of the signal's parameter types, e.g. `(Gd<Node>,)` for `Node::child_entered_tree`. The type is used by
+/// [`emit()`][Self::emit], so emitting with wrong arguments fails to compile instead of at runtime.
+///
+/// Unlike [`Signal`], connecting does not require a flags bitmask. Instead, [`connect()`][Self::connect] returns a [`ConnectBuilder`],
+/// on which flags such as [`one_shot()`][ConnectBuilder::one_shot] can be set before [`done()`][ConnectBuilder::done] connects.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::Button;
+/// # fn example(button: Gd<Button>, callable: Callable) {
+/// // Called once on the next emission, at idle time.
+/// button.signals().pressed().connect(callable).one_shot().deferred().done();
+///
+/// // Parameters are typed: `toggled` takes a `bool`.
+/// button.signals().toggled().emit((true,));
+/// # }
+/// ```
+pub struct TypedSignal<Ps> {
+    signal: Signal,
+    _params: PhantomData<fn(Ps)>,
+}
+
+impl<Ps: SignalParams> TypedSignal<Ps> {
+    #[doc(hidden)]
+    pub fn __new(object: &Gd<Object>, signal_name: &str) -> Self {
+        Self {
+            signal: Signal::from_object_signal(object, signal_name),
+            _params: PhantomData,
+        }
+    }
+
+    /// Prepares a connection of the signal to `callable`.
+    ///
+    /// Nothing is connected until [`ConnectBuilder::done()`] is called, so that all flags are in place from the start.
+    pub fn connect(&self, callable: Callable) -> ConnectBuilder {
+        ConnectBuilder {
+            signal: self.signal.clone(),
+            callable,
+            flags: ConnectFlags::default(),
+        }
+    }
+
+    /// Disconnects `callable` from the signal.
+    ///
+    /// Connections made with [`ConnectBuilder::reference_counted()`] need to be disconnected as many times as they were connected.
+    /// If the connection does not exist, Godot prints an error.
+    pub fn disconnect(&self, callable: Callable) {
+        self.signal.disconnect(callable);
+    }
+
+    /// Returns `true` if `callable` is connected to the signal.
+    pub fn is_connected(&self, callable: Callable) -> bool {
+        self.signal.is_connected(callable)
+    }
+
+    /// Emits the signal with the given arguments, one tuple element per signal parameter.
+    pub fn emit(&self, params: Ps) {
+        self.signal.emit(&params.to_variants());
+    }
+
+    /// Returns the untyped [`Signal`], e.g. to pass it to Godot APIs or to await it.
+    pub fn to_signal(&self) -> Signal {
+        self.signal.clone()
+    }
+}
+
+impl<Ps> fmt::Debug for TypedSignal<Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedSignal")
+            .field("signal", &self.signal)
+            .finish()
+    }
+}
+
+/// Builder for a signal connection, returned by [`TypedSignal::connect()`].
+///
+/// Flag methods only record the [`ConnectFlags`]. The connection is made exactly once, with all flags, by [`done()`][Self::done].
+#[must_use = "the connection is only made when calling `done()`"]
+#[derive(Debug)]
+pub struct ConnectBuilder {
+    signal: Signal,
+    callable: Callable,
+    flags: ConnectFlags,
+}
+
+impl ConnectBuilder {
+    /// Disconnects the callable automatically after its first invocation.
+    ///
+    /// _Godot equivalent: `CONNECT_ONE_SHOT`_
+    pub fn one_shot(self) -> Self {
+        self.with_flags(ConnectFlags::ONE_SHOT)
+    }
+
+    /// Defers the call to idle time, instead of invoking the callable immediately on emission.
+    ///
+    /// _Godot equivalent: `CONNECT_DEFERRED`_
+    pub fn deferred(self) -> Self {
+        self.with_flags(ConnectFlags::DEFERRED)
+    }
+
+    /// Saves the connection when the object is serialized to a scene file.
+    ///
+    /// _Godot equivalent: `CONNECT_PERSIST`_
+    pub fn persist(self) -> Self {
+        self.with_flags(ConnectFlags::PERSIST)
+    }
+
+    /// Allows connecting the same callable multiple times; it must be disconnected as many times as it was connected.
+    ///
+    /// _Godot equivalent: `CONNECT_REFERENCE_COUNTED`_
+    pub fn reference_counted(self) -> Self {
+        self.with_flags(ConnectFlags::REFERENCE_COUNTED)
+    }
+
+    /// Adds arbitrary flags, e.g. ones not covered by the dedicated methods.
+    pub fn with_flags(mut self, flags: ConnectFlags) -> Self {
+        self.flags = self.flags | flags;
+        self
+    }
+
+    /// Returns the flags that [`done()`][Self::done] will connect with.
+    pub fn flags(&self) -> ConnectFlags {
+        self.flags
+    }
+
+    /// Connects the callable to the signal, using all flags set so far.
+    ///
+    /// Returns the same errors as [`Signal::connect()`], e.g. [`Error::ERR_INVALID_PARAMETER`] if the callable is already connected
+    /// without [`reference_counted()`][Self::reference_counted].
+    pub fn done(self) -> Error {
+        self.signal.connect(self.callable, self.flags.ord() as i64)
+    }
+}
+
+/// Parameter types of a [`TypedSignal`], as a tuple.
+///
+/// Implemented for tuples of up to 14 elements, each implementing [`ToGodot`].
+pub trait SignalParams {
+    /// Converts the parameters to variants, in declaration order.
+    fn to_variants(&self) -> Vec<Variant>;
+}
+
+macro_rules! impl_signal_params_for_tuple {
+    ($($P:ident : $n:tt),*) => {
+        impl<$($P: ToGodot),*> SignalParams for ($($P,)*) {
+            fn to_variants(&self) -> Vec<Variant> {
+                vec![ $( self.$n.to_variant() ),* ]
+            }
+        }
+    };
+}
+
+impl_signal_params_for_tuple!();
+impl_signal_params_for_tuple!(P0: 0);
+impl_signal_params_for_tuple!(P0: 0, P1: 1);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8, P9: 9);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8, P9: 9, P10: 10);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8, P9: 9, P10: 10, P11: 11);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8, P9: 9, P10: 10, P11: 11, P12: 12);
+impl_signal_params_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8, P9: 9, P10: 10, P11: 11, P12: 12, P13: 13);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 // SAFETY:
 // The `opaque` in `Signal` is just a pair of pointers, and requires no special initialization or cleanup
 // beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
@@ -208,3 +397,234 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Futures
+
+#[cfg(since_api = "4.2")]
+pub use signal_future::*;
+
+#[cfg(since_api = "4.2")]
+mod signal_future {
+    use std::error::Error as StdError;
+    use std::fmt;
+    use std::future::Future;
+    use std::hash::{Hash, Hasher};
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+
+    use crate::builtin::{Callable, RustCallable, Signal, Variant};
+    use crate::classes::object::ConnectFlags;
+    use crate::global::Error;
+    use crate::obj::EngineBitfield;
+
+    /// Future returned by [`Signal::to_future()`], resolving with the arguments of the next signal emission.
+    ///
+    /// The future is executor-agnostic: it only stores the [`Waker`] of the last poll and wakes it once the signal fires.
+    /// Since signals are emitted by Godot, the future needs to be polled on a thread that the emitting object may be accessed from
+    /// (usually the main thread).
+    ///
+    /// # Cancellation
+    /// Dropping a pending `SignalFuture` disconnects it from the signal. No callable is left behind, and a later emission
+    /// has no effect.
+    ///
+    /// # Freed objects
+    /// If the object owning the signal is freed before the signal is emitted, the future resolves to
+    /// [`SignalFutureError::ObjectFreed`] instead of staying pending forever.
+    ///
+    /// # Panics
+    /// Like most futures, `SignalFuture` must not be polled again after it has returned [`Poll::Ready`]. Doing so panics.
+    pub struct SignalFuture {
+        state: Arc<Mutex<WaiterState>>,
+        signal: Signal,
+        id: u64,
+    }
+
+    impl SignalFuture {
+        pub(super) fn new(signal: &Signal) -> Self {
+            static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let state = Arc::new(Mutex::new(WaiterState::default()));
+
+            let error = signal.connect(
+                Callable::from_custom(SignalWaiter::new(id, &state)),
+                ConnectFlags::ONE_SHOT.ord() as i64,
+            );
+
+            if error != Error::OK {
+                // No waker registered yet.
+                let _ = state
+                    .lock()
+                    .unwrap()
+                    .finish(Err(SignalFutureError::ConnectFailed(error)));
+            }
+
+            Self {
+                state,
+                signal: signal.clone(),
+                id,
+            }
+        }
+    }
+
+    impl Future for SignalFuture {
+        type Output = Result<Vec<Variant>, SignalFutureError>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let mut state = self.state.lock().unwrap();
+
+            if let Some(result) = state.result.take() {
+                return Poll::Ready(result);
+            }
+
+            if !state.is_finished {
+                state.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+
+            // Release the lock first, so it is not poisoned by the panic.
+            drop(state);
+            panic!("SignalFuture polled after completion");
+        }
+    }
+
+    impl Drop for SignalFuture {
+        fn drop(&mut self) {
+            if self.state.lock().unwrap().is_finished {
+                return;
+            }
+
+            // Still connected: remove the waiter, so the signal doesn't keep a dangling one-shot connection.
+            if self.signal.object().is_some() {
+                // Equality of custom callables only compares the ID, so this refers to the connected waiter.
+                let callable = Callable::from_custom(SignalWaiter::new(self.id, &self.state));
+
+                if self.signal.is_connected(callable.clone()) {
+                    self.signal.disconnect(callable);
+                }
+            }
+        }
+    }
+
+    impl fmt::Debug for SignalFuture {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SignalFuture")
+                .field("signal", &self.signal)
+                .field("is_finished", &self.state.lock().unwrap().is_finished)
+                .finish()
+        }
+    }
+
+    /// Error returned by [`SignalFuture`] if the signal can no longer be awaited.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum SignalFutureError {
+        /// The object owning the signal was freed before the signal was emitted.
+        ObjectFreed,
+
+        /// Connecting to the signal failed, e.g. because the signal does not exist on the object.
+        ConnectFailed(Error),
+    }
+
+    impl fmt::Display for SignalFutureError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::ObjectFreed => write!(f, "object was freed before the signal was emitted"),
+                Self::ConnectFailed(error) => write!(f, "failed to connect to signal: {error:?}"),
+            }
+        }
+    }
+
+    impl StdError for SignalFutureError {}
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Implementation
+
+    #[derive(Default)]
+    struct WaiterState {
+        result: Option<Result<Vec<Variant>, SignalFutureError>>,
+        waker: Option<Waker>,
+
+        /// Set once a result has been stored; the result itself is moved out on poll.
+        is_finished: bool,
+    }
+
+    impl WaiterState {
+        /// Stores the result, unless one has been stored before.
+        ///
+        /// Returns the waker to notify. It must be woken only after the lock on the state is released, as waking may poll the future
+        /// synchronously on some executors.
+        #[must_use]
+        fn finish(&mut self, result: Result<Vec<Variant>, SignalFutureError>) -> Option<Waker> {
+            if self.is_finished {
+                return None;
+            }
+
+            self.result = Some(result);
+            self.is_finished = true;
+            self.waker.take()
+        }
+    }
+
+    /// Callable connected to the signal. Godot destroys it either after the one-shot emission or when the object is freed.
+    struct SignalWaiter {
+        id: u64,
+        state: Arc<Mutex<WaiterState>>,
+    }
+
+    impl SignalWaiter {
+        fn new(id: u64, state: &Arc<Mutex<WaiterState>>) -> Self {
+            Self {
+                id,
+                state: Arc::clone(state),
+            }
+        }
+    }
+
+    impl RustCallable for SignalWaiter {
+        fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+            let args = args.iter().map(|&arg| arg.clone()).collect();
+            let waker = self.state.lock().unwrap().finish(Ok(args));
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+
+            Ok(Variant::nil())
+        }
+    }
+
+    impl Drop for SignalWaiter {
+        fn drop(&mut self) {
+            // No-op if the signal has already been emitted or the future has been dropped.
+            let waker = match self.state.lock() {
+                Ok(mut state) => state.finish(Err(SignalFutureError::ObjectFreed)),
+                Err(_) => None,
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+
+    impl PartialEq for SignalWaiter {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    impl Hash for SignalWaiter {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.id.hash(state);
+        }
+    }
+
+    impl fmt::Display for SignalWaiter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "SignalFuture::waiter#{}", self.id)
+        }
+    }
+}
@@ -12,7 +12,11 @@ use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::{inner, NodePath, StringName};
+use crate::builtin::{inner, Dictionary, NodePath, StringName, VariantArray};
+use crate::meta::ToGodot;
+
+/// Placeholder pattern used by Godot's `String.format()`, where `_` stands for the index or key.
+const DEFAULT_PLACEHOLDER: &str = "{_}";
 
 /// Godot's reference counted string type.
 ///
@@ -85,6 +89,86 @@ impl GString {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Substitutes `{0}`, `{1}`, ... placeholders with the elements of `args`, by position.
+    ///
+    /// Delegates to Godot's `String.format()` and thus follows its exact rules: elements are converted with `str()`, and placeholders
+    /// without a matching index are left untouched. There is no escape syntax for literal braces. Godot also accepts an array of
+    /// `[key, value]` pairs, which substitutes `{key}` placeholders like [`format_dict()`][Self::format_dict].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let template = GString::from("{0} has {1} HP");
+    /// let text = template.format_array(&varray!["Goblin", 12]);
+    /// assert_eq!(text, "Goblin has 12 HP".into());
+    /// ```
+    ///
+    /// _Godot equivalent: `String.format(Array)`_
+    #[doc(alias = "format")]
+    pub fn format_array(&self, args: &VariantArray) -> GString {
+        self.as_inner()
+            .format(args.to_variant(), GString::from(DEFAULT_PLACEHOLDER))
+    }
+
+    /// Substitutes `{key}` placeholders with the values of the corresponding keys in `args`.
+    ///
+    /// Delegates to Godot's `String.format()` and thus follows its exact rules: keys and values are converted with `str()`, and
+    /// placeholders without a matching key are left untouched. There is no escape syntax for literal braces.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let template = GString::from("{name} has {hp} HP");
+    /// let text = template.format_dict(&dict! { "name": "Goblin", "hp": 12 });
+    /// assert_eq!(text, "Goblin has 12 HP".into());
+    /// ```
+    ///
+    /// _Godot equivalent: `String.format(Dictionary)`_
+    pub fn format_dict(&self, args: &Dictionary) -> GString {
+        self.as_inner()
+            .format(args.to_variant(), GString::from(DEFAULT_PLACEHOLDER))
+    }
+
+    /// Splits the string at each occurrence of `delimiter`.
+    ///
+    /// Delegates to Godot's `String.split()`, so edge cases behave exactly as in GDScript: with `allow_empty`, empty substrings between
+    /// adjacent delimiters (or at the ends) are included, otherwise they are skipped. An empty `delimiter` splits into single characters.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let parts = GString::from("a,b,,c").split(",", false);
+    /// assert_eq!(parts, ["a", "b", "c"].map(GString::from));
+    /// ```
+    ///
+    /// _Godot equivalent: `String.split()`_
+    pub fn split(&self, delimiter: &str, allow_empty: bool) -> Vec<GString> {
+        self.as_inner()
+            .split(GString::from(delimiter), allow_empty, 0)
+            .as_slice()
+            .to_vec()
+    }
+
+    /// Splits the string at each occurrence of `delimiter` and parses each substring as a float.
+    ///
+    /// Delegates to Godot's `String.split_floats()`. Like there, substrings that are not valid numbers are parsed as `0.0`, and empty
+    /// substrings are only included (as `0.0`) if `allow_empty` is true.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let values = GString::from("1.5,2,,-3").split_floats(",", false);
+    /// assert_eq!(values, [1.5, 2.0, -3.0]);
+    /// ```
+    ///
+    /// _Godot equivalent: `String.split_floats()`_
+    pub fn split_floats(&self, delimiter: &str, allow_empty: bool) -> Vec<f64> {
+        self.as_inner()
+            .split_floats(GString::from(delimiter), allow_empty)
+            .as_slice()
+            .to_vec()
+    }
+
     /// Gets the internal chars slice from a [`GString`].
     pub fn chars(&self) -> &[char] {
         // SAFETY: Godot 4.1 ensures valid UTF-32, making interpreting as char slice safe.
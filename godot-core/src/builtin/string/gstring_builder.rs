@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::GString;
+
+/// Assembles a [`GString`] from many pieces, converting to Godot only once.
+///
+/// Concatenating `GString`s piece by piece crosses the FFI boundary and allocates a new Godot string for every step. This builder
+/// accumulates the text in a Rust [`String`] instead, and creates the `GString` in a single conversion when calling
+/// [`build()`][Self::build]. It implements [`fmt::Write`], so the `write!` and `writeln!` macros can be used.
+///
+/// # Example
+/// ```no_run
+/// use std::fmt::Write;
+/// use godot::builtin::strings::GStringBuilder;
+/// use godot::builtin::{GString, Vector2};
+///
+/// let mut builder = GStringBuilder::new();
+/// for (i, pos) in [Vector2::ZERO, Vector2::ONE].iter().enumerate() {
+///     writeln!(builder, "unit {i}: {pos}").unwrap();
+/// }
+///
+/// let text: GString = builder.build();
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct GStringBuilder {
+    buffer: String,
+}
+
+impl GStringBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty builder, pre-allocating space for `capacity` bytes of UTF-8 text.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a string slice.
+    pub fn push_str(&mut self, string: &str) {
+        self.buffer.push_str(string);
+    }
+
+    /// Appends a single character.
+    pub fn push(&mut self, ch: char) {
+        self.buffer.push(ch);
+    }
+
+    /// Returns `true` if nothing has been appended yet.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns the text accumulated so far, without converting it.
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Converts the accumulated text into a `GString`, in a single FFI call.
+    pub fn build(self) -> GString {
+        GString::from(self.buffer)
+    }
+}
+
+impl fmt::Write for GStringBuilder {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.buffer.push_str(string);
+        Ok(())
+    }
+
+    fn write_char(&mut self, ch: char) -> fmt::Result {
+        self.buffer.push(ch);
+        Ok(())
+    }
+}
+
+impl From<GStringBuilder> for GString {
+    fn from(builder: GStringBuilder) -> Self {
+        builder.build()
+    }
+}
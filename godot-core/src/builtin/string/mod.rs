@@ -8,6 +8,7 @@
 //! Godot-types that are Strings.
 
 mod gstring;
+mod gstring_builder;
 mod macros;
 mod node_path;
 mod string_name;
@@ -16,6 +17,7 @@ use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, GodotConvert, ToGodot};
 
 pub use gstring::*;
+pub use gstring_builder::GStringBuilder;
 pub use node_path::NodePath;
 pub use string_name::{StringName, TransientStringNameOrd};
 
@@ -49,6 +49,120 @@ impl NodePath {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Returns `true` if the path starts from the scene-tree root (begins with `/`).
+    ///
+    /// _Godot equivalent: `NodePath.is_absolute()`_
+    pub fn is_absolute(&self) -> bool {
+        self.as_inner().is_absolute()
+    }
+
+    /// Number of node names in the path, i.e. the `/`-separated parts before any `:property` subnames.
+    ///
+    /// For `^"Path/To/Node:position:x"`, this returns 3.
+    ///
+    /// _Godot equivalent: `NodePath.get_name_count()`_
+    pub fn get_name_count(&self) -> usize {
+        self.as_inner().get_name_count().try_into().unwrap()
+    }
+
+    /// Returns the node name at `index`.
+    ///
+    /// For `^"Path/To/Node:position:x"`, index 0 is `Path`, 1 is `To` and 2 is `Node`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds, see [`get_name_count()`][Self::get_name_count].
+    ///
+    /// _Godot equivalent: `NodePath.get_name()`_
+    pub fn get_name(&self, index: usize) -> StringName {
+        let count = self.get_name_count();
+        assert!(
+            index < count,
+            "NodePath '{self}': name index {index} out of bounds (count {count})"
+        );
+
+        self.as_inner().get_name(index as i64)
+    }
+
+    /// Number of subnames in the path, i.e. the `:`-separated property parts after the node names.
+    ///
+    /// For `^"Path/To/Node:position:x"`, this returns 2.
+    ///
+    /// _Godot equivalent: `NodePath.get_subname_count()`_
+    pub fn get_subname_count(&self) -> usize {
+        self.as_inner().get_subname_count().try_into().unwrap()
+    }
+
+    /// Returns the subname at `index`.
+    ///
+    /// For `^"Path/To/Node:position:x"`, index 0 is `position` and 1 is `x`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds, see [`get_subname_count()`][Self::get_subname_count].
+    ///
+    /// _Godot equivalent: `NodePath.get_subname()`_
+    pub fn get_subname(&self, index: usize) -> StringName {
+        let count = self.get_subname_count();
+        assert!(
+            index < count,
+            "NodePath '{self}': subname index {index} out of bounds (count {count})"
+        );
+
+        self.as_inner().get_subname(index as i64)
+    }
+
+    /// Returns all node names joined by `/`, without subnames and without the leading `/` of absolute paths.
+    ///
+    /// For `^"/root/Path/To/Node:position:x"`, this returns `root/Path/To/Node`.
+    ///
+    /// _Godot equivalent: `NodePath.get_concatenated_names()`_
+    pub fn get_concatenated_names(&self) -> StringName {
+        self.as_inner().get_concatenated_names()
+    }
+
+    /// Returns all subnames joined by `:`.
+    ///
+    /// For `^"Path/To/Node:position:x"`, this returns `position:x`.
+    ///
+    /// _Godot equivalent: `NodePath.get_concatenated_subnames()`_
+    pub fn get_concatenated_subnames(&self) -> StringName {
+        self.as_inner().get_concatenated_subnames()
+    }
+
+    /// Appends the relative path `other` to this path, separated by `/`.
+    ///
+    /// Subnames of `other` are kept, so this can be used to build property paths such as `^"Player/Sprite:modulate:a"` at runtime.
+    /// If either path is empty, the other one is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::builtin::NodePath;
+    /// let path = NodePath::from("Player").join(&NodePath::from("Sprite:modulate:a"));
+    /// assert_eq!(path, NodePath::from("Player/Sprite:modulate:a"));
+    /// ```
+    ///
+    /// # Panics
+    /// If `other` is absolute, or if `self` has subnames -- subnames can only appear at the end of a path.
+    pub fn join(&self, other: &NodePath) -> NodePath {
+        assert!(
+            !other.is_absolute(),
+            "NodePath::join(): cannot append absolute path '{other}' to '{self}'"
+        );
+        assert_eq!(
+            self.get_subname_count(),
+            0,
+            "NodePath::join(): cannot append '{other}' to path '{self}' with subnames"
+        );
+
+        if self.is_empty() {
+            return other.clone();
+        }
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        NodePath::from(format!("{self}/{other}"))
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerNodePath {
         inner::InnerNodePath::from_outer(self)
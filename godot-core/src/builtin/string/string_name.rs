@@ -5,7 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::RwLock;
 
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
@@ -37,6 +39,11 @@ use crate::builtin::{GString, NodePath};
 /// can be used directly by Godot, without allocation or conversion. The encoding is limited to Latin-1, however. See the corresponding
 /// [`From<&'static CStr>` impl](#impl-From<%26CStr>-for-StringName).
 ///
+/// For names that are repeatedly converted from Rust strings, e.g. method names in hot loops, [`intern()`][Self::intern] caches the
+/// resulting `StringName` and avoids repeated UTF-8 conversion.
+///
+/// Dynamic ASCII names can be converted with [`from_latin1()`][Self::from_latin1], which skips UTF-8 decoding.
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -50,6 +57,16 @@ pub struct StringName {
     opaque: sys::types::OpaqueStringName,
 }
 
+/// Cache for [`StringName::intern()`]. Cleared on shutdown, since string names must not outlive the Godot binding.
+///
+/// Read-mostly: after warm-up, lookups only take a shared lock.
+///
+/// A global cache is sound because `StringName` is `Send + Sync` (see the impls below, which this `static` also requires): it is immutable,
+/// and Godot protects its reference count, so entries may be cloned and dropped on any thread. A `thread_local!` cache would not work here:
+/// [`StringName::clear_interned()`] could only clear the calling thread's entries, and the caches of other threads would drop their names
+/// at thread exit, possibly after the library has been unloaded.
+static INTERNED: RwLock<Option<HashMap<String, StringName>>> = RwLock::new(None);
+
 impl StringName {
     fn from_opaque(opaque: sys::types::OpaqueStringName) -> Self {
         Self { opaque }
@@ -78,6 +95,93 @@ impl StringName {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Returns a cached `StringName` for the given string, creating it on first use.
+    ///
+    /// Subsequent calls with the same string only look up the cache and return a (cheap, reference-counted) clone, without converting
+    /// from UTF-8 again. This is useful for names that are used over and over, such as method names passed to `Object::call()`.
+    ///
+    /// The cache is global and never evicts entries on its own, so only intern a bounded set of names. It is automatically cleared when the
+    /// GDExtension library is unloaded; [`clear_interned()`][Self::clear_interned] allows to do so earlier.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut obj = Object::new_alloc();
+    /// obj.call(StringName::intern("free"), &[]);
+    /// ```
+    pub fn intern(string: &str) -> Self {
+        let cached = INTERNED
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|interned| interned.get(string).cloned());
+
+        if let Some(name) = cached {
+            return name;
+        }
+
+        // Another thread may have inserted the same name in the meantime; keep the first entry.
+        let mut interned = INTERNED.write().unwrap();
+        interned
+            .get_or_insert_with(HashMap::new)
+            .entry(string.to_string())
+            .or_insert_with(|| Self::from(string))
+            .clone()
+    }
+
+    /// Creates a `StringName` from Latin-1 encoded bytes, skipping UTF-8 decoding.
+    ///
+    /// This is a fast path for ASCII identifiers that are not known at compile time (for literals, prefer `c"..."` strings, see
+    /// [`From<&'static CStr>`](#impl-From<%26CStr>-for-StringName)). Each byte is interpreted as one Latin-1 character, so for
+    /// ASCII input the result is identical to `StringName::from(str)`. Short names are converted without heap allocation on the Rust side.
+    ///
+    /// Like for other strings, Godot ignores bytes after a null byte.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::StringName;
+    ///
+    /// let method = format!("get_{}", "position");
+    /// let name = StringName::from_latin1(method.as_bytes());
+    /// assert_eq!(name, StringName::from("get_position"));
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn from_latin1(latin1: &[u8]) -> Self {
+        // Godot's Latin-1 constructor expects a nul-terminated buffer. Common identifiers fit on the stack.
+        const STACK_CAPACITY: usize = 64;
+
+        let mut stack_buf = [0u8; STACK_CAPACITY];
+        let heap_buf: Vec<u8>;
+
+        let nul_terminated: &[u8] = if latin1.len() < STACK_CAPACITY {
+            stack_buf[..latin1.len()].copy_from_slice(latin1);
+            &stack_buf[..=latin1.len()]
+        } else {
+            heap_buf = [latin1, &[0]].concat();
+            &heap_buf
+        };
+
+        // SAFETY: buffer is nul-terminated and lives until after the call. Godot copies the characters, since p_is_static is false.
+        unsafe {
+            Self::new_with_string_uninit(|ptr| {
+                sys::interface_fn!(string_name_new_with_latin1_chars)(
+                    ptr,
+                    nul_terminated.as_ptr() as *const std::ffi::c_char,
+                    sys::conv::SYS_FALSE, // p_is_static
+                )
+            })
+        }
+    }
+
+    /// Empties the cache of [`intern()`][Self::intern].
+    ///
+    /// Previously returned `StringName` instances stay valid; only the cache's own references are released.
+    pub fn clear_interned() {
+        // Move entries out first, so that destructors don't run while the lock is held.
+        let interned = INTERNED.write().unwrap().take();
+        drop(interned);
+    }
+
     /// O(1), non-lexicographic, non-stable ordering relation.
     ///
     /// The result of the comparison is **not** lexicographic and **not** stable across multiple runs of your application.
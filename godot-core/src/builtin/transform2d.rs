@@ -767,4 +767,12 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&transform, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_exact() {
+        let transform = Transform2D::from_angle_origin(0.3, Vector2::new(0.1, -1.0 / 3.0));
+
+        crate::builtin::test_utils::roundtrip_exact(&transform);
+    }
 }
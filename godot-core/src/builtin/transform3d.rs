@@ -9,7 +9,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{ApproxEq, GlamConv, GlamType};
-use crate::builtin::{real, Aabb, Basis, Plane, Projection, RAffine3, Vector3};
+use crate::builtin::{real, Aabb, Basis, Plane, Projection, Quaternion, RAffine3, Vector3};
 
 use std::fmt::Display;
 use std::ops::Mul;
@@ -135,18 +135,21 @@ impl Transform3D {
 
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// Like in Godot, both transforms are decomposed into scale, rotation and translation. Scale and translation are interpolated
+    /// linearly, rotation spherically.
     #[must_use]
     pub fn interpolate_with(&self, other: &Self, weight: real) -> Self {
-        let src_scale = self.basis.scale();
-        let src_rot = self.basis.to_quat().normalized();
+        let src_scale = self.scale();
+        let src_rot = self.rotation_quat().normalized();
         let src_loc = self.origin;
 
-        let dst_scale = other.basis.scale();
-        let dst_rot = other.basis.to_quat().normalized();
+        let dst_scale = other.scale();
+        let dst_rot = other.rotation_quat().normalized();
         let dst_loc = other.origin;
 
         let mut basis = Basis::from_scale(src_scale.lerp(dst_scale, weight));
-        basis = Basis::from_quat(src_rot.slerp(dst_rot, weight)) * basis;
+        basis = Basis::from_quat(src_rot.slerp(dst_rot, weight).normalized()) * basis;
 
         Self {
             basis,
@@ -154,6 +157,25 @@ impl Transform3D {
         }
     }
 
+    /// Returns the scale of the transform's basis.
+    ///
+    /// The translation part is directly available as the `origin` field.
+    ///
+    /// _Godot equivalent: `Transform3D.basis.get_scale()`_
+    #[must_use]
+    pub fn scale(&self) -> Vector3 {
+        self.basis.scale()
+    }
+
+    /// Returns the rotation of the transform's basis as a quaternion, with scale removed.
+    ///
+    /// _Godot equivalent: `Transform3D.basis.get_rotation_quaternion()`_
+    #[doc(alias = "get_rotation_quaternion")]
+    #[must_use]
+    pub fn rotation_quat(&self) -> Quaternion {
+        self.basis.to_quat()
+    }
+
     /// Returns true if this transform is finite by calling `is_finite` on the
     /// basis and origin.
     pub fn is_finite(&self) -> bool {
@@ -399,6 +421,8 @@ crate::meta::impl_godot_as_self!(Transform3D);
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_eq_approx;
+    use crate::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4};
 
     // Tests translated from Godot.
 
@@ -466,6 +490,34 @@ mod test {
         );
     }
 
+    #[test]
+    fn interpolate() {
+        let src = Transform3D::IDENTITY;
+        let dst = Transform3D::new(
+            Basis::from_axis_angle(Vector3::UP, FRAC_PI_2).scaled(Vector3::splat(2.0)),
+            Vector3::new(2.0, 4.0, 6.0),
+        );
+
+        assert_eq_approx!(src.interpolate_with(&dst, 0.0), src);
+        assert_eq_approx!(src.interpolate_with(&dst, 1.0), dst);
+
+        let expected = Transform3D::new(
+            Basis::from_axis_angle(Vector3::UP, FRAC_PI_4).scaled(Vector3::splat(1.5)),
+            Vector3::new(1.0, 2.0, 3.0),
+        );
+        assert_eq_approx!(src.interpolate_with(&dst, 0.5), expected);
+    }
+
+    #[test]
+    fn decompose() {
+        let rotation = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 1.0);
+        let scale = Vector3::new(1.0, 2.0, 3.0);
+        let transform = Transform3D::new(rotation * Basis::from_scale(scale), Vector3::ZERO);
+
+        assert_eq_approx!(transform.scale(), scale);
+        assert_eq_approx!(Basis::from_quat(transform.rotation_quat()), rotation);
+    }
+
     #[test]
     fn finite_number_checks() {
         let y = Vector3::new(0.0, 1.0, 2.0);
@@ -220,3 +220,111 @@ impl GodotType for Variant {
         "Variant".into()
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// TryFrom<Variant> impls
+
+// A blanket impl over `T: FromGodot` is not possible due to coherence rules, so each type is listed explicitly.
+macro_rules! impl_try_from_variant {
+    ($( $(#[$attr:meta])* $T:ty ),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl TryFrom<Variant> for $T {
+                type Error = ConvertError;
+
+                fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+                    variant.try_to()
+                }
+            }
+
+            $(#[$attr])*
+            impl TryFrom<&Variant> for $T {
+                type Error = ConvertError;
+
+                fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+                    variant.try_to()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_variant!(
+    bool,
+    i8,
+    u8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    f32,
+    f64,
+    Aabb,
+    Basis,
+    Callable,
+    Vector2,
+    Vector3,
+    Vector4,
+    Vector2i,
+    Vector3i,
+    Vector4i,
+    Quaternion,
+    Color,
+    GString,
+    StringName,
+    NodePath,
+    PackedByteArray,
+    PackedInt32Array,
+    PackedInt64Array,
+    PackedFloat32Array,
+    PackedFloat64Array,
+    PackedStringArray,
+    PackedVector2Array,
+    PackedVector3Array,
+    #[cfg(since_api = "4.3")]
+    PackedVector4Array,
+    PackedColorArray,
+    Plane,
+    Projection,
+    Rid,
+    Rect2,
+    Rect2i,
+    Signal,
+    Transform2D,
+    Transform3D,
+    Dictionary,
+);
+
+impl<T: ArrayElement> TryFrom<Variant> for Array<T> {
+    type Error = ConvertError;
+
+    fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+        variant.try_to()
+    }
+}
+
+impl<T: ArrayElement> TryFrom<&Variant> for Array<T> {
+    type Error = ConvertError;
+
+    fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+        variant.try_to()
+    }
+}
+
+impl<T: crate::obj::GodotClass> TryFrom<Variant> for crate::obj::Gd<T> {
+    type Error = ConvertError;
+
+    fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+        variant.try_to()
+    }
+}
+
+impl<T: crate::obj::GodotClass> TryFrom<&Variant> for crate::obj::Gd<T> {
+    type Error = ConvertError;
+
+    fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+        variant.try_to()
+    }
+}
@@ -51,23 +51,51 @@ impl Variant {
         value.to_variant()
     }
 
+    /// Create a variant from an optional value, mapping `None` to nil.
+    ///
+    /// Unlike `Option<T>: ToGodot`, which is only implemented for types that are nullable in Godot (objects), this works with any `T`.
+    pub fn from_option<T: ToGodot>(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.to_variant(),
+            None => Self::nil(),
+        }
+    }
+
     /// ⚠️ Convert to type `T`, panicking on failure.
     ///
-    /// Equivalent to `T::from_variant(&self)`.
+    /// Equivalent to `self.try_to::<T>().unwrap()`, but with a more descriptive panic message.
     ///
     /// # Panics
     /// When this variant holds a different type.
     pub fn to<T: FromGodot>(&self) -> T {
-        T::from_variant(self)
+        self.try_to()
+            .unwrap_or_else(|err| panic!("Variant::to() failed: {err}"))
     }
 
     /// Convert to type `T`, returning `Err` on failure.
     ///
-    /// Equivalent to `T::try_from_variant(&self)`.
+    /// Equivalent to `T::try_from_variant(&self)`. The returned [`ConvertError`] describes the variant's actual type and the expected
+    /// target type. Use this instead of [`to()`][Self::to] to validate untrusted values, e.g. results of dynamic calls.
+    ///
+    /// Builtin types, `Array<T>` and `Gd<T>` also implement `TryFrom<Variant>` with the same semantics, so you can write
+    /// `let v: Vector3 = variant.try_into()?` in functions returning `Result<_, ConvertError>`.
     pub fn try_to<T: FromGodot>(&self) -> Result<T, ConvertError> {
         T::try_from_variant(self)
     }
 
+    /// Convert to `Option<T>`, mapping nil to `None` (fallible).
+    ///
+    /// Unlike `try_to::<Option<T>>()`, which requires `T` to be nullable in Godot (objects), this works with any `T`. It is useful for
+    /// results of dynamic calls such as [`Object::call()`][crate::classes::Object::call], which return nil for "no value" or `void` methods.
+    /// A non-nil variant that cannot be converted to `T` is still an error.
+    pub fn try_to_option<T: FromGodot>(&self) -> Result<Option<T>, ConvertError> {
+        if self.is_nil() {
+            Ok(None)
+        } else {
+            self.try_to::<T>().map(Some)
+        }
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`Self::get_type`].
@@ -106,6 +134,14 @@ impl Variant {
         }
     }
 
+    /// Returns the Godot name of the type currently held by this variant, e.g. `"int"`, `"Vector2"` or `"Dictionary"`.
+    ///
+    /// Equivalent to GDScript's `type_string(typeof(value))`. Like [`get_type()`][Self::get_type], a null object is reported as `"Nil"`.
+    /// Useful for diagnostics, such as logging the result of a dynamic call.
+    pub fn type_name(&self) -> &'static str {
+        variant_type_name(self.get_type())
+    }
+
     /// ⚠️ Calls the specified `method` with the given `args`.
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc.).
@@ -463,3 +499,55 @@ impl fmt::Debug for Variant {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type names
+
+/// Godot name of a variant type, as returned from `Variant::get_type_name()` in the engine.
+fn variant_type_name(ty: VariantType) -> &'static str {
+    match ty {
+        VariantType::NIL => "Nil",
+        VariantType::BOOL => "bool",
+        VariantType::INT => "int",
+        VariantType::FLOAT => "float",
+        VariantType::STRING => "String",
+        VariantType::VECTOR2 => "Vector2",
+        VariantType::VECTOR2I => "Vector2i",
+        VariantType::RECT2 => "Rect2",
+        VariantType::RECT2I => "Rect2i",
+        VariantType::VECTOR3 => "Vector3",
+        VariantType::VECTOR3I => "Vector3i",
+        VariantType::TRANSFORM2D => "Transform2D",
+        VariantType::VECTOR4 => "Vector4",
+        VariantType::VECTOR4I => "Vector4i",
+        VariantType::PLANE => "Plane",
+        VariantType::QUATERNION => "Quaternion",
+        VariantType::AABB => "AABB",
+        VariantType::BASIS => "Basis",
+        VariantType::TRANSFORM3D => "Transform3D",
+        VariantType::PROJECTION => "Projection",
+        VariantType::COLOR => "Color",
+        VariantType::STRING_NAME => "StringName",
+        VariantType::NODE_PATH => "NodePath",
+        VariantType::RID => "RID",
+        VariantType::OBJECT => "Object",
+        VariantType::CALLABLE => "Callable",
+        VariantType::SIGNAL => "Signal",
+        VariantType::DICTIONARY => "Dictionary",
+        VariantType::ARRAY => "Array",
+        VariantType::PACKED_BYTE_ARRAY => "PackedByteArray",
+        VariantType::PACKED_INT32_ARRAY => "PackedInt32Array",
+        VariantType::PACKED_INT64_ARRAY => "PackedInt64Array",
+        VariantType::PACKED_FLOAT32_ARRAY => "PackedFloat32Array",
+        VariantType::PACKED_FLOAT64_ARRAY => "PackedFloat64Array",
+        VariantType::PACKED_STRING_ARRAY => "PackedStringArray",
+        VariantType::PACKED_VECTOR2_ARRAY => "PackedVector2Array",
+        VariantType::PACKED_VECTOR3_ARRAY => "PackedVector3Array",
+        VariantType::PACKED_COLOR_ARRAY => "PackedColorArray",
+        #[cfg(since_api = "4.3")]
+        VariantType::PACKED_VECTOR4_ARRAY => "PackedVector4Array",
+
+        // VariantType is not a real enum, so new Godot versions may introduce unknown types.
+        _ => "<unknown>",
+    }
+}
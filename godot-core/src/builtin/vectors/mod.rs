@@ -27,3 +27,112 @@ pub use vector_axis::*;
 pub use vector_swizzle::*;
 
 pub use crate::swizzle;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Debug;
+    use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    use crate::builtin::{real, Color};
+
+    use super::*;
+
+    /// Checks the arithmetic operators shared by all vector types and `Color` through generic code.
+    ///
+    /// Values must be chosen such that all operations are exact, i.e. `b` and `s` are non-zero powers of two.
+    fn check_ops<V, S>(a: V, b: V, s: S)
+    where
+        V: Copy
+            + PartialEq
+            + Debug
+            + Add<Output = V>
+            + Sub<Output = V>
+            + Mul<Output = V>
+            + Div<Output = V>
+            + Mul<S, Output = V>
+            + Div<S, Output = V>
+            + Neg<Output = V>
+            + AddAssign
+            + SubAssign
+            + MulAssign
+            + DivAssign
+            + MulAssign<S>
+            + DivAssign<S>
+            + std::iter::Sum,
+        S: Copy + Mul<V, Output = V>,
+    {
+        assert_eq!(a + b - b, a);
+        assert_eq!(a - b, a + -b);
+        assert_eq!(-(-a), a);
+        assert_eq!(a * b / b, a);
+        assert_eq!(a * s / s, a);
+        assert_eq!(a * s, s * a);
+        assert_eq!([a, b].into_iter().sum::<V>(), a + b);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+        c *= b;
+        assert_eq!(c, a * b);
+        c /= b;
+        assert_eq!(c, a);
+        c *= s;
+        assert_eq!(c, a * s);
+        c /= s;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn arithmetic_matrix() {
+        let s: real = 2.0;
+        check_ops(Vector2::new(1.0, -2.0), Vector2::new(2.0, 4.0), s);
+        check_ops(Vector3::new(1.0, -2.0, 3.0), Vector3::new(2.0, 4.0, 8.0), s);
+        check_ops(
+            Vector4::new(1.0, -2.0, 3.0, -4.0),
+            Vector4::new(2.0, 4.0, 8.0, 16.0),
+            s,
+        );
+
+        check_ops(Vector2i::new(1, -2), Vector2i::new(2, 4), 2);
+        check_ops(Vector3i::new(1, -2, 3), Vector3i::new(2, 4, 8), 2);
+        check_ops(Vector4i::new(1, -2, 3, -4), Vector4i::new(2, 4, 8, 16), 2);
+
+        check_ops(
+            Color::from_rgba(0.5, 0.25, 1.0, 0.75),
+            Color::from_rgba(2.0, 4.0, 0.5, 0.25),
+            2.0f32,
+        );
+    }
+
+    #[test]
+    fn integer_remainder() {
+        assert_eq!(Vector2i::new(7, -7) % 3, Vector2i::new(1, -1));
+        assert_eq!(
+            Vector3i::new(7, 8, 9) % Vector3i::new(2, 3, 4),
+            Vector3i::new(1, 2, 1)
+        );
+
+        let mut v = Vector4i::new(5, 6, 7, 8);
+        v %= 4;
+        assert_eq!(v, Vector4i::new(1, 2, 3, 0));
+        v %= Vector4i::new(1, 1, 2, 1);
+        assert_eq!(v, Vector4i::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn integer_division_by_zero() {
+        let _ = Vector2i::new(1, 1) / Vector2i::new(1, 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn integer_overflow() {
+        let _ = Vector3i::new(i32::MAX, 0, 0) + Vector3i::new(1, 0, 0);
+    }
+}
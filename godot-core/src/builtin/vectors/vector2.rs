@@ -243,4 +243,12 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_exact() {
+        let vector = Vector2::new(0.1, -1.0 / 3.0);
+
+        crate::builtin::test_utils::roundtrip_exact(&vector);
+    }
 }
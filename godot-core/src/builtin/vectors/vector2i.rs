@@ -93,6 +93,7 @@ impl_vector_fns!(Vector2i, glam::IVec2, i32, (x, y));
 impl_vector2x_fns!(Vector2i, Vector3i, i32);
 
 impl_vector_operators!(Vector2i, i32, (x, y));
+impl_integer_vector_operators!(Vector2i, i32, (x, y));
 
 /// Formats the vector like Godot: `(x, y)`.
 impl fmt::Display for Vector2i {
@@ -397,6 +397,84 @@ mod test {
         assert_eq_approx!(vector1.slerp(vector2, 0.5).length(), real!(6.258_311));
     }
 
+    // Expected values taken from Godot's test_vector3.h.
+    #[test]
+    fn move_toward() {
+        let from = Vector3::new(1.0, 2.0, 3.0);
+        let to = Vector3::new(4.0, 5.0, 6.0);
+        assert_eq_approx!(
+            from.move_toward(to, 0.5),
+            Vector3::new(1.288_675_1, 2.288_675_1, 3.288_675_1)
+        );
+
+        // Does not overshoot, and lands exactly on target.
+        assert_eq!(from.move_toward(to, 100.0), to);
+
+        // Negative delta moves away.
+        let to = Vector3::new(4.0, 6.0, 3.0);
+        assert_eq_approx!(from.move_toward(to, -5.0), Vector3::new(-2.0, -2.0, 3.0));
+
+        // No NaN for coinciding points.
+        assert_eq!(from.move_toward(from, 0.0), from);
+        assert_eq!(from.move_toward(from, -1.0), from);
+    }
+
+    #[test]
+    fn direction_to() {
+        let from = Vector3::new(1.0, 2.0, 3.0);
+        let to = Vector3::new(4.0, 6.0, 3.0);
+
+        assert_eq_approx!(from.direction_to(to), Vector3::new(0.6, 0.8, 0.0));
+        assert_eq_approx!(to.direction_to(from), Vector3::new(-0.6, -0.8, 0.0));
+        assert_eq!(from.try_direction_to(from), None);
+    }
+
+    #[test]
+    fn limit_length() {
+        let vector = Vector3::new(10.0, 10.0, 10.0);
+        assert_eq_approx!(
+            vector.limit_length(None),
+            Vector3::new(0.577_350_26, 0.577_350_26, 0.577_350_26)
+        );
+        assert_eq_approx!(
+            vector.limit_length(Some(5.0)),
+            Vector3::new(2.886_751_3, 2.886_751_3, 2.886_751_3)
+        );
+
+        // Shorter vectors and zero vectors are unchanged.
+        let short = Vector3::new(0.1, 0.0, 0.0);
+        assert_eq!(short.limit_length(None), short);
+        assert_eq!(Vector3::ZERO.limit_length(Some(0.0)), Vector3::ZERO);
+
+        // Negative length flips the direction.
+        assert_eq_approx!(
+            Vector3::new(0.0, 2.0, 0.0).limit_length(Some(-1.0)),
+            Vector3::new(0.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn slide_bounce_reflect() {
+        let vector = Vector3::new(0.5, 1.0, 2.0);
+        let normal = Vector3::new(0.0, 0.0, 1.0);
+
+        assert_eq_approx!(vector.slide(normal), Vector3::new(0.5, 1.0, 0.0));
+        assert_eq_approx!(vector.bounce(normal), Vector3::new(0.5, 1.0, -2.0));
+        assert_eq_approx!(vector.reflect(normal), Vector3::new(-0.5, -1.0, 2.0));
+    }
+
+    #[test]
+    fn slide_non_normalized() {
+        let vector = Vector3::new(0.5, 1.0, 2.0);
+
+        // Prints an error, but does not panic.
+        assert_eq_approx!(vector.slide(Vector3::ZERO), vector);
+        assert_eq_approx!(
+            vector.slide(Vector3::new(0.0, 0.0, 2.0)),
+            Vector3::new(0.5, 1.0, -6.0)
+        );
+    }
+
     #[test]
     fn iter_sum() {
         let vecs = vec![
@@ -420,4 +498,12 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_exact() {
+        let vector = Vector3::new(0.1, -1.0 / 3.0, real::MIN_POSITIVE);
+
+        crate::builtin::test_utils::roundtrip_exact(&vector);
+    }
 }
@@ -97,6 +97,7 @@ impl Vector3i {
 impl_vector3x_fns!(Vector3i, Vector2i, i32);
 
 impl_vector_operators!(Vector3i, i32, (x, y, z));
+impl_integer_vector_operators!(Vector3i, i32, (x, y, z));
 
 /// Formats the vector like Godot: `(x, y, z)`.
 impl fmt::Display for Vector3i {
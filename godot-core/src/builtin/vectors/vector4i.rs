@@ -100,6 +100,7 @@ impl Vector4i {
 
 impl_vector4x_fns!(Vector4i, i32);
 impl_vector_operators!(Vector4i, i32, (x, y, z, w));
+impl_integer_vector_operators!(Vector4i, i32, (x, y, z, w));
 
 /// Formats the vector like Godot: `(x, y, z, w)`.
 impl fmt::Display for Vector4i {
@@ -218,6 +218,25 @@ macro_rules! impl_vector_operators {
     }
 }
 
+/// Implements the operators that are only available on integer vectors, i.e. remainder (`%`).
+///
+/// Like Rust's integer arithmetic, these panic on division by zero.
+macro_rules! impl_integer_vector_operators {
+    (
+        // Name of the vector type to be implemented, for example `Vector2i`.
+        $Vector:ty,
+        // Type of each individual component, for example `i32`.
+        $Scalar:ty,
+        // Names of the components, with parentheses, for example `(x, y)`.
+        ($($components:ident),*)
+    ) => {
+        impl_vector_vector_binary_operator!($Vector, ($($components),*), Rem, rem);
+        impl_vector_scalar_binary_operator!($Vector, $Scalar, ($($components),*), Rem, rem);
+        impl_vector_vector_assign_operator!($Vector, ($($components),*), RemAssign, rem_assign);
+        impl_vector_scalar_assign_operator!($Vector, $Scalar, ($($components),*), RemAssign, rem_assign);
+    }
+}
+
 /// Implements `Index` and `IndexMut` for a vector type, using an enum to indicate the desired axis.
 macro_rules! impl_vector_index {
     (
@@ -1042,19 +1061,36 @@ macro_rules! impl_vector2_vector3_fns {
                 -self.reflect(n)
             }
 
-            /// Returns the vector with a maximum length by limiting its length to `length`.
+            /// Returns the vector with a maximum length by limiting its length to `length` (default 1.0).
+            ///
+            /// Like in Godot, a zero vector is returned unchanged, and a negative `length` flips the direction of a vector that is
+            /// longer than `length`.
             #[inline]
             pub fn limit_length(self, length: Option<real>) -> Self {
                 let length = length.unwrap_or(1.0);
+                let current = self.length();
 
-                Self::from_glam(self.to_glam().clamp_length_max(length))
-
+                if current > 0.0 && length < current {
+                    self / current * length
+                } else {
+                    self
+                }
             }
 
             /// Returns a new vector moved toward `to` by the fixed `delta` amount. Will not go past the final value.
+            ///
+            /// If the remaining distance is at most `delta` or approximately zero, `to` is returned exactly. A negative `delta`
+            /// moves away from `to`.
             #[inline]
             pub fn move_toward(self, to: Self, delta: real) -> Self {
-                Self::from_glam(self.to_glam().move_towards(to.to_glam(), delta))
+                let diff = to - self;
+                let distance = diff.length();
+
+                if distance <= delta || distance < real::CMP_EPSILON {
+                    to
+                } else {
+                    self + diff / distance * delta
+                }
             }
 
             /// Returns the result of projecting the vector onto the given vector `b`.
@@ -1075,11 +1111,13 @@ macro_rules! impl_vector2_vector3_fns {
 
             /// Returns a new vector slid along a plane defined by the given normal.
             ///
-            /// # Panics
-            /// If `n` is not normalized.
+            /// Like in Godot, an error is printed if `n` is not normalized (including a zero vector), but the result is still computed.
             #[inline]
             pub fn slide(self, n: Self) -> Self {
-                assert!(n.is_normalized(), "n is not normalized!");
+                if !n.is_normalized() {
+                    crate::godot_error!("slide(): normal {n:?} must be normalized");
+                }
+
                 self - n * self.dot(n)
             }
         }
@@ -18,11 +18,15 @@ pub(crate) fn debug_string<T: GodotClass>(
     f: &mut std::fmt::Formatter<'_>,
     ty: &str,
 ) -> std::fmt::Result {
+    // Static type T and dynamic class can differ, e.g. for Gd<Node> pointing to a Node3D.
+    let static_class = T::class_name();
+
     if let Some(id) = obj.instance_id_or_none() {
         let class: GString = obj.raw.as_object().get_class();
-        write!(f, "{ty} {{ id: {id}, class: {class} }}")
+        write!(f, "{ty}<{static_class}> {{ id: {id}, class: {class} }}")
     } else {
-        write!(f, "{ty} {{ freed obj }}")
+        let id = obj.instance_id_unchecked();
+        write!(f, "{ty}<{static_class}> {{ id: {id}, <freed> }}")
     }
 }
 
@@ -30,6 +34,10 @@ pub(crate) fn display_string<T: GodotClass>(
     obj: &Gd<T>,
     f: &mut std::fmt::Formatter<'_>,
 ) -> std::fmt::Result {
+    if !obj.is_instance_valid() {
+        return write!(f, "<freed>");
+    }
+
     let string: GString = obj.raw.as_object().to_string();
     <GString as std::fmt::Display>::fmt(&string, f)
 }
@@ -46,7 +54,9 @@ where
     // SAFETY: adhere to Godot API; valid class name and returned pointer is an object.
     unsafe {
         let object_ptr = sys::interface_fn!(classdb_construct_object)(T::class_name().string_sys());
-        Gd::from_obj_sys(object_ptr)
+        let obj = Gd::from_obj_sys(object_ptr);
+        crate::obj::leak_tracker::track(&obj);
+        obj
     }
 }
 
@@ -70,16 +80,20 @@ pub(crate) fn ensure_object_alive(
     );
 }
 
+#[cfg(debug_assertions)]
+pub(crate) fn object_inherits(derived: ClassName, base: ClassName) -> bool {
+    derived == base
+        || base == Object::class_name() // for Object base, anything inherits by definition
+        || is_derived_base_cached(derived, base)
+}
+
 #[cfg(debug_assertions)]
 pub(crate) fn ensure_object_inherits(
     derived: ClassName,
     base: ClassName,
     instance_id: InstanceId,
 ) -> bool {
-    if derived == base
-        || base == Object::class_name() // for Object base, anything inherits by definition
-        || is_derived_base_cached(derived, base)
-    {
+    if object_inherits(derived, base) {
         return true;
     }
 
@@ -5,26 +5,67 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
+use crate::builtin::{GString, NodePath, StringName, Variant, Vector2};
+use crate::classes::{
+    Engine, Input, Node, Object, PackedScene, ProjectSettings, PropertyTweener, SceneTree, Tween,
+};
+use crate::meta::error::CallError;
+use crate::meta::{CallContext, FromGodot, ToGodot};
 use crate::obj::{Gd, Inherits};
 
 /// Manual extensions for the `Node` class.
 impl Node {
+    /// Adds `node` as a child at the end of the current frame, like `call_deferred("add_child", node)`.
+    ///
+    /// Godot rejects adding children while the scene tree is busy, e.g. in `ready()` of the parent or during physics callbacks. Deferring
+    /// the call works around that, and this typed wrapper avoids spelling out the method name.
+    pub fn add_child_deferred<T>(&mut self, node: Gd<T>)
+    where
+        T: Inherits<Node>,
+    {
+        self.call_deferred("add_child".into(), &[node.upcast::<Node>().to_variant()]);
+    }
+
+    /// Removes the child `node` at the end of the current frame, like `call_deferred("remove_child", node)`.
+    ///
+    /// See [`add_child_deferred()`][Self::add_child_deferred] for when deferring is needed.
+    pub fn remove_child_deferred<T>(&mut self, node: Gd<T>)
+    where
+        T: Inherits<Node>,
+    {
+        self.call_deferred("remove_child".into(), &[node.upcast::<Node>().to_variant()]);
+    }
+
     /// ⚠️ Retrieves the node at path `path`, panicking if not found or bad type.
     ///
+    /// The runtime class of the node is validated before casting, so a node of the wrong type is reported as such, rather than as missing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn example(node: Gd<Node>) {
+    /// let sprite = node.get_node_as::<Node2D>("Player/Sprite");
+    /// # }
+    /// ```
+    ///
     /// # Panics
-    /// If the node is not found, or if it does not have type `T` or inherited.
+    /// If the node is not found, or if it does not have type `T` or inherited. The panic message contains the path and the expected type.
     pub fn get_node_as<T>(&self, path: impl Into<NodePath>) -> Gd<T>
     where
         T: Inherits<Node>,
     {
         let path = path.into();
-        let copy = path.clone(); // TODO avoid copy
+        let Some(node) = self.get_node_or_null(path.clone()) else {
+            panic!(
+                "get_node_as(): there is no node at path `{path}` (expected type {ty})",
+                ty = T::class_name()
+            );
+        };
 
-        self.try_get_node_as(path).unwrap_or_else(|| {
+        node.try_cast::<T>().unwrap_or_else(|node| {
             panic!(
-                "There is no node of type {ty} at path `{copy}`",
+                "get_node_as(): node at path `{path}` has type {actual}, but expected type {ty}",
+                actual = node.get_class(),
                 ty = T::class_name()
             )
         })
@@ -38,12 +79,69 @@ impl Node {
     where
         T: Inherits<Node>,
     {
-        let path = path.into();
-
-        // TODO differentiate errors (not found, bad type) with Result
-        self.get_node_or_null(path)
+        self.get_node_or_null(path.into())
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Returns the [`SceneTree`] this node belongs to, or `None` if the node is not inside the tree.
+    ///
+    /// Unlike [`get_tree()`][Self::get_tree], this does not make Godot print an error when called too early, e.g. in `init()` or before
+    /// the node is added as a child. Inside `ready()` and other callbacks that run in the tree, the result is always `Some`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let node = Node::new_alloc();
+    /// assert!(node.get_tree_safe().is_none());
+    /// # node.free();
+    /// ```
+    pub fn get_tree_safe(&self) -> Option<Gd<SceneTree>> {
+        if self.is_inside_tree() {
+            self.get_tree()
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the direct children of this node, in tree order.
+    ///
+    /// The child list is snapshotted when this method is called; children added or removed afterward do not affect the iteration.
+    /// Internal children are not included.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn example(node: Gd<Node>) {
+    /// for child in node.children() {
+    ///     godot_print!("{}", child.get_name());
+    /// }
+    /// # }
+    /// ```
+    pub fn children(&self) -> impl Iterator<Item = Gd<Node>> {
+        let children = self.get_children();
+        (0..children.len()).map(move |i| children.at(i))
+    }
+
+    /// Iterates over all descendants of this node (children, grandchildren, ...) in depth-first pre-order.
+    ///
+    /// The node itself is not included. Each node's children are snapshotted lazily, at the time that node is yielded. This means that
+    /// children added to a not-yet-visited node during iteration are visited. Freeing a node that is yet to be yielded or whose
+    /// children are still pending leads to a panic.
+    pub fn descendants(&self) -> impl Iterator<Item = Gd<Node>> {
+        // Stack in reverse order, so that the first child is popped first.
+        let mut stack: Vec<Gd<Node>> = self.children().collect();
+        stack.reverse();
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+
+            let start = stack.len();
+            stack.extend(node.children());
+            stack[start..].reverse();
+
+            Some(node)
+        })
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -72,3 +170,396 @@ impl PackedScene {
         self.instantiate().and_then(|gd| gd.try_cast::<T>().ok())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Returns all nodes in `group` which have type `T` or inherited, in scene tree order.
+    ///
+    /// Nodes of other types are skipped. To message all nodes in a group at once, use [`call_group()`][Self::call_group], which converts
+    /// the arguments only once for the whole broadcast.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn test(tree: Gd<SceneTree>) {
+    /// for mut enemy in tree.get_nodes_in_group_as::<Node3D>("enemies") {
+    ///     enemy.translate(Vector3::UP);
+    /// }
+    /// # }
+    /// ```
+    pub fn get_nodes_in_group_as<T>(&self, group: impl Into<StringName>) -> Vec<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.get_nodes_in_group(group.into())
+            .iter_shared()
+            .filter_map(|node| node.try_cast::<T>().ok())
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Engine` class.
+impl Engine {
+    /// Retrieves the engine singleton registered under `name`, cast to type `T` (fallible).
+    ///
+    /// If no singleton with that name exists, or it does not have type `T` or inherited, `None` will be returned.
+    ///
+    /// Most engine singletons also have a typed accessor, e.g. `Input::singleton()`. This method is useful for singletons only known
+    /// at runtime, such as those registered by other extensions or via [`register_singleton()`][Self::register_singleton].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// use godot::classes::{Engine, ProjectSettings};
+    ///
+    /// let settings = Engine::singleton().get_singleton_as::<ProjectSettings>("ProjectSettings");
+    /// ```
+    pub fn get_singleton_as<T>(&self, name: impl Into<StringName>) -> Option<Gd<T>>
+    where
+        T: Inherits<Object>,
+    {
+        let name = name.into();
+        if !self.has_singleton(name.clone()) {
+            return None;
+        }
+
+        self.get_singleton(name)
+            .and_then(|singleton| singleton.try_cast::<T>().ok())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Input` class.
+///
+/// The `*_named()` methods take action names as `&str` and [intern][StringName::intern] them on first use. This makes them convenient for
+/// per-frame polling, without allocating a new `StringName` on every call.
+impl Input {
+    /// Returns the input vector from four actions, with the default deadzone; see [`get_vector()`][Self::get_vector].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// use godot::classes::Input;
+    ///
+    /// let direction = Input::singleton().get_vector_named("ui_left", "ui_right", "ui_up", "ui_down");
+    /// ```
+    pub fn get_vector_named(
+        &self,
+        negative_x: &str,
+        positive_x: &str,
+        negative_y: &str,
+        positive_y: &str,
+    ) -> Vector2 {
+        self.get_vector(
+            StringName::intern(negative_x),
+            StringName::intern(positive_x),
+            StringName::intern(negative_y),
+            StringName::intern(positive_y),
+        )
+    }
+
+    /// Returns the input axis from two actions; see [`get_axis()`][Self::get_axis].
+    pub fn get_axis_named(&self, negative: &str, positive: &str) -> f32 {
+        self.get_axis(StringName::intern(negative), StringName::intern(positive))
+    }
+
+    /// Whether `action` is currently pressed; see [`is_action_pressed()`][Self::is_action_pressed].
+    pub fn is_action_pressed_named(&self, action: &str) -> bool {
+        self.is_action_pressed(StringName::intern(action))
+    }
+
+    /// Whether `action` started being pressed in the current frame; see [`is_action_just_pressed()`][Self::is_action_just_pressed].
+    pub fn is_action_just_pressed_named(&self, action: &str) -> bool {
+        self.is_action_just_pressed(StringName::intern(action))
+    }
+
+    /// Whether `action` stopped being pressed in the current frame; see [`is_action_just_released()`][Self::is_action_just_released].
+    pub fn is_action_just_released_named(&self, action: &str) -> bool {
+        self.is_action_just_released(StringName::intern(action))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Object` class.
+impl Object {
+    /// Emits the signal `signal` with `args` at the end of the current frame, like [`call_deferred()`][Self::call_deferred].
+    ///
+    /// Useful to notify listeners from code that must not be re-entered, e.g. physics callbacks or regions holding a `bind_mut()` guard.
+    ///
+    /// The arguments are copied into Godot's message queue, which keeps reference-counted values alive until the emission runs. If the
+    /// object is freed in the meantime, the emission is dropped.
+    pub fn emit_signal_deferred(&mut self, signal: impl Into<StringName>, args: &[Variant]) {
+        let mut call_args = Vec::with_capacity(args.len() + 1);
+        call_args.push(signal.into().to_variant());
+        call_args.extend_from_slice(args);
+
+        self.call_deferred("emit_signal".into(), &call_args);
+    }
+
+    /// Like [`call_deferred()`][Self::call_deferred], but validates the method before queueing the call.
+    ///
+    /// Returns an error if `method` does not exist on the object, or if more `args` are passed than it has parameters (including
+    /// default parameters). This catches mistakes up front, whereas `call_deferred()` only reports them when the queued call is
+    /// eventually executed, detached from the calling code.
+    ///
+    /// Argument _types_ and missing arguments are not checked here; Godot validates them once the queued call runs. The parameter count
+    /// is only available from Godot 4.3 on; before that, only the existence of the method is checked. Varargs methods count only their
+    /// fixed parameters, so use `call_deferred()` for them, or [`emit_signal_deferred()`][Self::emit_signal_deferred] for signals.
+    ///
+    /// To pass arguments of different types without converting them by hand, see [`deferred_call()`][Self::deferred_call].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut node = Node::new_alloc();
+    /// node.call_deferred_checked("set_name", &["Deferred".to_variant()])
+    ///     .expect("valid call");
+    ///
+    /// let result = node.call_deferred_checked("set_nmae", &["Deferred".to_variant()]);
+    /// assert!(result.is_err()); // Typo in method name.
+    /// # node.free();
+    /// ```
+    pub fn call_deferred_checked(
+        &mut self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Result<(), CallError> {
+        let method = method.into();
+        let call_ctx = CallContext {
+            class_name: self.get_class().to_string().into(),
+            function_name: "call_deferred_checked",
+        };
+
+        if !self.has_method(method.clone()) {
+            return Err(CallError::failed_method_not_found(
+                &call_ctx,
+                &method.to_string(),
+            ));
+        }
+
+        #[cfg(since_api = "4.3")]
+        {
+            let param_count = self.get_method_argument_count(method.clone()) as usize;
+            CallError::check_max_arg_count(&call_ctx, args.len(), param_count)?;
+        }
+
+        self.call_deferred(method, args);
+        Ok(())
+    }
+
+    /// Starts a deferred call to `method`, with arguments added one by one.
+    ///
+    /// The call is validated and queued once [`done()`][DeferredCall::done] is invoked; see
+    /// [`call_deferred_checked()`][Self::call_deferred_checked] for the checks performed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn example(mut node: Gd<Node3D>) {
+    /// node.deferred_call("set_position")
+    ///     .arg(Vector3::new(1.0, 2.0, 3.0))
+    ///     .done()
+    ///     .expect("valid call");
+    /// # }
+    /// ```
+    pub fn deferred_call(&mut self, method: impl Into<StringName>) -> DeferredCall<'_> {
+        DeferredCall {
+            object: self,
+            method: method.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Retrieves the property `property`, converted to type `T` (fallible).
+    ///
+    /// Returns `None` if the object has no such property, or if its value cannot be converted to `T`. Useful when the property name
+    /// is only known at runtime; otherwise, prefer the generated typed getters.
+    ///
+    /// For dynamic use, [`get()`][Self::get] returns the raw `Variant`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let node = Node2D::new_alloc();
+    /// let position = node.get_as::<Vector2>("position");
+    /// assert_eq!(position, Some(Vector2::ZERO));
+    ///
+    /// assert_eq!(node.get_as::<i64>("position"), None); // Wrong type.
+    /// assert_eq!(node.get_as::<Vector2>("no_such_property"), None);
+    /// # node.free();
+    /// ```
+    pub fn get_as<T>(&self, property: impl Into<StringName>) -> Option<T>
+    where
+        T: FromGodot,
+    {
+        let property = property.into();
+        let value = self.get(property.clone());
+
+        // Godot returns nil for absent properties, which some types (e.g. `Option<Gd<T>>`) would accept as a valid value.
+        if value.is_nil() && !self.has_property(&property.to_string()) {
+            return None;
+        }
+
+        value.try_to::<T>().ok()
+    }
+
+    /// Sets the property `property` to `value` at the end of the current frame, like [`set_deferred()`][Self::set_deferred].
+    ///
+    /// Typed counterpart to [`get_as()`][Self::get_as]. As with `set_deferred()`, an absent property or a value of the wrong type is
+    /// only reported once the queued assignment runs.
+    pub fn set_deferred_as<T>(&mut self, property: impl Into<StringName>, value: T)
+    where
+        T: ToGodot,
+    {
+        self.set_deferred(property.into(), value.to_variant());
+    }
+
+    /// Whether `name` is listed among the object's properties, including script-defined ones.
+    pub(crate) fn has_property(&self, name: &str) -> bool {
+        self.get_property_list()
+            .iter_shared()
+            .any(|info| info.get_or_nil("name").to_string() == name)
+    }
+}
+
+/// Builder for a deferred method call, created by [`Object::deferred_call()`].
+#[must_use = "the call is only queued by done()"]
+pub struct DeferredCall<'a> {
+    object: &'a mut Object,
+    method: StringName,
+    args: Vec<Variant>,
+}
+
+impl DeferredCall<'_> {
+    /// Appends an argument, converting it to `Variant`.
+    pub fn arg<A: ToGodot>(mut self, arg: A) -> Self {
+        self.args.push(arg.to_variant());
+        self
+    }
+
+    /// Validates the call and queues it; see [`Object::call_deferred_checked()`].
+    pub fn done(self) -> Result<(), CallError> {
+        self.object.call_deferred_checked(self.method, &self.args)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ProjectSettings` class.
+impl ProjectSettings {
+    /// Retrieves the project setting at `name`, converted to type `T` (fallible).
+    ///
+    /// If the setting does not exist, or its value cannot be converted to `T`, `None` will be returned.
+    ///
+    /// For dynamic use, [`get_setting()`][Self::get_setting] returns the raw `Variant`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// use godot::classes::ProjectSettings;
+    ///
+    /// let settings = ProjectSettings::singleton();
+    /// let width = settings.get_setting_as::<i64>("display/window/size/viewport_width");
+    /// ```
+    pub fn get_setting_as<T>(&self, name: impl Into<GString>) -> Option<T>
+    where
+        T: FromGodot,
+    {
+        let name = name.into();
+        if !self.has_setting(name.clone()) {
+            return None;
+        }
+
+        self.get_setting(name).try_to::<T>().ok()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Tween` class.
+impl Tween {
+    /// Like [`tween_property()`][Self::tween_property], but checks that `property` exists on `object` first.
+    ///
+    /// `property` is a property path such as `"position"` or `"position:x"`; its first component must be a property of `object`.
+    /// Returns `None` if it is not, instead of letting Godot report an error and create an invalid tweener.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn example(mut node: Gd<Node2D>) {
+    /// let mut tween = node.create_tween().unwrap();
+    /// tween
+    ///     .tween_property_checked(&node, "position", Vector2::new(100.0, 0.0), 0.5)
+    ///     .expect("Node2D has a position");
+    /// # }
+    /// ```
+    pub fn tween_property_checked<T>(
+        &mut self,
+        object: &Gd<T>,
+        property: impl Into<NodePath>,
+        final_val: impl ToGodot,
+        duration: f64,
+    ) -> Option<Gd<PropertyTweener>>
+    where
+        T: Inherits<Object>,
+    {
+        let property = property.into();
+
+        // "position:x" has name "position" and subname "x"; ":position:x" only subnames.
+        let first = if property.get_name_count() > 0 {
+            property.get_name(0)
+        } else if property.get_subname_count() > 0 {
+            property.get_subname(0)
+        } else {
+            return None;
+        };
+
+        let base = object.upcast_ref::<Object>();
+        if !base.has_property(&first.to_string()) {
+            return None;
+        }
+
+        self.tween_property(object, property, final_val.to_variant(), duration)
+    }
+
+    /// Tweens a value from `from` to `to` over `duration` seconds, invoking the Rust closure `method` with each intermediate value.
+    ///
+    /// This is a typed alternative to [`tween_method()`][Self::tween_method], which requires a `Callable`. The closure is stored inside
+    /// a custom callable that is owned by the tween, so it stays alive as long as the tween needs it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn example(mut node: Gd<Node>) {
+    /// let mut tween = node.create_tween().unwrap();
+    /// tween.tween_method_fn(0.0, 1.0, 2.0, |progress| {
+    ///     godot_print!("progress: {progress}");
+    /// });
+    /// # }
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn tween_method_fn<F>(
+        &mut self,
+        from: f64,
+        to: f64,
+        duration: f64,
+        mut method: F,
+    ) -> Option<Gd<crate::classes::MethodTweener>>
+    where
+        F: FnMut(f64) + Send + Sync + 'static,
+    {
+        let callable = crate::builtin::Callable::from_fn("tween_method_fn", move |args| {
+            let value = args.first().ok_or(())?.try_to::<f64>().map_err(|_| ())?;
+            method(value);
+            Ok(Variant::nil())
+        });
+
+        self.tween_method(callable, from.to_variant(), to.to_variant(), duration)
+    }
+}
@@ -15,6 +15,7 @@
 //! Noteworthy sub-modules of `godot::classes` are:
 //! * [`native`]: definition of _native structure_ types.
 //! * [`notify`]: all notification enums, used when working with the virtual callback to handle lifecycle notifications.
+//! * [`signals`]: signal collections such as `SignalsOfNode`, returned by [`Gd::signals()`](crate::obj::Gd::signals).
 
 mod class_runtime;
 mod manual_extensions;
@@ -22,6 +23,9 @@ mod manual_extensions;
 // Re-exports all generated classes, interface traits and sidecar modules.
 pub use crate::gen::classes::*;
 
+// Helper types returned by manual class extensions.
+pub use manual_extensions::DeferredCall;
+
 /// Support for Godot _native structures_.
 ///
 /// Native structures are a niche API in Godot. These are low-level data types that are passed as pointers to/from the engine.
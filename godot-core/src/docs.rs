@@ -44,14 +44,18 @@ pub struct StructDocs {
 #[derive(Clone, Copy, Debug, Default)]
 pub struct InherentImplDocs {
     pub methods: &'static str,
-    pub signals_block: &'static str,
-    pub constants_block: &'static str,
+    /// Not-yet-parented `<signal>` tags.
+    pub signals: &'static str,
+    /// Not-yet-parented `<constant>` tags.
+    pub constants: &'static str,
 }
 
 #[derive(Default)]
 struct DocPieces {
     definition: StructDocs,
     inherent: InherentImplDocs,
+    /// Docs from `#[godot_api(secondary)]` blocks, appended to those of the primary block.
+    secondary: Vec<InherentImplDocs>,
     virtual_methods: &'static str,
 }
 
@@ -79,6 +83,10 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
                 map.entry(class_name).or_default().inherent = docs
             }
 
+            PluginItem::SecondaryImpl { docs, .. } => {
+                map.entry(class_name).or_default().secondary.push(docs)
+            }
+
             PluginItem::ITraitImpl {
                 virtual_method_docs,
                 ..
@@ -98,16 +106,20 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
                 members,
             } = pieces.definition;
 
-            let InherentImplDocs {
-                methods,
-                signals_block,
-                constants_block,
-            } = pieces.inherent;
+            // Primary block first, then secondary blocks.
+            let impl_docs = std::iter::once(&pieces.inherent).chain(&pieces.secondary);
+            let mut methods = String::new();
+            let mut signals = String::new();
+            let mut constants = String::new();
+            for docs in impl_docs {
+                methods.push_str(docs.methods);
+                signals.push_str(docs.signals);
+                constants.push_str(docs.constants);
+            }
 
-            let virtual_methods = pieces.virtual_methods;
-            let methods_block = (virtual_methods.is_empty() && methods.is_empty())
-                .then(String::new)
-                .unwrap_or_else(|| format!("<methods>{methods}{virtual_methods}</methods>"));
+            let methods_block = to_tagged(methods + pieces.virtual_methods, "methods");
+            let signals_block = to_tagged(signals, "signals");
+            let constants_block = to_tagged(constants, "constants");
 
             let brief = description
                 .split_once("[br]")
@@ -128,6 +140,15 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
         )
 }
 
+/// Wraps `content` in `<tag>...</tag>`, or returns an empty string if there is no content.
+fn to_tagged(content: String, tag: &str) -> String {
+    if content.is_empty() {
+        content
+    } else {
+        format!("<{tag}>{content}</{tag}>")
+    }
+}
+
 /// # Safety
 ///
 /// The Godot binding must have been initialized before calling this function.
@@ -24,11 +24,10 @@ macro_rules! inner_function {
 #[macro_export]
 #[doc(hidden)]
 macro_rules! inner_godot_msg {
-    // FIXME expr needs to be parenthesised, see usages
-    ($godot_fn:ident; $fmt:literal $(, $args:expr)* $(,)?) => {
-    //($($args:tt),* $(,)?) => {
+    // Arguments are forwarded as token trees, so that all format!() syntax (e.g. named arguments `name = value`) is supported.
+    ($godot_fn:ident; $fmt:literal $($args:tt)*) => {
         unsafe {
-            let msg = format!("{}\0", format_args!($fmt $(, $args)*));
+            let msg = format!("{}\0", format_args!($fmt $($args)*));
             // assert!(msg.is_ascii(), "godot_error: message must be ASCII");
 
             // Check whether engine is loaded, otherwise fall back to stderr.
@@ -50,43 +49,62 @@ macro_rules! inner_godot_msg {
 
 /// Pushes a warning message to Godot's built-in debugger and to the OS terminal.
 ///
+/// Accepts the same syntax as [`format!`]. The Rust source location (file, line and function) is attached to the message and shown in
+/// the editor's debugger. If the engine is not yet initialized, the message is printed to stderr instead.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// let path = "res://missing.tscn";
+/// godot_warn!("scene {path} not found, using fallback");
+/// godot_warn!("{count} retries left", count = 3);
+/// ```
+///
 /// _Godot equivalent: [`@GlobalScope.push_warning()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-push-warning)_.
 #[macro_export]
 macro_rules! godot_warn {
-    ($fmt:literal $(, $args:expr)* $(,)?) => {
-        $crate::inner_godot_msg!(print_warning; $fmt $(, $args)*);
+    ($fmt:literal $($args:tt)*) => {
+        $crate::inner_godot_msg!(print_warning; $fmt $($args)*);
     };
 }
 
 /// Pushes an error message to Godot's built-in debugger and to the OS terminal.
 ///
+/// Like [`godot_warn!`], this accepts [`format!`] syntax and attaches the Rust source location.
+///
 /// _Godot equivalent: [`@GlobalScope.push_error()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-push-error)_.
 #[macro_export]
 macro_rules! godot_error {
-    ($fmt:literal $(, $args:expr)* $(,)?) => {
-        $crate::inner_godot_msg!(print_error; $fmt $(, $args)*);
+    ($fmt:literal $($args:tt)*) => {
+        $crate::inner_godot_msg!(print_error; $fmt $($args)*);
     };
 }
 
 /// Logs a script error to Godot's built-in debugger and to the OS terminal.
 #[macro_export]
 macro_rules! godot_script_error {
-    ($fmt:literal $(, $args:expr)* $(,)?) => {
-        $crate::inner_godot_msg!(print_script_error; $fmt $(, $args)*);
+    ($fmt:literal $($args:tt)*) => {
+        $crate::inner_godot_msg!(print_script_error; $fmt $($args)*);
     };
 }
 
 /// Prints to the Godot console.
 ///
+/// Accepts the same syntax as [`format!`]. If the engine is not yet initialized, the message is printed to stdout instead.
+///
 /// _Godot equivalent: [`@GlobalScope.print()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-print)_.
 #[macro_export]
 macro_rules! godot_print {
-    ($fmt:literal $(, $args:expr)* $(,)?) => {
-        $crate::global::print(&[
-            $crate::builtin::Variant::from(
-                format!($fmt $(, $args)*)
-            )
-        ])
+    ($fmt:literal $($args:tt)*) => {
+        // Check whether engine is loaded, otherwise fall back to stdout.
+        if $crate::sys::is_initialized() {
+            $crate::global::print(&[
+                $crate::builtin::Variant::from(
+                    format!($fmt $($args)*)
+                )
+            ])
+        } else {
+            println!($fmt $($args)*)
+        }
     };
 }
 
@@ -97,10 +115,10 @@ macro_rules! godot_print {
 /// _Godot equivalent: [`@GlobalScope.print_rich()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-print-rich)_.
 #[macro_export]
 macro_rules! godot_print_rich {
-    ($fmt:literal $(, $args:expr)* $(,)?) => {
+    ($fmt:literal $($args:tt)*) => {
         $crate::global::print_rich(&[
             $crate::builtin::Variant::from(
-                format!($fmt $(, $args)*)
+                format!($fmt $($args)*)
             )
         ])
     };
@@ -157,6 +157,11 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    if level == InitLevel::Scene {
+        // Objects are still accessible at this point; user classes are unregistered right below.
+        crate::obj::leak_tracker::report_leaks();
+    }
+
     crate::registry::class::unregister_classes(level);
 
     if level == InitLevel::Core {
@@ -164,6 +169,8 @@ fn gdext_on_level_deinit(level: InitLevel) {
         // No business logic by itself, but ensures consistency if re-initialization (hot-reload on Linux) occurs.
 
         // Garbage-collect various statics.
+        crate::builtin::StringName::clear_interned();
+
         // SAFETY: this is the last time meta APIs are used.
         unsafe {
             crate::meta::cleanup();
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::meta::ClassName;
+use crate::obj::InstanceId;
+
+/// Error returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum BindError {
+    /// The user instance is already bound in a conflicting way.
+    ///
+    /// This is typically caused by re-entrancy, e.g. a `&mut self` method emitting a signal whose handler accesses the same object.
+    AlreadyBound {
+        /// Description of the conflicting borrow.
+        details: String,
+    },
+
+    /// The object's runtime class is not `T` or derived from it.
+    ///
+    /// This can happen if the object's identity was changed through `DerefMut`. Only detected in Debug mode.
+    WrongType {
+        /// Static class `T` of the `Gd<T>`.
+        expected: ClassName,
+        /// Class that the object was created with.
+        actual: ClassName,
+    },
+
+    /// The object has already been freed.
+    Dead {
+        /// Last known instance ID of the object.
+        instance_id: InstanceId,
+    },
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyBound { details } => write!(f, "instance is already bound: {details}"),
+            Self::WrongType { expected, actual } => write!(
+                f,
+                "object has type {actual} but is incorrectly stored in a Gd<{expected}>"
+            ),
+            Self::Dead { instance_id } => {
+                write!(f, "instance with ID {instance_id} has already been freed")
+            }
+        }
+    }
+}
+
+impl Error for BindError {}
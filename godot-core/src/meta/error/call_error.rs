@@ -31,6 +31,7 @@ use std::fmt;
 /// - **Failed return value conversion**: The returned `Variant` of a dynamic method cannot be converted to the expected return type.
 /// - **Too many or too few arguments**: The number of arguments passed to the method does not match the number of parameters.
 /// - **User panic**: A Rust method caused a panic.
+/// - **Returned error**: A `#[func]` returning `Result<T, E>` returned `Err`.
 ///
 /// # Chained errors
 /// Let's say you have this code, and you want to call the method dynamically with `Object::try_call()`.
@@ -122,6 +123,20 @@ impl CallError {
         Err(call_error)
     }
 
+    /// Checks whether number of arguments does not exceed the number of parameters.
+    #[cfg(since_api = "4.3")]
+    pub(crate) fn check_max_arg_count(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count <= param_count {
+            return Ok(());
+        }
+
+        Err(Self::failed_param_count(call_ctx, arg_count, param_count))
+    }
+
     /// Checks the Godot side of a varcall (low-level `sys::GDExtensionCallError`).
     pub(crate) fn check_out_varcall<T: ToGodot>(
         call_ctx: &CallContext,
@@ -216,6 +231,11 @@ impl CallError {
         )
     }
 
+    /// Returns an error for a method that does not exist on the object.
+    pub(crate) fn failed_method_not_found(call_ctx: &CallContext, method_name: &str) -> Self {
+        Self::new(call_ctx, format!("method `{method_name}` not found"), None)
+    }
+
     fn failed_param_count(
         call_ctx: &CallContext,
         arg_count: usize,
@@ -300,6 +320,11 @@ impl CallError {
         call_error
     }
 
+    /// Returns an error for a `#[func]` that returned `Err`, with the error's `Display` representation as `reason`.
+    pub(crate) fn failed_by_user_result(call_ctx: &CallContext, reason: String) -> Self {
+        Self::new(call_ctx, format!("function returned Err: {reason}"), None)
+    }
+
     #[doc(hidden)]
     pub fn failed_by_user_panic(call_ctx: &CallContext, reason: String) -> Self {
         Self::new(call_ctx, reason, None)
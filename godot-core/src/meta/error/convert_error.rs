@@ -10,7 +10,7 @@ use std::fmt;
 
 use godot_ffi::VariantType;
 
-use crate::builtin::Variant;
+use crate::builtin::{GString, Variant};
 use crate::meta::{ArrayTypeInfo, ClassName, ToGodot};
 
 type Cause = Box<dyn Error + Send + Sync>;
@@ -296,6 +296,8 @@ pub(crate) enum FromVariantError {
 
     WrongClass {
         expected: ClassName,
+        /// Runtime class of the object, if it is alive.
+        actual: Option<GString>,
     },
 }
 
@@ -316,9 +318,10 @@ impl fmt::Display for FromVariantError {
                 write!(f, "expected type {expected:?}, got {actual:?}")
             }
             Self::BadValue => write!(f, "value cannot be represented in target type's domain"),
-            Self::WrongClass { expected } => {
-                write!(f, "expected class {expected}")
-            }
+            Self::WrongClass { expected, actual } => match actual {
+                Some(actual) => write!(f, "expected class {expected}, got {actual}"),
+                None => write!(f, "expected class {expected}"),
+            },
         }
     }
 }
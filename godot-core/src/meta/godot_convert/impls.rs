@@ -5,11 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{Array, Variant};
+use crate::builtin::{Array, Variant, VariantArray};
 use crate::meta::error::{ConvertError, ErrorKind, FromFfiError, FromVariantError};
 use crate::meta::{
-    ArrayElement, ClassName, FromGodot, GodotConvert, GodotNullableFfi, GodotType,
-    PropertyHintInfo, PropertyInfo, ToGodot,
+    ArrayElement, ClassName, FromGodot, FromVariantTuple, GodotConvert, GodotNullableFfi,
+    GodotType, PropertyHintInfo, PropertyInfo, ToGodot,
 };
 use crate::registry::method::MethodParamOrReturnInfo;
 use godot_ffi as sys;
@@ -442,3 +442,77 @@ impl_pointer_convert!(*mut *const u8);
 impl_pointer_convert!(*mut i32);
 impl_pointer_convert!(*mut f64);
 impl_pointer_convert!(*mut u8);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tuples
+
+macro_rules! impl_from_variant_tuple {
+    ($ARITY:literal; $( ($n:tt, $T:ident) ),*) => {
+        impl<$($T: FromGodot,)*> FromVariantTuple for ($($T,)*) {
+            const ARITY: usize = $ARITY;
+
+            #[allow(unused_variables)]
+            fn try_from_variant_slice(variants: &[Variant]) -> Result<Self, ConvertError> {
+                if variants.len() != $ARITY {
+                    return Err(ConvertError::new(format!(
+                        "expected {} element(s) for tuple, got {}",
+                        $ARITY,
+                        variants.len()
+                    )));
+                }
+
+                Ok(($(
+                    variants[$n].try_to::<$T>().map_err(|err| {
+                        ConvertError::new(format!(
+                            "tuple element #{} ({}) conversion failed: {err}",
+                            $n,
+                            std::any::type_name::<$T>()
+                        ))
+                    })?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_from_variant_tuple!(0;);
+impl_from_variant_tuple!(1; (0, T0));
+impl_from_variant_tuple!(2; (0, T0), (1, T1));
+impl_from_variant_tuple!(3; (0, T0), (1, T1), (2, T2));
+impl_from_variant_tuple!(4; (0, T0), (1, T1), (2, T2), (3, T3));
+impl_from_variant_tuple!(5; (0, T0), (1, T1), (2, T2), (3, T3), (4, T4));
+impl_from_variant_tuple!(6; (0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5));
+impl_from_variant_tuple!(7; (0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6));
+impl_from_variant_tuple!(8; (0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6), (7, T7));
+
+// Tuples are passed to Godot as untyped arrays, e.g. to return multiple values from a #[func].
+macro_rules! impl_godot_convert_for_tuple {
+    ($( ($n:tt, $T:ident) ),+) => {
+        impl<$($T,)+> GodotConvert for ($($T,)+) {
+            type Via = VariantArray;
+        }
+
+        impl<$($T: ToGodot,)+> ToGodot for ($($T,)+) {
+            fn to_godot(&self) -> Self::Via {
+                let mut array = VariantArray::new();
+                $(
+                    array.push(self.$n.to_variant());
+                )+
+                array
+            }
+        }
+
+        impl<$($T: FromGodot,)+> FromGodot for ($($T,)+) {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                via.to_tuple::<Self>()
+            }
+        }
+    };
+}
+
+impl_godot_convert_for_tuple!((0, T0));
+impl_godot_convert_for_tuple!((0, T0), (1, T1));
+impl_godot_convert_for_tuple!((0, T0), (1, T1), (2, T2));
+impl_godot_convert_for_tuple!((0, T0), (1, T1), (2, T2), (3, T3));
+impl_godot_convert_for_tuple!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4));
+impl_godot_convert_for_tuple!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5));
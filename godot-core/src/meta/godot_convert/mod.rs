@@ -98,6 +98,21 @@ pub trait FromGodot: Sized + GodotConvert {
     }
 }
 
+/// Tuple of [`FromGodot`] types, which can be extracted from a sequence of variants.
+///
+/// Implemented for tuples with up to 8 elements. Used by [`VariantArray::to_tuple()`][crate::builtin::Array::to_tuple], for example
+/// to unpack signal arguments or results of dynamic calls.
+pub trait FromVariantTuple: Sized {
+    /// Number of elements in the tuple.
+    const ARITY: usize;
+
+    /// Converts each variant to the corresponding tuple element, returning `Err` on failure.
+    ///
+    /// Fails if the number of variants differs from [`ARITY`][Self::ARITY], or if any element cannot be converted. The error names
+    /// the failing element's index and type.
+    fn try_from_variant_slice(variants: &[Variant]) -> Result<Self, ConvertError>;
+}
+
 pub(crate) fn into_ffi<T: ToGodot>(value: T) -> <T::Via as GodotType>::Ffi {
     value.into_godot().into_ffi()
 }
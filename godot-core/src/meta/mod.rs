@@ -45,7 +45,7 @@ mod traits;
 
 pub mod error;
 pub use class_name::ClassName;
-pub use godot_convert::{FromGodot, GodotConvert, ToGodot};
+pub use godot_convert::{FromGodot, FromVariantTuple, GodotConvert, ToGodot};
 pub use traits::{ArrayElement, GodotType, PackedArrayElement};
 
 pub(crate) use crate::impl_godot_as_self;
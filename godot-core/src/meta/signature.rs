@@ -39,7 +39,7 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         arg_count: i64,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
-        func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Result<Self::Ret, String>,
     ) -> Result<(), CallError>;
 
     unsafe fn out_class_varcall(
@@ -88,9 +88,9 @@ pub trait PtrcallSignatureTuple {
         call_ctx: &CallContext<'static>,
         args_ptr: *const sys::GDExtensionConstTypePtr,
         ret: sys::GDExtensionTypePtr,
-        func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Result<Self::Ret, String>,
         call_type: sys::PtrcallType,
-    );
+    ) -> Result<(), CallError>;
 
     unsafe fn out_class_ptrcall(
         method_bind: ClassMethodBind,
@@ -184,7 +184,7 @@ macro_rules! impl_varcall_signature_for_tuple {
                 arg_count: i64,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
-                func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Result<Self::Ret, String>,
             ) -> Result<(), CallError> {
                 //$crate::out!("in_varcall: {call_ctx}");
                 CallError::check_arg_count(call_ctx, arg_count as usize, $PARAM_COUNT)?;
@@ -196,7 +196,9 @@ macro_rules! impl_varcall_signature_for_tuple {
                     unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
                 )*) ;
 
-                let rust_result = func(instance_ptr, args);
+                let rust_result = func(instance_ptr, args)
+                    .map_err(|reason| CallError::failed_by_user_result(call_ctx, reason))?;
+
                 varcall_return::<$R>(rust_result, ret, err);
                 Ok(())
             }
@@ -351,9 +353,9 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 call_ctx: &CallContext,
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
-                func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Result<Self::Ret, String>,
                 call_type: sys::PtrcallType,
-            ) {
+            ) -> Result<(), CallError> {
                 // $crate::out!("in_ptrcall: {call_ctx}");
 
                 #[cfg(feature = "trace")]
@@ -363,10 +365,14 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                     unsafe { ptrcall_arg::<$Pn, $n>(args_ptr, call_ctx, call_type) },
                 )*) ;
 
+                let rust_result = func(instance_ptr, args)
+                    .map_err(|reason| CallError::failed_by_user_result(call_ctx, reason))?;
+
                 // SAFETY:
                 // `ret` is always a pointer to an initialized value of type $R
                 // TODO: double-check the above
-                ptrcall_return::<$R>(func(instance_ptr, args), ret, call_ctx, call_type)
+                ptrcall_return::<$R>(rust_result, ret, call_ctx, call_type);
+                Ok(())
             }
 
             #[inline]
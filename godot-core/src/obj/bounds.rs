@@ -395,7 +395,9 @@ impl Declarer for DeclEngine {
         unsafe {
             let object_ptr =
                 sys::interface_fn!(classdb_construct_object)(T::class_name().string_sys());
-            Gd::from_obj_sys(object_ptr)
+            let obj = Gd::from_obj_sys(object_ptr);
+            crate::obj::leak_tracker::track(&obj);
+            obj
         }
     }
 }
@@ -420,7 +422,9 @@ impl Declarer for DeclUser {
     {
         unsafe {
             let object_ptr = callbacks::create::<T>(std::ptr::null_mut());
-            Gd::from_obj_sys(object_ptr)
+            let obj = Gd::from_obj_sys(object_ptr);
+            crate::obj::leak_tracker::track(&obj);
+            obj
         }
     }
 }
@@ -14,14 +14,14 @@ use sys::{static_assert_eq_size_align, VariantType};
 
 use crate::builtin::{Callable, NodePath, StringName, Variant};
 use crate::global::PropertyHint;
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{BindError, ConvertError, FromFfiError};
 use crate::meta::{
     ArrayElement, CallContext, ClassName, FromGodot, GodotConvert, GodotType, PropertyHintInfo,
     ToGodot,
 };
 use crate::obj::{
-    bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    RawGd,
+    bounds, cap, leak_tracker, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass,
+    Inherits, InstanceId, RawGd, WeakGd, WithSignals,
 };
 use crate::private::callbacks;
 use crate::registry::property::{Export, Var};
@@ -45,12 +45,16 @@ use crate::{classes, out};
 /// - **Reference-counted**<br>
 ///   Objects of type [`RefCounted`] or inherited from it are **reference-counted**. This means that every time a smart pointer is
 ///   shared using [`Clone::clone()`], the reference counter is incremented, and every time one is dropped, it is decremented.
-///   This ensures that the last reference (either in Rust or Godot) will deallocate the object and call `T`'s destructor.<br><br>
+///   This ensures that the last reference (either in Rust or Godot) will deallocate the object and call `T`'s destructor.
+///   Objects referring to each other form a cycle and are never freed; use [`WeakGd`] to break such cycles. To find them in Debug
+///   builds, set the environment variable `GODOT_RUST_TRACK_LEAKS`: on exit, every `RefCounted` object created through godot-rust
+///   which is still alive is logged with its class name and instance ID.<br><br>
 ///
 /// - **Manual**<br>
 ///   Objects inheriting from [`Object`] which are not `RefCounted` (or inherited) are **manually-managed**.
 ///   Their destructor is not automatically called (unless they are part of the scene tree). Creating a `Gd<T>` means that
-///   you are responsible for explicitly deallocating such objects using [`free()`][Self::free].<br><br>
+///   you are responsible for explicitly deallocating such objects using [`free()`][Self::free], or for handing them to a [`GdBox`],
+///   which frees them on drop.<br><br>
 ///
 /// - **Dynamic**<br>
 ///   For `T=Object`, the memory strategy is determined **dynamically**. Due to polymorphism, a `Gd<Object>` can point to either
@@ -93,6 +97,7 @@ use crate::{classes, out};
 /// For type conversions, please read the [`godot::meta` module docs][crate::meta].
 ///
 /// [book]: https://godot-rust.github.io/book/godot-api/objects.html
+/// [`GdBox`]: crate::obj::GdBox
 /// [`Object`]: classes::Object
 /// [`RefCounted`]: classes::RefCounted
 #[repr(C)] // must be layout compatible with engine classes
@@ -124,6 +129,16 @@ where
     /// The `init` function provides you with a `Base<T::Base>` object that you can use inside your `T`, which
     /// is then wrapped in a `Gd<T>`.
     ///
+    /// This is also the way to construct an object with caller-provided field values, without a separate `bind_mut()` after creation.
+    /// The closure runs before the object is returned, so the fields are initialized before the object can enter the scene tree;
+    /// callbacks such as `ready()` already see them.
+    ///
+    /// This works for any class with a `Base<T>` field, regardless of whether it inherits `Node`, `RefCounted` or `Object`. The base passed
+    /// to the closure refers to the same object as the returned `Gd<T>` (same instance ID).
+    ///
+    /// In contrast, [`new_alloc()`][crate::obj::NewAlloc::new_alloc] and [`new_gd()`][crate::obj::NewGd::new_gd] require a generated or
+    /// user-defined `init` constructor, while [`from_object()`][Self::from_object] only works for classes without a base field.
+    ///
     /// # Example
     /// ```no_run
     /// # use godot::prelude::*;
@@ -139,12 +154,15 @@ where
     ///     MyClass { my_base, other_field: 732 }
     /// });
     /// ```
+    #[doc(alias = "emplace")]
     pub fn from_init_fn<F>(init: F) -> Self
     where
         F: FnOnce(crate::obj::Base<T::Base>) -> T,
     {
         let object_ptr = callbacks::create_custom(init);
-        unsafe { Gd::from_obj_sys(object_ptr) }
+        let obj = unsafe { Gd::from_obj_sys(object_ptr) };
+        leak_tracker::track(&obj);
+        obj
     }
 
     /// Moves a user-created object into this smart pointer, submitting ownership to the Godot engine.
@@ -189,6 +207,51 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking.
+    ///
+    /// Useful for code that may be re-entered while the instance is already bound, e.g. signal handlers. Instead of crashing, such
+    /// code can detect the situation and bail out gracefully. The [`BindError`] distinguishes conflicting borrows from badly typed
+    /// or freed objects.
+    pub fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.raw.try_bind()
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking.
+    ///
+    /// See [`try_bind()`][Self::try_bind] for details.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.raw.try_bind_mut()
+    }
+
+    /// Runs `f` with exclusive access to the user instance, releasing the guard before returning.
+    ///
+    /// This is equivalent to `f(&mut *self.bind_mut())`, but makes the scope of the borrow explicit. Since the guard cannot outlive the
+    /// closure, calls that re-enter the object (e.g. emitting signals or calling into GDScript) can safely follow after this method,
+    /// instead of accidentally overlapping with a guard stored in a local variable. The closure's return value is passed through.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)]
+    /// # #[class(init)]
+    /// # struct Player { health: i32 }
+    /// let mut player = Player::new_gd();
+    /// let health = player.with_bind_mut(|p| {
+    ///     p.health -= 10;
+    ///     p.health
+    /// });
+    ///
+    /// // Guard is released; re-entrant access is fine.
+    /// player.emit_signal("health_changed".into(), &[health.to_variant()]);
+    /// ```
+    ///
+    /// # Panics
+    /// Same as [`bind_mut()`][Self::bind_mut].
+    pub fn with_bind_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut guard = self.bind_mut();
+        f(&mut guard)
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -196,7 +259,24 @@ impl<T: GodotClass> Gd<T> {
     /// Looks up the given instance ID and returns the associated object, if possible.
     ///
     /// If no such instance ID is registered, or if the dynamic type of the object behind that instance ID
-    /// is not compatible with `T`, then `None` is returned.
+    /// is not compatible with `T`, then an error is returned. The type check uses the object's runtime class,
+    /// so an instance of a subclass of `T` is accepted.
+    ///
+    /// This lets long-lived systems store instance IDs instead of `Gd` handles (e.g. to avoid reference cycles), and
+    /// safely rehydrate them later -- even if the object has been freed in the meantime.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let node = Node3D::new_alloc();
+    /// let id = node.instance_id();
+    ///
+    /// assert!(Gd::<Node>::try_from_instance_id(id).is_ok());
+    /// assert!(Gd::<Node2D>::try_from_instance_id(id).is_err()); // Wrong class.
+    ///
+    /// node.free();
+    /// assert!(Gd::<Node3D>::try_from_instance_id(id).is_err()); // Dangling ID.
+    /// ```
     pub fn try_from_instance_id(instance_id: InstanceId) -> Result<Self, ConvertError> {
         let ptr = classes::object_ptr_from_id(instance_id);
 
@@ -254,7 +334,9 @@ impl<T: GodotClass> Gd<T> {
     /// This function does not check that the returned instance ID points to a valid instance!
     /// Unless performance is a problem, use [`instance_id()`][Self::instance_id] instead.
     ///
-    /// This method is safe and never panics.
+    /// This method is safe and never panics. It is useful for bookkeeping of objects that may already be freed, e.g. for deduplicating or
+    /// logging during teardown. Since the returned ID may refer to a dead object, only use it for comparison or logging; looking it up again
+    /// via [`try_from_instance_id()`][Self::try_from_instance_id] fails for dead objects.
     pub fn instance_id_unchecked(&self) -> InstanceId {
         let instance_id = self.raw.instance_id_unchecked();
 
@@ -263,6 +345,28 @@ impl<T: GodotClass> Gd<T> {
         unsafe { instance_id.unwrap_unchecked() }
     }
 
+    /// Creates a weak pointer to this object, which does not keep it alive.
+    ///
+    /// See [`WeakGd`] for details. If this object is already dead, the returned weak pointer can never be upgraded.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.instance_id_unchecked())
+    }
+
+    /// Clones this pointer as `Gd<Object>`, without checking that the object's runtime class still matches `T`.
+    ///
+    /// Regular [`clone()`][Clone::clone] panics if the runtime type has diverged from `T` (e.g. after `std::mem::swap` on the
+    /// dereferenced objects). This method instead looks the object up by its instance ID and returns a new strong reference typed
+    /// as `Object`, which every class inherits. Since no type guarantee beyond `Object` is made, this is always sound. It is mostly
+    /// useful for bookkeeping, e.g. stashing copies of objects that must be freed during teardown.
+    ///
+    /// For `RefCounted` objects, the reference count is incremented like with `clone()`.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn clone_weak_typed(&self) -> Gd<classes::Object> {
+        Gd::from_instance_id(self.instance_id())
+    }
+
     /// Checks if this smart pointer points to a live object (read description!).
     ///
     /// Using this method is often indicative of bad design -- you should dispose of your pointers once an object is
@@ -275,6 +379,106 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Checks that the object's runtime class is still `T` or derived from it.
+    ///
+    /// A `Gd<T>` can end up pointing to an object of a different class if the objects behind two pointers are exchanged through
+    /// `DerefMut`, e.g. with `std::mem::swap(&mut *a, &mut *b)`. Such pointers panic on the next method call, which may be far away
+    /// from the actual cause. Calling this method at strategic points, e.g. at frame boundaries or after code that hands out `&mut`
+    /// references to engine objects, detects the corruption as close to its source as possible.
+    ///
+    /// Both the type information cached inside the pointer (Debug mode only) and the dynamic class reported by Godot are checked.
+    /// See [`validate_type()`][Self::validate_type] for a panicking version.
+    ///
+    /// # Errors
+    /// If the object is dead, or if its cached or dynamic class is not compatible with `T`.
+    pub fn check_type(&self) -> Result<(), ConvertError> {
+        let static_class = T::class_name();
+        let instance_id = self.instance_id_unchecked();
+
+        self.raw
+            .try_check_type()
+            .map_err(ConvertError::with_error)?;
+
+        if !self.is_instance_valid() {
+            return Err(ConvertError::with_error(BindError::Dead { instance_id }));
+        }
+
+        let object = self.raw.as_object();
+        if !object.is_class(static_class.to_gstring()) {
+            return Err(ConvertError::new(format!(
+                "Godot reports dynamic class {dynamic_class} for instance ID {instance_id}, which does not inherit {static_class}",
+                dynamic_class = object.get_class()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Asserts that the object's runtime class is still `T` or derived from it (Debug only).
+    ///
+    /// Panicking version of [`check_type()`][Self::check_type]. In Release mode, no checks are performed and calls to this method
+    /// compile out entirely.
+    ///
+    /// # Panics
+    /// In Debug mode, if the object is dead, or if its cached or dynamic class is not compatible with `T`.
+    #[inline]
+    pub fn validate_type(&self) {
+        #[cfg(debug_assertions)]
+        self.validate_type_debug();
+    }
+
+    #[cfg(debug_assertions)]
+    fn validate_type_debug(&self) {
+        if let Err(err) = self.check_type() {
+            // All errors of check_type() are custom ones, whose cause carries the message.
+            let reason = err
+                .cause()
+                .map_or_else(|| err.to_string(), |cause| cause.to_string());
+
+            panic!(
+                "Gd<{static_class}>::validate_type() failed: {reason}.\n\
+                This may happen if you change an object's identity through DerefMut, e.g. with std::mem::swap().",
+                static_class = T::class_name()
+            );
+        }
+    }
+
+    /// Destroys the object if that is possible without error, returning whether it was freed.
+    ///
+    /// Unlike [`free()`][Self::free], this never panics. It only frees the object if all of the following hold:
+    /// - The object is still alive.
+    /// - The object is manually managed. For `RefCounted` types (statically or dynamically), this is a no-op returning `false`.
+    /// - The runtime type of the object is compatible with `T`.
+    /// - The object is not bound by an ongoing `bind()` or `bind_mut()` call.
+    ///
+    /// This is mostly useful in cleanup code, where objects may have been freed elsewhere in the meantime.
+    pub fn free_if_valid(self) -> bool {
+        use bounds::Declarer;
+
+        // Runtime check in case of T=Object; `None` means the instance is dead.
+        let ref_counted =
+            <<T as Bounds>::DynMemory as bounds::DynMemory>::is_ref_counted(&self.raw);
+        if ref_counted != Some(false) || !self.is_instance_valid() || !self.raw.is_cast_valid::<T>()
+        {
+            return false;
+        }
+
+        // SAFETY: object is alive, as checked above. No multithreading here.
+        let bound = unsafe { T::Declarer::is_currently_bound(&self.raw) };
+        if bound {
+            return false;
+        }
+
+        // SAFETY: object alive as checked.
+        // This destroys the Storage instance, no need to run destructor again.
+        unsafe {
+            sys::interface_fn!(object_destroy)(self.raw.obj_sys());
+        }
+
+        std::mem::forget(self);
+        true
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -336,6 +540,37 @@ impl<T: GodotClass> Gd<T> {
         unsafe { self.raw.as_upcast_ref::<Base>() }
     }
 
+    /// **Upcast smart-pointer-ref:** access this object as a shared reference to a `Gd` of a base class.
+    ///
+    /// Unlike [`upcast()`][Self::upcast], this neither consumes `self` nor touches the reference count. Unlike
+    /// [`upcast_ref()`][Self::upcast_ref], it returns a smart pointer, which can be passed to functions expecting `&Gd<Base>`, or
+    /// cloned to obtain an owned `Gd<Base>`. The returned reference cannot outlive `self`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn log_class(obj: &Gd<Object>) {
+    ///     godot_print!("{}", obj.get_class());
+    /// }
+    ///
+    /// let node: Gd<Node3D> = Node3D::new_alloc();
+    /// log_class(node.upcast_gd_ref());
+    /// # node.free();
+    /// ```
+    pub fn upcast_gd_ref<Base>(&self) -> &Gd<Base>
+    where
+        Base: GodotClass + Bounds<Declarer = bounds::DeclEngine>,
+        T: Inherits<Base>,
+    {
+        // SAFETY: Gd<T> and RawGd<T> are #[repr(C)], with fields `obj`, `cached_rtti` and `cached_storage_ptr` in this order.
+        // - `Base` is an engine class (see bounds), so its `cached_storage_ptr` is a ZST, and Gd<Base> consists only of `obj` and
+        //   `cached_rtti`. `T` may be a user class, whose non-ZST `cached_storage_ptr` then trails these two fields. Gd<Base> is thus no
+        //   larger than Gd<T>, not more strictly aligned, and its fields have the same offsets and types (`*mut T` vs. `*mut Base` are
+        //   both thin pointers to the same object). Code going through &Gd<Base> only ever reads these two fields.
+        // - The cached RTTI stays valid, as T is a subclass of Base.
+        // - The reference is borrowed from `self`, so no ownership (and thus no reference count) is transferred.
+        unsafe { &*(self as *const Gd<T>).cast::<Gd<Base>>() }
+    }
+
     /// **Upcast exclusive-ref:** access this object as an exclusive reference to a base class.
     ///
     /// This is semantically equivalent to multiple applications of [`Self::deref_mut()`]. Not really useful on its own, but combined with
@@ -404,6 +639,81 @@ impl<T: GodotClass> Gd<T> {
         })
     }
 
+    /// **Up- or downcast:** try to convert into a smart pointer to `U`, which may be a base class or a derived class of `T`.
+    ///
+    /// Unifies [`upcast()`][Self::upcast] and [`try_cast()`][Self::try_cast]. The direction is determined from the static class hierarchy,
+    /// without querying the engine:
+    /// - If `T` inherits `U` (upcast), this always succeeds.
+    /// - If `U` inherits `T` (downcast), this succeeds if the object's runtime class is `U` or derived from it.
+    /// - If the classes are unrelated, this fails. Use [`dynamic_cast()`][Self::dynamic_cast] to cast based on the runtime class alone.
+    ///
+    /// On failure, `Err(self)` is returned, so you can reuse the original object for further casts.
+    pub fn try_cast_to<U>(self) -> Result<Gd<U>, Self>
+    where
+        U: GodotClass,
+    {
+        if T::inherits::<U>() || U::inherits::<T>() {
+            self.owned_cast()
+        } else {
+            Err(self)
+        }
+    }
+
+    /// ⚠️ **Up- or downcast:** convert into a smart pointer to `U`, which may be a base class or a derived class of `T`. Panics on error.
+    ///
+    /// See [`try_cast_to()`][Self::try_cast_to] for details.
+    ///
+    /// # Panics
+    /// If `U` is unrelated to `T`, or this is a downcast and the object's runtime class is not `U` or derived from it. The panic message
+    /// names the object's runtime class. In Debug mode, also if the object's runtime class no longer matches `T`.
+    pub fn cast_to<U>(self) -> Gd<U>
+    where
+        U: GodotClass,
+    {
+        let from = T::class_name();
+        let to = U::class_name();
+
+        // Checked up front for a precise message; casting would otherwise panic in a generic RTTI check.
+        if let Err(err) = self.raw.try_check_type() {
+            panic!(
+                "cast_to() from Gd<{from}> to Gd<{to}> failed: {err}.\n\
+                This may happen if you change an object's identity through DerefMut, e.g. with std::mem::swap()."
+            );
+        }
+
+        if !T::inherits::<U>() && !U::inherits::<T>() {
+            panic!("cast_to() from Gd<{from}> to Gd<{to}> failed: classes {from} and {to} are unrelated; consider dynamic_cast()");
+        }
+
+        self.try_cast_to().unwrap_or_else(|from_obj| {
+            let id = from_obj.instance_id_unchecked();
+            let runtime_class = match Gd::<classes::Object>::try_from_instance_id(id) {
+                Ok(object) => object.get_class().to_string(),
+                Err(_) => String::from("<freed>"),
+            };
+
+            panic!(
+                "cast_to() from Gd<{from}> to Gd<{to}> failed: instance {id} has runtime class {runtime_class}, which does not inherit {to}"
+            )
+        })
+    }
+
+    /// **Dynamic cast:** obtain a smart pointer to class `U`, based on the object's actual runtime class.
+    ///
+    /// Unlike [`try_cast()`][Self::try_cast], this ignores the static type `T` entirely and asks Godot for the object's real class.
+    /// `U` can thus be any class, including ones unrelated to `T` in the type system. This also works on `Gd<T>` instances whose
+    /// runtime type no longer matches `T`, which would make other casts panic.
+    ///
+    /// Returns a new reference to the same object if it is alive and its runtime class is `U` or derived, otherwise `None`.
+    ///
+    /// This is slower than the other cast methods, as it always performs a class lookup through the engine -- also in Release mode.
+    pub fn dynamic_cast<U>(&self) -> Option<Gd<U>>
+    where
+        U: GodotClass,
+    {
+        self.raw.dynamic_cast().map(Gd::from_ffi)
+    }
+
     /// Returns `Ok(cast_obj)` on success, `Err(self)` on error
     fn owned_cast<U>(self) -> Result<Gd<U>, Self>
     where
@@ -424,7 +734,9 @@ impl<T: GodotClass> Gd<T> {
     {
         unsafe {
             let object_ptr = callbacks::create::<T>(std::ptr::null_mut());
-            Gd::from_obj_sys(object_ptr)
+            let obj = Gd::from_obj_sys(object_ptr);
+            leak_tracker::track(&obj);
+            obj
         }
     }
 
@@ -651,6 +963,29 @@ where
     }
 }
 
+impl<T> Gd<T>
+where
+    T: WithSignals,
+{
+    /// Access to the signals of this object, with one method per signal.
+    ///
+    /// Signals declared by base classes are accessible as well. For user classes, these are the `#[signal]`s declared in `#[godot_api]`
+    /// blocks, followed by the signals of the engine base class.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::Button;
+    /// # fn example(button: Gd<Button>, callable: Callable) {
+    /// // Disconnected automatically after the first click.
+    /// button.signals().pressed().connect(callable).one_shot().done();
+    /// # }
+    /// ```
+    pub fn signals(&self) -> T::SignalCollection {
+        T::__signals_from(self)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
@@ -835,37 +1170,42 @@ where
 // Trait impls Property, Export and TypeStringHint for Option<Gd<T>> are covered by blanket impl for Option<T>
 
 impl<T: GodotClass> PartialEq for Gd<T> {
-    /// ⚠️ Returns whether two `Gd` pointers point to the same object.
+    /// Returns whether two `Gd` pointers point to the same object.
     ///
-    /// # Panics
-    /// When `self` or `other` is dead.
+    /// This compares object identity by instance ID, not the contents of the objects. It is thus not equivalent to Godot's `==`
+    /// operator for types that override it. Dead objects are compared by their last known instance ID, without panicking: a
+    /// freed pointer equals its clones, but no pointer to a different object.
     fn eq(&self, other: &Self) -> bool {
-        // Panics when one is dead
-        self.instance_id() == other.instance_id()
+        self.instance_id_unchecked() == other.instance_id_unchecked()
     }
 }
 
 impl<T: GodotClass> Eq for Gd<T> {}
 
 impl<T: GodotClass> Display for Gd<T> {
+    /// Formats the object using Godot's `Object::to_string()`, e.g. `<Node3D#1234>`.
+    ///
+    /// Prints `<freed>` if the object is dead.
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         classes::display_string(self, f)
     }
 }
 
 impl<T: GodotClass> Debug for Gd<T> {
+    /// Formats the static type `T`, the instance ID and the dynamic class, e.g. `Gd<Node> { id: 1234, class: Node3D }`.
+    ///
+    /// If the object is dead, prints `<freed>` instead of the class (the ID is the last one known).
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         classes::debug_string(self, f, "Gd")
     }
 }
 
 impl<T: GodotClass> std::hash::Hash for Gd<T> {
-    /// ⚠️ Hashes this object based on its instance ID.
+    /// Hashes this object based on its instance ID, consistent with [`PartialEq`].
     ///
-    /// # Panics
-    /// When `self` is dead.
+    /// Dead objects are hashed by their last known instance ID, without panicking.
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.instance_id().hash(state);
+        self.instance_id_unchecked().hash(state);
     }
 }
 
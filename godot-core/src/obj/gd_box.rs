@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::ops::{Deref, DerefMut};
+
+use crate::obj::{bounds, Bounds, Gd, GodotClass};
+
+/// Owning pointer to a manually-managed Godot object, which frees the object when dropped.
+///
+/// Objects that are not `RefCounted` must be freed explicitly, which is easy to forget or to get wrong on some code path. A `GdBox<T>`
+/// encodes the "free on drop" intent: it holds a [`Gd<T>`] and destroys the object exactly once, when the box goes out of scope.
+/// This is typically used for helper objects owned by a struct, instead of implementing [`Drop`] by hand.
+///
+/// Dropping never panics, so it is safe during unwinding. If the object has already been freed elsewhere (e.g. with
+/// [`queue_free()`][crate::classes::Node::queue_free] or from GDScript), dropping the box does nothing. The same applies if the object
+/// is bound by an ongoing `bind()` or `bind_mut()` call at that time. See [`Gd::free_if_valid()`] for the exact rules.
+///
+/// The box dereferences to `Gd<T>`, so all methods of the object are available. Cloning the inner `Gd<T>` yields a non-owning
+/// pointer; the object is still freed when the box is dropped. To give up ownership without freeing, use [`into_inner()`][Self::into_inner].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::GdBox;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Spawner {
+///     template: Option<GdBox<Node>>,
+/// }
+///
+/// let mut spawner = Spawner::new_gd();
+/// spawner.bind_mut().template = Some(GdBox::new(Node::new_alloc()));
+///
+/// // The template node is freed together with the spawner.
+/// ```
+pub struct GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    // Only `None` once ownership has been given up, inside into_inner() or free().
+    obj: Option<Gd<T>>,
+}
+
+impl<T> GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    /// Takes ownership of `obj`, which will be freed when the box is dropped.
+    ///
+    /// There must be no other owner that frees the object; in particular, nodes handed to `GdBox` should not be part of the scene tree,
+    /// which frees its nodes on its own. Should that happen anyway, the box notices that the object is dead and does not free it again.
+    pub fn new(obj: Gd<T>) -> Self {
+        Self { obj: Some(obj) }
+    }
+
+    /// Returns `true` if the owned object is still alive.
+    ///
+    /// See [`Gd::is_instance_valid()`].
+    pub fn is_instance_valid(&self) -> bool {
+        self.get().is_instance_valid()
+    }
+
+    /// ⚠️ Destroys the owned object immediately.
+    ///
+    /// # Panics
+    /// Like [`Gd::free()`], in particular if the object has already been freed. Use `drop()` for the non-panicking variant.
+    pub fn free(mut self) {
+        self.take().free();
+    }
+
+    /// Gives up ownership and returns the inner `Gd<T>`, without freeing the object.
+    ///
+    /// The caller is again responsible for freeing it.
+    pub fn into_inner(mut self) -> Gd<T> {
+        self.take()
+    }
+
+    fn get(&self) -> &Gd<T> {
+        self.obj.as_ref().expect("GdBox object already taken")
+    }
+
+    fn take(&mut self) -> Gd<T> {
+        self.obj.take().expect("GdBox object already taken")
+    }
+}
+
+impl<T> Deref for GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    type Target = Gd<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T> DerefMut for GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.obj.as_mut().expect("GdBox object already taken")
+    }
+}
+
+impl<T> Drop for GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn drop(&mut self) {
+        if let Some(obj) = self.obj.take() {
+            obj.free_if_valid();
+        }
+    }
+}
+
+impl<T> From<Gd<T>> for GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn from(obj: Gd<T>) -> Self {
+        Self::new(obj)
+    }
+}
+
+impl<T> Debug for GdBox<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let obj = self.get();
+        write!(
+            f,
+            "GdBox<{}> {{ id: {}, valid: {} }}",
+            T::class_name(),
+            obj.instance_id_unchecked(),
+            obj.is_instance_valid()
+        )
+    }
+}
@@ -31,6 +31,40 @@ impl<'a, T: GodotClass> GdRef<'a, T> {
     pub(crate) fn from_guard(guard: RefGuard<'a, T>) -> Self {
         Self { guard }
     }
+
+    /// Projects the guard to a part of the bound object, typically a field.
+    ///
+    /// The returned [`MappedRef`] keeps the object bound until it is dropped, just like the original guard. This allows e.g. returning
+    /// a reference to a field from a function, or matching on it without re-binding.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// use godot::obj::{GdRef, MappedRef};
+    ///
+    /// #[derive(GodotClass)]
+    /// #[class(init)]
+    /// struct Inventory {
+    ///     items: Vec<String>,
+    /// }
+    ///
+    /// fn items(inventory: &Gd<Inventory>) -> MappedRef<'_, Inventory, Vec<String>> {
+    ///     GdRef::map(inventory.bind(), |inv| &inv.items)
+    /// }
+    /// ```
+    // Associated function rather than method, so it doesn't shadow methods of T accessed through Deref.
+    pub fn map<U, F>(this: Self, project: F) -> MappedRef<'a, T, U>
+    where
+        U: ?Sized,
+        F: FnOnce(&T) -> &U,
+    {
+        let value: *const U = project(&*this);
+
+        MappedRef {
+            _guard: this,
+            value,
+        }
+    }
 }
 
 impl<T: GodotClass> Deref for GdRef<'_, T> {
@@ -51,6 +85,33 @@ impl<T: GodotClass> Drop for GdRef<'_, T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Shared reference guard to a part of a bound object, created by [`GdRef::map()`].
+///
+/// The object stays bound (as with `GdRef`) until this guard is dropped.
+pub struct MappedRef<'a, T: GodotClass, U: ?Sized> {
+    // Only held to keep the borrow active; released on drop.
+    _guard: GdRef<'a, T>,
+    value: *const U,
+}
+
+impl<T: GodotClass, U: ?Sized> Deref for MappedRef<'_, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        // SAFETY: `value` was derived from a reference to the object behind `_guard`. The guard keeps the object borrowed (so no one can
+        // obtain mutable access or free it), and moving the guard does not move the object.
+        unsafe { &*self.value }
+    }
+}
+
+impl<T: GodotClass, U: ?Sized + Debug> Debug for MappedRef<'_, T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Mutably/exclusively bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
 /// See [`Gd::bind_mut`][crate::obj::Gd::bind_mut] for usage.
@@ -14,6 +14,10 @@ use std::num::NonZeroU64;
 ///
 /// This is its own type for type safety and to deal with the inconsistent representation in Godot as both `u64` (C++) and `i64` (GDScript).
 /// You can usually treat this as an opaque value and pass it to and from GDScript; there are conversion methods however.
+///
+/// `InstanceId` implements `Eq`, `Ord` and `Hash`, so it can be used as a key in `HashMap`, `BTreeMap` and similar collections. Hashing is
+/// derived from the numeric ID and thus stable for the lifetime of the process. With the `serde` feature, it is (de)serialized as its `i64`
+/// representation. Note that IDs are only meaningful within the running engine instance; persisting them across sessions is not useful.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct InstanceId {
@@ -93,3 +97,67 @@ impl FromGodot for InstanceId {
         Self::try_from_i64(via).ok_or_else(|| FromGodotError::ZeroInstanceId.into_error(via))
     }
 }
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    // For "Available on crate feature `serde`" in docs. Cannot be inherited from module.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for InstanceId {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_i64(self.to_i64())
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for InstanceId {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let id = i64::deserialize(deserializer)?;
+            Self::try_from_i64(id).ok_or_else(|| D::Error::custom("expected non-zero instance ID"))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn hash_and_ord() {
+        let a = InstanceId::from_i64(42);
+        let b = InstanceId::from_i64(-7);
+
+        let set: HashSet<InstanceId> = [a, b, a].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&InstanceId::from_i64(42)));
+
+        assert_eq!(a.cmp(&InstanceId::from_i64(42)), std::cmp::Ordering::Equal);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let id = InstanceId::from_i64(-9223372036854775000);
+        crate::builtin::test_utils::roundtrip(&id, "-9223372036854775000");
+
+        let zero = serde_json::from_str::<InstanceId>("0");
+        assert!(zero.is_err());
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Opt-in detection of `RefCounted` objects that are never freed, typically due to reference cycles.
+//!
+//! Enabled by setting the environment variable `GODOT_RUST_TRACK_LEAKS` (to any value) before the library is loaded. Every `RefCounted`
+//! object constructed through gdext is then recorded, and those still alive when the library is unloaded are reported.
+//!
+//! Only active in Debug builds; in Release, all functions are empty and compile away.
+
+use crate::obj::{Gd, GodotClass};
+
+#[cfg(debug_assertions)]
+use crate::meta::ClassName;
+#[cfg(debug_assertions)]
+use crate::obj::InstanceId;
+#[cfg(debug_assertions)]
+use std::collections::HashMap;
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(debug_assertions)]
+use std::sync::LazyLock;
+
+/// Environment variable that enables the tracker.
+#[cfg(debug_assertions)]
+const ENV_VAR: &str = "GODOT_RUST_TRACK_LEAKS";
+
+/// Lower bound for the number of entries, after which dead objects are pruned.
+#[cfg(debug_assertions)]
+const MIN_PRUNE_THRESHOLD: usize = 256;
+
+/// Whether tracking is enabled. Kept outside the tracker, so that the common disabled case does not need to take the lock.
+#[cfg(debug_assertions)]
+static ENABLED: LazyLock<AtomicBool> =
+    LazyLock::new(|| AtomicBool::new(std::env::var_os(ENV_VAR).is_some()));
+
+#[cfg(debug_assertions)]
+struct LeakTracker {
+    live: HashMap<InstanceId, ClassName>,
+    prune_threshold: usize,
+}
+
+#[cfg(debug_assertions)]
+static TRACKER: sys::Global<LeakTracker> = sys::Global::new(|| LeakTracker {
+    live: HashMap::new(),
+    prune_threshold: MIN_PRUNE_THRESHOLD,
+});
+
+/// Records a newly constructed object of exact type `T`, if it is `RefCounted` and tracking is enabled.
+#[inline]
+pub(crate) fn track<T: GodotClass>(_obj: &Gd<T>) {
+    #[cfg(debug_assertions)]
+    track_debug(_obj);
+}
+
+/// Reports all tracked objects that are still alive, then forgets about them.
+///
+/// Called on library unload, after user code has run.
+#[inline]
+pub(crate) fn report_leaks() {
+    #[cfg(debug_assertions)]
+    report_leaks_debug();
+}
+
+/// Enables or disables tracking at runtime, returning the previous state. Objects tracked so far are kept.
+///
+/// Overrides the environment variable; used by integration tests.
+#[cfg(debug_assertions)]
+pub fn set_leak_tracking(enabled: bool) -> bool {
+    ENABLED.swap(enabled, Ordering::Relaxed)
+}
+
+#[cfg(debug_assertions)]
+fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[cfg(debug_assertions)]
+fn track_debug<T: GodotClass>(obj: &Gd<T>) {
+    if !is_enabled() {
+        return;
+    }
+
+    let instance_id = obj.instance_id();
+    if !instance_id.is_ref_counted() {
+        return;
+    }
+
+    let mut tracker = TRACKER.lock();
+
+    // Objects that died in the meantime are removed lazily; doubling the threshold keeps the amortized cost constant.
+    if tracker.live.len() >= tracker.prune_threshold {
+        tracker.live.retain(|id, _| is_alive(*id));
+        tracker.prune_threshold = (tracker.live.len() * 2).max(MIN_PRUNE_THRESHOLD);
+    }
+
+    // All call sites construct an object of exactly type T, so the static class name is also the dynamic one.
+    tracker.live.insert(instance_id, T::class_name());
+}
+
+/// Reports all tracked objects that are still alive, then forgets about them. Returns the reported objects, sorted by instance ID.
+#[cfg(debug_assertions)]
+pub fn report_leaks_debug() -> Vec<(InstanceId, ClassName)> {
+    if !is_enabled() {
+        return Vec::new();
+    }
+
+    let mut tracker = TRACKER.lock();
+    let mut leaked: Vec<(InstanceId, ClassName)> = tracker
+        .live
+        .drain()
+        .filter(|(id, _)| is_alive(*id))
+        .collect();
+    tracker.prune_threshold = MIN_PRUNE_THRESHOLD;
+    drop(tracker);
+
+    if leaked.is_empty() {
+        return leaked;
+    }
+
+    leaked.sort_by_key(|(id, _)| id.to_u64());
+
+    let mut message = format!(
+        "{} RefCounted object(s) created by godot-rust were never freed (possible reference cycle):",
+        leaked.len()
+    );
+    for (id, class_name) in &leaked {
+        message.push_str(&format!("\n  * {class_name} (instance ID {id})"));
+    }
+
+    crate::godot_warn!("{message}");
+    leaked
+}
+
+#[cfg(debug_assertions)]
+fn is_alive(instance_id: InstanceId) -> bool {
+    !crate::classes::object_ptr_from_id(instance_id).is_null()
+}
@@ -13,23 +13,28 @@
 
 mod base;
 mod gd;
+mod gd_box;
 mod guards;
 mod instance_id;
 mod onready;
 mod raw_gd;
 mod traits;
+mod weak_gd;
 
+pub(crate) mod leak_tracker;
 pub(crate) mod object_arg;
 pub(crate) mod rtti;
 
 pub use base::*;
 pub use gd::*;
-pub use guards::{BaseMut, BaseRef, GdMut, GdRef};
+pub use gd_box::*;
+pub use guards::{BaseMut, BaseRef, GdMut, GdRef, MappedRef};
 pub use instance_id::*;
 pub use object_arg::AsObjectArg;
 pub use onready::*;
 pub use raw_gd::*;
 pub use traits::*;
+pub use weak_gd::*;
 
 pub mod bounds;
 pub mod script;
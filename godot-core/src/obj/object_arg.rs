@@ -20,6 +20,33 @@ use std::ptr;
 /// - [`Option<Gd<T>>`] and `Option<&Gd<T>>`, to pass optional objects. `None` is mapped to a null argument.
 /// - [`Gd::null_arg()`], to pass `null` arguments without using `Option`.
 ///
+/// The inheritance relationship is checked statically: a `Gd<U>` can only be passed where `T` is expected if `U: Inherits<T>`.
+/// Passing by reference neither moves the handle nor touches the reference count, so it is usually preferable to an explicit
+/// [`upcast()`][Gd::upcast] or `clone()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// let mut parent = Node::new_alloc();
+/// let child = Node3D::new_alloc();
+///
+/// // add_child() accepts `impl AsObjectArg<Node>`; `&Gd<Node3D>` is coerced without moving `child`.
+/// parent.add_child(&child);
+/// assert_eq!(child.get_parent(), Some(parent.clone()));
+///
+/// parent.free(); // also frees child
+/// ```
+///
+/// Passing an object whose class does not inherit the parameter type fails to compile:
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// let mut parent = Node::new_alloc();
+/// let not_a_node = RefCounted::new_gd();
+/// parent.add_child(&not_a_node); // error: RefCounted does not inherit Node
+/// ```
+///
 /// # Nullability
 /// <div class="warning">
 /// The GDExtension API does not inform about nullability of its function parameters. It is up to you to verify that the arguments you pass
@@ -32,7 +59,7 @@ where
     #[doc(hidden)]
     fn as_object_arg(&self) -> ObjectArg<T>;
 
-    /// Returns
+    /// Returns an owned or borrowed version of the object, for cases where it must outlive the current expression.
     #[doc(hidden)]
     fn consume_object(self) -> ObjectCow<T>;
 }
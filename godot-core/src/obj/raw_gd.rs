@@ -11,7 +11,7 @@ use godot_ffi as sys;
 use sys::{interface_fn, GodotFfi, GodotNullableFfi, PtrcallType};
 
 use crate::builtin::Variant;
-use crate::meta::error::{ConvertError, FromVariantError};
+use crate::meta::error::{BindError, ConvertError, FromVariantError};
 use crate::meta::{
     CallContext, ClassName, FromGodot, GodotConvert, GodotFfiVariant, GodotType, ToGodot,
 };
@@ -117,7 +117,7 @@ impl<T: GodotClass> RawGd<T> {
     }
 
     // See use-site for explanation.
-    fn is_cast_valid<U>(&self) -> bool
+    pub(super) fn is_cast_valid<U>(&self) -> bool
     where
         U: GodotClass,
     {
@@ -167,6 +167,31 @@ impl<T: GodotClass> RawGd<T> {
         }
     }
 
+    /// Casts based on the dynamic class reported by Godot, ignoring the static type `T` and the cached RTTI.
+    ///
+    /// Returns a new strong reference on success, or `None` if the object is null, dead or not of class `U`.
+    pub(super) fn dynamic_cast<U>(&self) -> Option<RawGd<U>>
+    where
+        U: GodotClass,
+    {
+        if self.is_null() || !self.is_instance_valid() {
+            return None;
+        }
+
+        // SAFETY: object pointer is alive, as checked above. Object RTTI is always correct, as everything inherits Object.
+        // Weak pointer, so it must be forgotten below.
+        let as_obj = unsafe { RawGd::<classes::Object>::from_obj_sys_weak(self.obj_sys()) };
+
+        // SAFETY: Object is always a base class.
+        let is_class = unsafe { as_obj.as_upcast_ref::<classes::Object>() }
+            .is_class(U::class_name().to_gstring());
+
+        std::mem::forget(as_obj);
+
+        // SAFETY: object is alive and its dynamic class inherits `U`, so the new RTTI is correct.
+        is_class.then(|| unsafe { RawGd::from_obj_sys(self.obj_sys()) })
+    }
+
     /// # Safety
     /// Does not transfer ownership and is thus unsafe. Also operates on shared ref. Either the parameter or
     /// the return value *must* be forgotten (since reference counts are not updated).
@@ -387,6 +412,63 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind()`](Self::bind), but returns an error instead of panicking.
+    ///
+    /// See [`crate::obj::Gd::try_bind()`].
+    pub(crate) fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.try_check_rtti()?;
+
+        let guard = self
+            .storage()
+            .unwrap()
+            .try_get()
+            .map_err(|err| BindError::AlreadyBound {
+                details: err.to_string(),
+            })?;
+
+        Ok(GdRef::from_guard(guard))
+    }
+
+    /// Like [`bind_mut()`](Self::bind_mut), but returns an error instead of panicking.
+    ///
+    /// See [`crate::obj::Gd::try_bind_mut()`].
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.try_check_rtti()?;
+
+        let guard =
+            self.storage()
+                .unwrap()
+                .try_get_mut()
+                .map_err(|err| BindError::AlreadyBound {
+                    details: err.to_string(),
+                })?;
+
+        Ok(GdMut::from_guard(guard))
+    }
+
+    /// Checks only the runtime type against `T`, returning the instance ID. No-op in Release mode.
+    pub(super) fn try_check_type(&self) -> Result<InstanceId, BindError> {
+        let rtti = self.cached_rtti.as_ref().expect("cannot check null object");
+
+        rtti.try_check_type::<T>()
+            .map_err(|actual| BindError::WrongType {
+                expected: T::class_name(),
+                actual,
+            })
+    }
+
+    /// Non-panicking version of [`check_rtti()`](Self::check_rtti).
+    fn try_check_rtti(&self) -> Result<(), BindError> {
+        let instance_id = self.try_check_type()?;
+
+        // Same conditions as classes::ensure_object_alive(); a reused ID is treated as dead, too.
+        if classes::object_ptr_from_id(instance_id) != self.obj_sys() {
+            return Err(BindError::Dead { instance_id });
+        }
+
+        Ok(())
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -595,8 +677,12 @@ impl<T: GodotClass> GodotFfiVariant for RawGd<T> {
         };
 
         raw.with_inc_refcount().owned_cast().map_err(|raw| {
+            let actual =
+                (!raw.is_null() && raw.is_instance_valid()).then(|| raw.as_object().get_class());
+
             FromVariantError::WrongClass {
                 expected: T::class_name(),
+                actual,
             }
             .into_error(raw)
         })
@@ -53,6 +53,17 @@ impl ObjectRtti {
         self.instance_id
     }
 
+    /// Like [`check_type()`](Self::check_type), but returns the dynamic class as an error instead of panicking.
+    #[inline]
+    pub fn try_check_type<T: GodotClass>(&self) -> Result<InstanceId, crate::meta::ClassName> {
+        #[cfg(debug_assertions)]
+        if !crate::classes::object_inherits(self.class_name, T::class_name()) {
+            return Err(self.class_name);
+        }
+
+        Ok(self.instance_id)
+    }
+
     #[inline]
     pub fn instance_id(&self) -> InstanceId {
         self.instance_id
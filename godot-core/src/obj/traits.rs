@@ -137,6 +137,20 @@ pub unsafe trait Inherits<Base: GodotClass>: GodotClass {}
 // SAFETY: Every class is a subclass of itself.
 unsafe impl<T: GodotClass> Inherits<T> for T {}
 
+/// Gives access to the signals of a class, through [`Gd::signals()`][crate::obj::Gd::signals].
+///
+/// Automatically implemented for all engine classes. User classes implement it through `#[derive(GodotClass)]`, providing their own
+/// `#[signal]`s as well as the signals of their engine base class.
+pub trait WithSignals: GodotClass {
+    /// Type returned by [`Gd::signals()`][crate::obj::Gd::signals], with one method per signal, e.g. `SignalsOfNode`.
+    ///
+    /// Signals of base classes are available through `Deref`.
+    type SignalCollection;
+
+    #[doc(hidden)]
+    fn __signals_from(object: &Gd<Self>) -> Self::SignalCollection;
+}
+
 /// Implemented for all user-defined classes, providing extensions on the raw object to interact with `Gd`.
 #[doc(hidden)]
 pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
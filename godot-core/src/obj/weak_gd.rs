@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Weak pointer to a Godot object, which does not keep it alive.
+///
+/// A `WeakGd<T>` only stores the [`InstanceId`] of the object. Unlike [`Gd<T>`], it does not increment the reference count of `RefCounted`
+/// objects, so it can be used to break reference cycles (e.g. between a parent and child that refer to each other). It also works for
+/// manually-managed objects: once such an object is freed, the weak pointer can no longer be upgraded.
+///
+/// To access the object, call [`upgrade()`][Self::upgrade], which returns a strong `Gd<T>` if the object is still alive.
+///
+/// Obtain a `WeakGd<T>` via [`Gd::downgrade()`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::WeakGd;
+///
+/// let node = Node::new_alloc();
+/// let weak: WeakGd<Node> = node.downgrade();
+/// assert!(weak.upgrade().is_some());
+///
+/// node.free();
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // Like Gd<T>, a weak pointer can only be upgraded on the thread that owns the object, so it's neither Send nor Sync.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(crate) fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong pointer to the object, or `None` if it has been destroyed in the meantime.
+    ///
+    /// For `RefCounted` objects, the returned `Gd<T>` keeps the object alive until it is dropped.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Returns the instance ID of the referred-to object, which may no longer be alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+// Manual impls, as derives would require bounds on T.
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> Hash for WeakGd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "WeakGd<{}> {{ id: {} }}",
+            T::class_name(),
+            self.instance_id
+        )
+    }
+}
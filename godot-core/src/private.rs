@@ -15,6 +15,9 @@ pub use sys::out;
 #[cfg(feature = "trace")]
 pub use crate::meta::trace;
 
+#[cfg(debug_assertions)]
+pub use crate::obj::leak_tracker::{report_leaks_debug, set_leak_tracking};
+
 use crate::global::godot_error;
 use crate::meta::error::CallError;
 use crate::meta::CallContext;
@@ -144,6 +147,152 @@ pub struct ClassConfig {
     pub is_tool: bool,
 }
 
+/// Backs the `emit_*` methods generated for `#[signal]` declarations.
+///
+/// Implemented on `&mut T` rather than `T`, so that generated methods can be bounded on it without causing errors for classes that have
+/// no `Base<T>` field -- those only fail to compile once an emitter is actually called.
+#[diagnostic::on_unimplemented(
+    message = "emitting signals via generated `emit_*` methods requires a `Base<T>` field in the class",
+    label = "class has no `Base<T>` field",
+    note = "alternatively, use `Object::emit_signal()` on a `Gd<T>` pointer"
+)]
+pub trait EmitUserSignal {
+    fn emit_user_signal(self, signal_name: &str, args: &[crate::builtin::Variant]);
+}
+
+impl<T> EmitUserSignal for &mut T
+where
+    T: crate::obj::WithBaseField,
+    T::Base: crate::obj::Inherits<crate::classes::Object>,
+{
+    fn emit_user_signal(self, signal_name: &str, args: &[crate::builtin::Variant]) {
+        // Keep the guard alive during emission, so that connected handlers can call back into this object.
+        let mut guard = self.base_mut();
+
+        guard
+            .upcast_mut::<crate::classes::Object>()
+            .emit_signal(signal_name.into(), args);
+    }
+}
+
+/// Converts `Result<T, E>` values returned from `#[func]` methods.
+///
+/// The `Err` case is reported as a failed call to Godot (a `CallError` for `try_*` callers), without unwinding. In that case, the return
+/// value is left at its default (`null` for varcalls).
+///
+/// `#[godot_api]` only uses this trait if the return type is spelled `Result<...>`, possibly with a path (e.g. `io::Result<T>`). Type aliases
+/// with a different name are not recognized, and are passed to Godot as regular return values.
+#[diagnostic::on_unimplemented(
+    message = "`#[func]` returning `Result<T, E>` requires `E: Display`",
+    label = "error type does not implement `Display`"
+)]
+pub trait FuncReturnResult {
+    type Ok;
+
+    fn into_func_result(self) -> Result<Self::Ok, String>;
+}
+
+impl<T, E> FuncReturnResult for Result<T, E>
+where
+    E: std::fmt::Display,
+{
+    type Ok = T;
+
+    fn into_func_result(self) -> Result<T, String> {
+        self.map_err(|err| err.to_string())
+    }
+}
+
+/// Carries `Option<T>` parameters and return values of `#[func]` methods, for `T` that are not nullable in Godot (e.g. `i64`).
+///
+/// `Option<T>: GodotConvert` is only implemented for nullable types such as `Gd<T>`. A blanket impl for all other `T` would overlap with
+/// it (coherence cannot rule out that `T::Via` becomes nullable), so `#[godot_api]` instead replaces `Option<T>` in the signature with
+/// this type, which is passed as `Variant`: `None` maps to nil, and nil maps to `None`.
+///
+/// The replacement works on tokens: it applies to types spelled `Option<...>`, except for `Option<Gd<...>>`, which keeps its typed
+/// representation. `Result<Option<T>, E>` return types are not supported.
+#[derive(Debug)]
+pub struct FuncOption<T>(pub Option<T>);
+
+impl<T> crate::meta::GodotConvert for FuncOption<T> {
+    type Via = crate::builtin::Variant;
+}
+
+impl<T: crate::meta::ToGodot> crate::meta::ToGodot for FuncOption<T> {
+    fn to_godot(&self) -> crate::builtin::Variant {
+        match &self.0 {
+            Some(value) => value.to_variant(),
+            None => crate::builtin::Variant::nil(),
+        }
+    }
+}
+
+impl<T: crate::meta::FromGodot> crate::meta::FromGodot for FuncOption<T> {
+    fn try_from_godot(
+        via: crate::builtin::Variant,
+    ) -> Result<Self, crate::meta::error::ConvertError> {
+        via.try_to_option::<T>().map(FuncOption)
+    }
+}
+
+/// Implemented by every engine class; lists the Godot names of all virtual methods it declares or inherits (e.g. `"_process"`).
+///
+/// Used by `#[godot_api]` to detect `#[func]` methods that are mistaken for virtual overrides.
+pub trait EngineVirtualMethods {
+    const VIRTUAL_METHOD_NAMES: &'static [&'static str];
+}
+
+/// Virtuals available on every class, which are not part of the generated per-class lists (special-cased in `I*` traits).
+const SPECIAL_VIRTUAL_METHOD_NAMES: &[&str] = &[
+    "_notification",
+    "_get",
+    "_set",
+    "_get_property_list",
+    "_property_can_revert",
+    "_property_get_revert",
+    "_to_string",
+];
+
+/// Whether `godot_name` is a virtual method of engine class `T`, which can only be overridden through its `I*` interface trait.
+pub const fn is_engine_virtual_method<T: EngineVirtualMethods>(godot_name: &str) -> bool {
+    contains_str(T::VIRTUAL_METHOD_NAMES, godot_name)
+        || contains_str(SPECIAL_VIRTUAL_METHOD_NAMES, godot_name)
+}
+
+/// Implemented by every engine class; lists the Godot names of all integer constants it declares or inherits, including enumerators.
+///
+/// Used by `#[godot_api]` to detect `#[constant]` declarations that shadow an engine constant.
+pub trait EngineConstants {
+    const CONSTANT_NAMES: &'static [&'static str];
+}
+
+/// Whether `godot_name` is an integer constant (or enumerator) of engine class `T` or one of its bases.
+pub const fn is_engine_constant<T: EngineConstants>(godot_name: &str) -> bool {
+    contains_str(T::CONSTANT_NAMES, godot_name)
+}
+
+const fn contains_str(haystack: &[&str], needle: &str) -> bool {
+    let needle = needle.as_bytes();
+
+    let mut i = 0;
+    while i < haystack.len() {
+        let candidate = haystack[i].as_bytes();
+        if candidate.len() == needle.len() {
+            let mut j = 0;
+            while j < needle.len() && candidate[j] == needle[j] {
+                j += 1;
+            }
+
+            if j == needle.len() {
+                return true;
+            }
+        }
+        i += 1;
+    }
+
+    false
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Capability queries and internal access
 
@@ -291,13 +440,17 @@ pub fn handle_varcall_panic<F, R>(
 
 pub fn handle_ptrcall_panic<F, R>(call_ctx: &CallContext, code: F)
 where
-    F: FnOnce() -> R + std::panic::UnwindSafe,
+    F: FnOnce() -> Result<R, CallError> + std::panic::UnwindSafe,
 {
-    let outcome: Result<R, String> = handle_panic_with_print(|| call_ctx, code, false);
+    let outcome: Result<Result<R, CallError>, String> =
+        handle_panic_with_print(|| call_ctx, code, false);
 
     let call_error = match outcome {
         // All good.
-        Ok(_result) => return,
+        Ok(Ok(_result)) => return,
+
+        // Call error signalled by gdext, e.g. a `#[func]` returning `Err`.
+        Ok(Err(err)) => err,
 
         // Panic occurred (typically through user): forward message.
         Err(panic_msg) => CallError::failed_by_user_panic(call_ctx, panic_msg),
@@ -387,7 +540,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{CallError, CallErrors};
+    use super::{contains_str, CallError, CallErrors};
     use crate::meta::CallContext;
 
     fn make(index: usize) -> CallError {
@@ -432,4 +585,16 @@ mod tests {
         let e = store.remove(id13).expect("generation not yet overwritten");
         assert_eq!(e.method_name(), "method_13");
     }
+
+    #[test]
+    fn test_contains_str() {
+        const NAMES: &[&str] = &["_ready", "_process", "_physics_process"];
+
+        assert!(contains_str(NAMES, "_process"));
+        assert!(contains_str(NAMES, "_ready"));
+        assert!(!contains_str(NAMES, "_proces"));
+        assert!(!contains_str(NAMES, "_processes"));
+        assert!(!contains_str(NAMES, ""));
+        assert!(!contains_str(&[], "_ready"));
+    }
 }
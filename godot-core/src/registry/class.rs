@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ptr;
 
 use crate::init::InitLevel;
@@ -43,6 +43,8 @@ struct ClassRegistrationInfo {
     parent_class_name: Option<ClassName>,
     // Following functions are stored separately, since their order matters.
     register_methods_constants_fn: Option<ErasedRegisterFn>,
+    /// From `#[godot_api(secondary)]` blocks; registered after the primary `#[godot_api]` block.
+    register_secondary_fns: Vec<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is at least one OnReady field)
@@ -73,6 +75,9 @@ impl ClassRegistrationInfo {
             PluginItem::Struct { .. } => 0,
             PluginItem::InherentImpl { .. } => 1,
             PluginItem::ITraitImpl { .. } => 2,
+
+            // Multiple secondary impl blocks are allowed.
+            PluginItem::SecondaryImpl { .. } => return,
         };
 
         if self.component_already_filled[index] {
@@ -130,6 +135,7 @@ pub fn register_class<
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
         register_methods_constants_fn: None,
+        register_secondary_fns: Vec::new(),
         register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
@@ -153,6 +159,10 @@ pub fn auto_register_classes(init_level: InitLevel) {
     //
     let mut map = HashMap::<ClassName, ClassRegistrationInfo>::new();
 
+    // Rust type defining each Godot class name of this level. Different Rust types have distinct `ClassName` keys in `map` even if they
+    // map to the same Godot name (e.g. via #[class(rename)]), so duplicates must be detected separately.
+    let mut rust_types_by_name = HashMap::<String, &'static str>::new();
+
     crate::private::iterate_plugins(|elem: &ClassPlugin| {
         // Filter per ClassPlugin and not PluginItem, because all components of all classes are mixed together in one huge list.
         if elem.init_level != init_level {
@@ -162,6 +172,10 @@ pub fn auto_register_classes(init_level: InitLevel) {
         //out!("* Plugin: {elem:#?}");
 
         let name = elem.class_name;
+        if let PluginItem::Struct { rust_type_name, .. } = &elem.item {
+            insert_unique_class(&mut rust_types_by_name, name.to_string(), rust_type_name);
+        }
+
         let class_info = map
             .entry(name)
             .or_insert_with(|| default_registration_info(name));
@@ -170,8 +184,27 @@ pub fn auto_register_classes(init_level: InitLevel) {
     });
 
     let mut loaded_classes_by_level = global_loaded_classes();
+
+    // Godot names of all classes registered so far, including those of previous init levels.
+    let mut registered_names: HashSet<String> = loaded_classes_by_level
+        .values()
+        .flatten()
+        .map(|loaded| loaded.name.to_string())
+        .collect();
+
     for info in map.into_values() {
         let class_name = info.class_name;
+
+        // Duplicates within this level have been rejected above; a class of a previous level may still have the same name. Godot would
+        // keep the first class and only print an error, so continuing here would register methods and properties on an unrelated class.
+        if !registered_names.insert(class_name.to_string()) {
+            godot_error!(
+                "Failed to register class `{class_name}`: another Rust class is already registered under this name; \
+                use #[class(rename = NewName)] to disambiguate"
+            );
+            continue;
+        }
+
         out!("Register class:   {class_name} at level `{init_level:?}`");
         let loaded_class = LoadedClass {
             name: class_name,
@@ -189,6 +222,25 @@ pub fn auto_register_classes(init_level: InitLevel) {
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
+/// Records that `rust_type_name` defines the Godot class `godot_name`.
+///
+/// # Panics
+/// If another Rust type already defines a class of the same name.
+fn insert_unique_class(
+    rust_types_by_name: &mut HashMap<String, &'static str>,
+    godot_name: String,
+    rust_type_name: &'static str,
+) {
+    if let Some(previous) = rust_types_by_name.get(&godot_name) {
+        panic!(
+            "Godot class `{godot_name}` is defined by both `{previous}` and `{rust_type_name}`; \
+            use #[class(rename = NewName)] to disambiguate"
+        );
+    }
+
+    rust_types_by_name.insert(godot_name, rust_type_name);
+}
+
 pub fn unregister_classes(init_level: InitLevel) {
     let mut loaded_classes_by_level = global_loaded_classes();
     let loaded_classes_current_level = loaded_classes_by_level
@@ -222,6 +274,7 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
     match item {
         PluginItem::Struct {
             base_class_name,
+            rust_type_name: _,
             generated_create_fn,
             generated_recreate_fn,
             register_properties_fn,
@@ -289,6 +342,14 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.register_methods_constants_fn = Some(register_methods_constants_fn);
         }
 
+        PluginItem::SecondaryImpl {
+            register_methods_constants_fn,
+            #[cfg(all(since_api = "4.3", feature = "docs"))]
+                docs: _,
+        } => {
+            c.register_secondary_fns.push(register_methods_constants_fn);
+        }
+
         PluginItem::ITraitImpl {
             user_register_fn,
             user_create_fn,
@@ -416,6 +477,10 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         (register_fn.raw)(&mut class_builder);
     }
 
+    for register_fn in info.register_secondary_fns.iter() {
+        (register_fn.raw)(&mut class_builder);
+    }
+
     if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
@@ -467,6 +532,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
         register_methods_constants_fn: None,
+        register_secondary_fns: Vec::new(),
         register_properties_fn: None,
         user_register_fn: None,
         default_virtual_fn: None,
@@ -557,3 +623,39 @@ fn default_creation_info() -> sys::GDExtensionClassCreationInfo3 {
         class_userdata: ptr::null_mut(),
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_unique_class_distinct() {
+        let mut rust_types_by_name = HashMap::new();
+        insert_unique_class(
+            &mut rust_types_by_name,
+            "Player".to_string(),
+            "game::Player",
+        );
+        insert_unique_class(&mut rust_types_by_name, "Enemy".to_string(), "game::Enemy");
+
+        assert_eq!(rust_types_by_name.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "`Player` is defined by both `game::Player` and `game::npc::Player`")]
+    fn insert_unique_class_same_level_duplicate() {
+        let mut rust_types_by_name = HashMap::new();
+        insert_unique_class(
+            &mut rust_types_by_name,
+            "Player".to_string(),
+            "game::Player",
+        );
+        insert_unique_class(
+            &mut rust_types_by_name,
+            "Player".to_string(),
+            "game::npc::Player",
+        );
+    }
+}
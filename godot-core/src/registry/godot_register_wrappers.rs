@@ -7,11 +7,13 @@
 
 //! Internal registration machinery used by proc-macro APIs.
 
-use crate::builtin::StringName;
+use crate::builtin::{
+    GString, StringName, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
 use crate::global::PropertyUsageFlags;
 use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
 use crate::obj::GodotClass;
-use crate::registry::property::{Export, Var};
+use crate::registry::property::{Export, PhantomVar, Var};
 use crate::{classes, sys};
 use godot_ffi::GodotFfi;
 
@@ -79,3 +81,177 @@ fn register_var_or_export_inner(
         );
     }
 }
+
+/// Starts an inspector group for all subsequently registered properties, like GDScript's `@export_group`.
+///
+/// If `prefix` is non-empty, only properties starting with it are part of the group. An empty `group_name` ends the current group.
+pub fn register_group<C: GodotClass>(group_name: &str, prefix: &str) {
+    let group_name = GString::from(group_name);
+    let prefix = GString::from(prefix);
+    let class_name = C::class_name();
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_property_group)(
+            sys::get_library(),
+            class_name.string_sys(),
+            group_name.string_sys(),
+            prefix.string_sys(),
+        );
+    }
+}
+
+/// Starts an inspector subgroup inside the current group, like GDScript's `@export_subgroup`.
+pub fn register_subgroup<C: GodotClass>(subgroup_name: &str, prefix: &str) {
+    let subgroup_name = GString::from(subgroup_name);
+    let prefix = GString::from(prefix);
+    let class_name = C::class_name();
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_property_subgroup)(
+            sys::get_library(),
+            class_name.string_sys(),
+            subgroup_name.string_sys(),
+            prefix.string_sys(),
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Validation of custom #[var] accessors
+
+/// Receiver of a custom `#[var]` getter/setter, used to disambiguate the blanket impls of [`PropertyGetter`] and [`PropertySetter`].
+pub struct AccessorRef;
+pub struct AccessorMut;
+pub struct AccessorGd;
+
+/// Functions that can be used with `#[var(get = ...)]` for a property of type `T`.
+#[diagnostic::on_unimplemented(
+    message = "`#[var(get = ...)]` function must have signature `fn(&self) -> {T}`, matching the property type",
+    label = "invalid getter signature"
+)]
+pub trait PropertyGetter<C, T, Receiver> {}
+
+impl<C, T, F> PropertyGetter<C, T, AccessorRef> for F where F: Fn(&C) -> T {}
+
+impl<C, T, F> PropertyGetter<C, T, AccessorMut> for F where F: Fn(&mut C) -> T {}
+
+impl<C, T, F> PropertyGetter<C, T, AccessorGd> for F
+where
+    C: GodotClass,
+    F: Fn(crate::obj::Gd<C>) -> T,
+{
+}
+
+/// Functions that can be used with `#[var(set = ...)]` for a property of type `T`.
+#[diagnostic::on_unimplemented(
+    message = "`#[var(set = ...)]` function must have signature `fn(&mut self, value: {T})`, matching the property type",
+    label = "invalid setter signature"
+)]
+pub trait PropertySetter<C, T, Receiver> {}
+
+impl<C, T, F> PropertySetter<C, T, AccessorMut> for F where F: Fn(&mut C, T) {}
+
+impl<C, T, F> PropertySetter<C, T, AccessorRef> for F where F: Fn(&C, T) {}
+
+impl<C, T, F> PropertySetter<C, T, AccessorGd> for F
+where
+    C: GodotClass,
+    F: Fn(crate::obj::Gd<C>, T),
+{
+}
+
+/// Maps the field type of a `#[var]` to the type that its custom accessors take or return.
+///
+/// Only implemented for [`PhantomVar<T>`], whose accessors use `T`. For all other fields, the
+/// macro uses the field type directly.
+pub trait PhantomVarValue {
+    type Value;
+}
+
+impl<T> PhantomVarValue for PhantomVar<T> {
+    type Value = T;
+}
+
+/// Statically verifies that a custom `#[var]` getter has a valid receiver and returns exactly the property type `T`.
+pub fn ensure_property_getter<C, T, F, R>(_getter: F)
+where
+    C: GodotClass,
+    F: PropertyGetter<C, T, R>,
+{
+}
+
+/// Statically verifies that a custom `#[var]` setter has a valid receiver and accepts exactly the property type `T`.
+pub fn ensure_property_setter<C, T, F, R>(_setter: F)
+where
+    C: GodotClass,
+    F: PropertySetter<C, T, R>,
+{
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Compile-time validation of #[export] hints
+
+/// Field types that can be used with `#[export(range = ...)]`.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(range = ...)]` can only be used on integer, float or vector fields",
+    label = "type is not numeric"
+)]
+pub trait ExportRange {}
+
+/// Field types that can be used with `#[export(exp_easing)]`.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(exp_easing)]` can only be used on float fields",
+    label = "type is not a float"
+)]
+pub trait ExportExpEasing {}
+
+/// Field types that can be used with `#[export(enum = ...)]`.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(enum = ...)]` can only be used on integer or string fields",
+    label = "type is neither integer nor string"
+)]
+pub trait ExportEnum {}
+
+/// Field types that can be used with `#[export(file)]`, `#[export(global_file)]`, `#[export(dir)]` and `#[export(global_dir)]`.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(file/dir)]` hints can only be used on string fields",
+    label = "type is not a string"
+)]
+pub trait ExportFile {}
+
+/// Field types that can be used with `#[export(multiline)]`.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(multiline)]` can only be used on string fields",
+    label = "type is not a string"
+)]
+pub trait ExportMultiline {}
+
+macro_rules! impl_export_hint_check {
+    ($Trait:ident for $($Ty:ty),+) => {
+        $( impl $Trait for $Ty {} )+
+    };
+}
+
+impl_export_hint_check!(ExportRange for i8, i16, i32, i64, u8, u16, u32, f32, f64);
+impl_export_hint_check!(ExportRange for Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i);
+impl_export_hint_check!(ExportExpEasing for f32, f64);
+impl_export_hint_check!(ExportEnum for i8, i16, i32, i64, u8, u16, u32, String, GString, StringName);
+impl_export_hint_check!(ExportFile for String, GString);
+impl_export_hint_check!(ExportMultiline for String, GString);
+
+// Hints on a PhantomVar<T> apply to T.
+impl<T: ExportRange> ExportRange for PhantomVar<T> {}
+impl<T: ExportExpEasing> ExportExpEasing for PhantomVar<T> {}
+impl<T: ExportEnum> ExportEnum for PhantomVar<T> {}
+impl<T: ExportFile> ExportFile for PhantomVar<T> {}
+impl<T: ExportMultiline> ExportMultiline for PhantomVar<T> {}
+
+pub fn ensure_export_range<T: ExportRange>() {}
+
+pub fn ensure_export_exp_easing<T: ExportExpEasing>() {}
+
+pub fn ensure_export_enum<T: ExportEnum>() {}
+
+pub fn ensure_export_file<T: ExportFile>() {}
+
+pub fn ensure_export_multiline<T: ExportMultiline>() {}
@@ -55,6 +55,9 @@ pub enum PluginItem {
     Struct {
         base_class_name: ClassName,
 
+        /// Path of the Rust type, e.g. `my_crate::Player`. Used to report classes that are defined more than once.
+        rust_type_name: &'static str,
+
         /// Godot low-level `create` function, wired up to library-generated `init`.
         generated_create_fn: Option<
             unsafe extern "C" fn(
@@ -111,6 +114,14 @@ pub enum PluginItem {
         docs: InherentImplDocs,
     },
 
+    /// Collected from `#[godot_api(secondary)] impl MyClass`. A class can have any number of these.
+    SecondaryImpl {
+        /// Callback to library-generated function which registers functions, signals and constants in the `impl` block.
+        register_methods_constants_fn: ErasedRegisterFn,
+        #[cfg(all(since_api = "4.3", feature = "docs"))]
+        docs: InherentImplDocs,
+    },
+
     /// Collected from `#[godot_api] impl I... for MyClass`.
     ITraitImpl {
         #[cfg(all(since_api = "4.3", feature = "docs"))]
@@ -101,6 +101,86 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Properties without storage
+
+/// Declares a property of type `T` that has no backing field, like a GDScript `var` with both `get:` and `set:` blocks.
+///
+/// `PhantomVar<T>` is a zero-sized field type. It is registered as a property of type `T`, but all reads and writes go through custom
+/// accessors, which must be specified with `#[var(get = ..., set = ...)]` (or only one of them, for read/write-only properties).
+/// Their signatures are checked against `T`, not against `PhantomVar<T>`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Player {
+///     health: i64,
+///
+///     #[var(get = is_alive)]
+///     alive: PhantomVar<bool>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     fn is_alive(&self) -> bool {
+///         self.health > 0
+///     }
+/// }
+/// ```
+pub struct PhantomVar<T>(std::marker::PhantomData<T>);
+
+// Manual impls, to not require bounds on `T`.
+impl<T> Default for PhantomVar<T> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<T> Clone for PhantomVar<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PhantomVar<T> {}
+
+impl<T> std::fmt::Debug for PhantomVar<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PhantomVar")
+    }
+}
+
+impl<T: GodotConvert> GodotConvert for PhantomVar<T> {
+    type Via = T::Via;
+}
+
+// The #[derive(GodotClass)] macro rejects generated accessors for PhantomVar fields, so get_property() and set_property() are not called.
+impl<T: Var> Var for PhantomVar<T> {
+    fn get_property(&self) -> Self::Via {
+        unreachable!("PhantomVar has no value; a custom getter must be used")
+    }
+
+    fn set_property(&mut self, _value: Self::Via) {
+        unreachable!("PhantomVar has no value; a custom setter must be used")
+    }
+
+    fn var_hint() -> PropertyHintInfo {
+        T::var_hint()
+    }
+}
+
+impl<T: Export> Export for PhantomVar<T> {
+    fn export_hint() -> PropertyHintInfo {
+        T::export_hint()
+    }
+
+    fn as_node_class() -> Option<ClassName> {
+        T::as_node_class()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Export machinery
 
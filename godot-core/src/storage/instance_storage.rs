@@ -93,6 +93,12 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
 
+    /// Returns a shared reference to this storage's instance, or an error if it is already exclusively bound.
+    fn try_get(&self) -> Result<RefGuard<'_, Self::Instance>, Box<dyn std::error::Error>>;
+
+    /// Returns a mutable/exclusive reference to this storage's instance, or an error if it is already bound.
+    fn try_get_mut(&self) -> Result<MutGuard<'_, Self::Instance>, Box<dyn std::error::Error>>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
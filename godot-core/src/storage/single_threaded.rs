@@ -60,7 +60,7 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn get(&self) -> RefGuard<'_, T> {
-        self.user_instance.borrow().unwrap_or_else(|err| {
+        self.try_get().unwrap_or_else(|err| {
             panic!(
                 "\
                     Gd<T>::bind() failed, already bound; T = {}.\n  \
@@ -73,7 +73,7 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn get_mut(&self) -> MutGuard<'_, T> {
-        self.user_instance.borrow_mut().unwrap_or_else(|err| {
+        self.try_get_mut().unwrap_or_else(|err| {
             panic!(
                 "\
                     Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
@@ -85,6 +85,14 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         })
     }
 
+    fn try_get(&self) -> Result<RefGuard<'_, T>, Box<dyn std::error::Error>> {
+        self.user_instance.borrow()
+    }
+
+    fn try_get_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn std::error::Error>> {
+        self.user_instance.borrow_mut()
+    }
+
     fn get_inaccessible<'a: 'b, 'b>(
         &'a self,
         value: &'b mut Self::Instance,
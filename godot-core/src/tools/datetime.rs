@@ -0,0 +1,164 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::RangeInclusive;
+
+use crate::builtin::{Dictionary, Variant};
+use crate::classes::time::{Month, Weekday};
+use crate::classes::Time;
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+
+/// Calendar date and time of day, as used by the [`Time`] singleton.
+///
+/// Godot represents date-times as dictionaries, e.g. in [`Time::get_datetime_dict_from_system()`]. This struct is a typed equivalent,
+/// with the same field names and value ranges. It converts to and from such a dictionary via [`to_dictionary()`][Self::to_dictionary],
+/// [`try_from_dictionary()`][Self::try_from_dictionary] or the [`ToGodot`]/[`FromGodot`] traits.
+///
+/// Conversions from dictionaries check that values are in range. Fields can also be set directly, in which case it is the user's
+/// responsibility to keep them valid.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::Datetime;
+///
+/// let now = Datetime::from_system(true);
+/// godot_print!("{}-{:02}-{:02}", now.year, now.month.ord(), now.day);
+///
+/// // Round trip through Godot's representation.
+/// let dict: Dictionary = now.to_dictionary();
+/// assert_eq!(Datetime::try_from_dictionary(&dict).unwrap(), now);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Datetime {
+    /// Year, e.g. `2024`. Can be negative for dates before year 0 (proleptic Gregorian calendar).
+    pub year: i64,
+
+    /// Month of the year, from January (`1`) to December (`12`).
+    pub month: Month,
+
+    /// Day of the month, from `1` to `31`.
+    pub day: u8,
+
+    /// Day of the week, from Sunday (`0`) to Saturday (`6`).
+    pub weekday: Weekday,
+
+    /// Hour of the day, from `0` to `23`.
+    pub hour: u8,
+
+    /// Minute of the hour, from `0` to `59`.
+    pub minute: u8,
+
+    /// Second of the minute, from `0` to `59`.
+    pub second: u8,
+}
+
+impl Datetime {
+    /// Current date and time of the system, either in UTC (`utc = true`) or in the local timezone.
+    ///
+    /// _Godot equivalent: `Time.get_datetime_dict_from_system(utc)`_
+    #[doc(alias = "get_datetime_dict_from_system")]
+    pub fn from_system(utc: bool) -> Self {
+        let dict = Time::singleton()
+            .get_datetime_dict_from_system_ex()
+            .utc(utc)
+            .done();
+
+        Self::from_engine_dictionary(&dict)
+    }
+
+    /// Date and time corresponding to `unix_time`, the number of seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+    ///
+    /// The timezone is not considered, so the result is in UTC.
+    ///
+    /// _Godot equivalent: `Time.get_datetime_dict_from_unix_time(unix_time)`_
+    #[doc(alias = "get_datetime_dict_from_unix_time")]
+    pub fn from_unix_time(unix_time: i64) -> Self {
+        let dict = Time::singleton().get_datetime_dict_from_unix_time(unix_time);
+
+        Self::from_engine_dictionary(&dict)
+    }
+
+    /// Parses a dictionary with keys `year`, `month`, `day`, `weekday`, `hour`, `minute` and `second`.
+    ///
+    /// Additional keys (such as `dst` from [`Time::get_datetime_dict_from_system()`]) are ignored. Fails if a key is missing, holds
+    /// a non-integer value, or a value is outside its range.
+    pub fn try_from_dictionary(dict: &Dictionary) -> Result<Self, ConvertError> {
+        Ok(Self {
+            year: get_value(dict, "year")?,
+            month: get_value(dict, "month")?,
+            day: get_in_range(dict, "day", 1..=31)?,
+            weekday: get_value(dict, "weekday")?,
+            hour: get_in_range(dict, "hour", 0..=23)?,
+            minute: get_in_range(dict, "minute", 0..=59)?,
+            second: get_in_range(dict, "second", 0..=59)?,
+        })
+    }
+
+    /// Converts to a dictionary with keys `year`, `month`, `day`, `weekday`, `hour`, `minute` and `second`.
+    ///
+    /// The result can be passed to `Time` methods accepting a date-time dictionary, e.g. `get_unix_time_from_datetime_dict()`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("year", self.year);
+        dict.set("month", self.month);
+        dict.set("day", self.day);
+        dict.set("weekday", self.weekday);
+        dict.set("hour", self.hour);
+        dict.set("minute", self.minute);
+        dict.set("second", self.second);
+        dict
+    }
+
+    fn from_engine_dictionary(dict: &Dictionary) -> Self {
+        Self::try_from_dictionary(dict)
+            .unwrap_or_else(|err| panic!("Godot returned invalid date-time dictionary: {err}"))
+    }
+}
+
+impl GodotConvert for Datetime {
+    type Via = Dictionary;
+}
+
+impl ToGodot for Datetime {
+    fn to_godot(&self) -> Self::Via {
+        self.to_dictionary()
+    }
+}
+
+impl FromGodot for Datetime {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_dictionary(&via)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+fn get_value<T: FromGodot>(dict: &Dictionary, key: &str) -> Result<T, ConvertError> {
+    let value: Variant = dict
+        .get(key)
+        .ok_or_else(|| ConvertError::new(format!("date-time dictionary is missing key '{key}'")))?;
+
+    value.try_to::<T>()
+}
+
+fn get_in_range(
+    dict: &Dictionary,
+    key: &str,
+    range: RangeInclusive<u8>,
+) -> Result<u8, ConvertError> {
+    let value: i64 = get_value(dict, key)?;
+
+    match u8::try_from(value) {
+        Ok(value) if range.contains(&value) => Ok(value),
+        _ => Err(ConvertError::new(format!(
+            "date-time dictionary has value {value} for key '{key}', expected range {range:?}"
+        ))),
+    }
+}
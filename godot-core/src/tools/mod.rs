@@ -10,10 +10,12 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+mod datetime;
 mod gfile;
 mod save_load;
 mod translate;
 
+pub use datetime::*;
 pub use gfile::*;
 pub use save_load::*;
 pub use translate::*;
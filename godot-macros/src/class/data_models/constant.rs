@@ -8,10 +8,19 @@
 use crate::util::bail;
 use crate::{util, ParseResult};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
+use std::collections::HashSet;
 
 pub struct ConstDefinition {
     pub raw_constant: venial::Constant,
+
+    /// Godot enum or bitfield that the constant belongs to, from `#[constant(enum = ...)]` or `#[constant(bitfield = ...)]`.
+    pub group: Option<ConstGroup>,
+}
+
+pub struct ConstGroup {
+    pub name: Ident,
+    pub is_bitfield: bool,
 }
 
 pub fn make_constant_registration(
@@ -23,13 +32,20 @@ pub fn make_constant_registration(
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
 
-    for constant in consts.iter() {
-        let constant = &constant.raw_constant;
+    // Enums and bitfields in order of first appearance, each with their constants.
+    let mut groups: Vec<(&ConstGroup, Vec<TokenStream>)> = Vec::new();
+
+    // Names of constants that are not conditionally compiled; used to detect duplicates.
+    let mut unconditional_names: HashSet<String> = HashSet::new();
+
+    for definition in consts.iter() {
+        let constant = &definition.raw_constant;
         if constant.initializer.is_none() {
             return bail!(constant, "exported const should have initializer");
         };
 
         let name = &constant.name;
+        let name_str = name.to_string();
 
         // In contrast to #[func] and #[signal], we don't remove the attributes from constant signatures
         // within process_godot_constants().
@@ -37,14 +53,66 @@ pub fn make_constant_registration(
             .into_iter()
             .collect::<Vec<_>>();
 
-        // Transport #[cfg] attributes to the FFI glue, to ensure constants which were conditionally removed
-        // from compilation don't cause errors.
-        integer_constant_cfg_attrs.push(cfg_attrs);
-        integer_constant_names.push(constant.name.to_string());
-        integer_constant_values.push(quote! { #class_name::#name });
+        // Duplicates can only be detected reliably for constants without #[cfg]; others may be mutually exclusive.
+        if cfg_attrs.is_empty() && !unconditional_names.insert(name_str.clone()) {
+            return bail!(
+                name,
+                "#[constant]: `{name_str}` is registered more than once"
+            );
+        }
+
+        match &definition.group {
+            None => {
+                // Transport #[cfg] attributes to the FFI glue, to ensure constants which were conditionally removed
+                // from compilation don't cause errors.
+                integer_constant_cfg_attrs.push(cfg_attrs);
+                integer_constant_names.push(name_str);
+                integer_constant_values.push(quote! { #class_name::#name });
+            }
+            Some(group) => {
+                let push = quote! {
+                    #(#cfg_attrs)*
+                    constants.push(IntegerConstant::new(StringName::from(#name_str), #class_name::#name));
+                };
+
+                match groups.iter_mut().find(|(g, _)| g.name == group.name) {
+                    Some((existing, pushes)) => {
+                        if existing.is_bitfield != group.is_bitfield {
+                            return bail!(
+                                &group.name,
+                                "#[constant]: `{}` is used both as enum and bitfield",
+                                group.name
+                            );
+                        }
+                        pushes.push(push);
+                    }
+                    None => groups.push((group, vec![push])),
+                }
+            }
+        }
     }
 
-    let tokens = if !integer_constant_names.is_empty() {
+    let group_registrations = groups.into_iter().map(|(group, pushes)| {
+        let group_name_str = group.name.to_string();
+        let kind = if group.is_bitfield {
+            quote! { ConstantKind::Bitfield { name, flags: constants } }
+        } else {
+            quote! { ConstantKind::Enum { name, enumerators: constants } }
+        };
+
+        quote! {
+            {
+                let name = StringName::from(#group_name_str);
+                let mut constants = Vec::new();
+                #( #pushes )*
+
+                ExportConstant::new(#class_name_obj, #kind).register();
+            }
+        }
+    });
+    let group_registrations = group_registrations.collect::<Vec<_>>();
+
+    let tokens = if !integer_constant_names.is_empty() || !group_registrations.is_empty() {
         quote! {
             use ::godot::register::private::constant::*;
             use ::godot::meta::ClassName;
@@ -62,6 +130,8 @@ pub fn make_constant_registration(
                     )
                 ).register();
             )*
+
+            #( #group_registrations )*
         }
     } else {
         TokenStream::new()
@@ -69,3 +139,93 @@ pub fn make_constant_registration(
 
     Ok(tokens)
 }
+
+/// Statically checks that no `#[constant]` shadows an integer constant (or enumerator) of the engine base class.
+///
+/// Godot only rejects duplicates within the same class, but silently registers constants that hide one of a base class, e.g.
+/// `NOTIFICATION_READY`. Since the macro cannot know the base class's constants, the check is deferred to const evaluation.
+pub fn make_constant_shadow_checks(consts: &[ConstDefinition], class_name: &Ident) -> TokenStream {
+    let checks = consts.iter().map(|definition| {
+        let constant = &definition.raw_constant;
+        let name_str = constant.name.to_string();
+        let cfg_attrs = util::extract_cfg_attrs(&constant.attributes);
+        let message = format!(
+            "#[constant] `{name_str}` shadows an integer constant of the engine base class of `{class_name}`; choose a different name"
+        );
+
+        quote_spanned! { constant.name.span()=>
+            #(#cfg_attrs)*
+            const _: () = if ::godot::private::is_engine_constant::<
+                <#class_name as ::godot::obj::GodotClass>::Base
+            >(#name_str) {
+                panic!(#message)
+            };
+        }
+    });
+
+    quote! {
+        #( #checks )*
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_registration(impl_tokens: TokenStream) -> ParseResult<TokenStream> {
+        let venial::Item::Impl(impl_block) = venial::parse_item(impl_tokens).expect("parse") else {
+            panic!("expected impl block");
+        };
+
+        let consts = impl_block
+            .body_items
+            .iter()
+            .filter_map(|item| match item {
+                venial::ImplMember::AssocConstant(constant) => Some(ConstDefinition {
+                    raw_constant: constant.clone(),
+                    group: None,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let class_name = util::ident("MyClass");
+        make_constant_registration(consts, &class_name, &quote! { class_name })
+    }
+
+    #[test]
+    fn constant_duplicate_rejected() {
+        let result = make_registration(quote! {
+            impl MyClass {
+                const LIMIT: i64 = 1;
+                const LIMIT: i64 = 2;
+            }
+        });
+
+        let Err(err) = result else {
+            panic!("duplicate constant not reported");
+        };
+
+        let message = err.to_compile_error().to_string();
+        assert!(
+            message.contains("`LIMIT` is registered more than once"),
+            "unexpected error: {message}"
+        );
+    }
+
+    #[test]
+    fn constant_cfg_gated_duplicates_allowed() {
+        let result = make_registration(quote! {
+            impl MyClass {
+                #[cfg(feature = "a")]
+                const LIMIT: i64 = 1;
+                #[cfg(not(feature = "a"))]
+                const LIMIT: i64 = 2;
+            }
+        });
+
+        assert!(result.is_ok());
+    }
+}
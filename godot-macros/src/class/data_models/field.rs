@@ -6,6 +6,8 @@
  */
 
 use crate::class::{FieldExport, FieldVar};
+use crate::util::KvParser;
+use crate::ParseResult;
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -14,7 +16,10 @@ pub struct Field {
     pub default_val: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    pub group: Option<FieldGroup>,
+    pub subgroup: Option<FieldGroup>,
     pub is_onready: bool,
+    pub is_phantom_var: bool,
     #[cfg(feature = "docs")]
     pub attributes: Vec<venial::Attribute>,
 }
@@ -27,13 +32,31 @@ impl Field {
             default_val: None,
             var: None,
             export: None,
+            group: None,
+            subgroup: None,
             is_onready: false,
+            is_phantom_var: false,
             #[cfg(feature = "docs")]
             attributes: field.attributes.clone(),
         }
     }
 }
 
+/// Inspector group started by `#[export_group]` or `#[export_subgroup]` right before a field.
+pub struct FieldGroup {
+    pub name: TokenStream,
+    pub prefix: Option<TokenStream>,
+}
+
+impl FieldGroup {
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let name = parser.handle_expr_required("name")?;
+        let prefix = parser.handle_expr("prefix")?;
+
+        Ok(Self { name, prefix })
+    }
+}
+
 pub struct Fields {
     /// All fields except `base_field`.
     pub all_fields: Vec<Field>,
@@ -58,4 +81,4 @@ impl Fields {
 
         return vars;
     }
-}
\ No newline at end of file
+}
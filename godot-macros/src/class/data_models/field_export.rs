@@ -9,7 +9,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use std::collections::{HashMap, HashSet};
 
-use crate::util::{KvParser, ListParser};
+use crate::util::{bail, KvParser, ListParser};
 use crate::ParseResult;
 
 /// Store info from `#[export]` attribute.
@@ -227,18 +227,23 @@ impl FieldExport {
             None => (),
         }
 
-        if parser.handle_alone("dir")? {
-            return Ok(Self::File {
-                global: false,
-                kind: FileKind::Dir,
-            });
-        }
-
-        if parser.handle_alone("global_dir")? {
-            return Ok(Self::File {
-                global: true,
-                kind: FileKind::Dir,
-            });
+        for (key, global) in [("dir", false), ("global_dir", true)] {
+            match parser.handle_any_entry(key) {
+                Some((_, None)) => {
+                    return Ok(Self::File {
+                        global,
+                        kind: FileKind::Dir,
+                    })
+                }
+                Some((key, Some(_))) => {
+                    // Godot's @export_dir has no filters; give a more helpful error than the generic one.
+                    return bail!(
+                        &key,
+                        "`{key}` does not accept a filter; extension filters are only supported by `file` and `global_file`"
+                    );
+                }
+                None => (),
+            }
         }
 
         if parser.handle_alone("multiline")? {
@@ -375,6 +380,22 @@ macro_rules! quote_export_func {
 }
 
 impl FieldExport {
+    /// Statically verifies that the field type is compatible with the hint, for hints that only apply to certain types.
+    pub fn to_type_check(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
+        let check_fn = match self {
+            FieldExport::Range { .. } => quote! { ensure_export_range },
+            FieldExport::ExpEasing { .. } => quote! { ensure_export_exp_easing },
+            FieldExport::Enum { .. } => quote! { ensure_export_enum },
+            FieldExport::File { .. } => quote! { ensure_export_file },
+            FieldExport::Multiline => quote! { ensure_export_multiline },
+            _ => return None,
+        };
+
+        Some(quote! {
+            ::godot::register::private::#check_fn::<#field_type>();
+        })
+    }
+
     pub fn to_export_hint(&self) -> Option<TokenStream> {
         match self {
             FieldExport::Default => None,
@@ -9,8 +9,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::class::{
-    into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
-    FuncDefinition,
+    into_signature_info, make_method_registration, Field, FieldHint, FuncDefinition,
 };
 use crate::util::KvParser;
 use crate::{util, ParseResult};
@@ -119,9 +118,11 @@ impl GetterSetter {
             GetterSetter::Generated => Some(GetterSetterImpl::from_generated_impl(
                 class_name, kind, field,
             )),
-            GetterSetter::Custom(function_name) => {
-                Some(GetterSetterImpl::from_custom_impl(function_name))
-            }
+            GetterSetter::Custom(function_name) => Some(GetterSetterImpl::from_custom_impl(
+                function_name,
+                kind,
+                field,
+            )),
         }
     }
 
@@ -204,6 +205,7 @@ impl GetterSetterImpl {
                 external_attributes: Vec::new(),
                 rename: None,
                 is_script_virtual: false,
+                shadows_engine_virtual: false,
             },
         );
 
@@ -216,11 +218,30 @@ impl GetterSetterImpl {
         }
     }
 
-    fn from_custom_impl(function_name: &Ident) -> Self {
+    fn from_custom_impl(function_name: &Ident, kind: GetSet, field: &Field) -> Self {
+        let field_type = &field.ty;
+
+        // Accessors of a PhantomVar<T> work with T; all others with the field type itself.
+        let value_type = if field.is_phantom_var {
+            quote! { <#field_type as ::godot::register::private::PhantomVarValue>::Value }
+        } else {
+            quote! { #field_type }
+        };
+
+        // Besides checking that the function exists, verify at compile time that its signature matches the property type.
+        let ensure_fn = match kind {
+            GetSet::Get => quote! { ensure_property_getter },
+            GetSet::Set => quote! { ensure_property_setter },
+        };
+
+        let export_token = quote! {
+            ::godot::register::private::#ensure_fn::<Self, #value_type, _, _>(Self::#function_name);
+        };
+
         Self {
             function_name: function_name.clone(),
             function_impl: TokenStream::new(),
-            export_token: make_existence_check(function_name),
+            export_token,
         }
     }
 }
@@ -19,6 +19,8 @@ pub struct FuncDefinition {
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
     pub is_script_virtual: bool,
+    /// Opt-out of the check that the Godot name doesn't collide with an engine virtual method (`#[func(shadow_virtual)]`).
+    pub shadows_engine_virtual: bool,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -62,12 +64,16 @@ pub fn make_virtual_callback(
 }
 
 /// Generates code that registers the specified method for the given class.
+///
+/// Script-virtual functions (`#[func(virtual)]`) are registered under two names:
+/// * The Godot name (e.g. `greet`) forwards to the dispatching Rust method, which calls a script override if present.
+/// * The script name (e.g. `_greet`) forwards to the early-bound Rust default, so that `super()` in a script override doesn't re-enter
+///   the script. If the function is renamed, both names coincide and only the latter is registered.
 pub fn make_method_registration(
     class_name: &Ident,
     func_definition: FuncDefinition,
 ) -> ParseResult<TokenStream> {
     let signature_info = &func_definition.signature_info;
-    let sig_tuple = signature_info.tuple_type();
 
     let is_script_virtual = func_definition.is_script_virtual;
     let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
@@ -75,19 +81,79 @@ pub fn make_method_registration(
         Err(msg) => return bail_fn(msg, &signature_info.method_name),
     };
 
-    let forwarding_closure =
-        make_forwarding_closure(class_name, signature_info, BeforeKind::Without);
-
     // String literals
     let method_name = &signature_info.method_name;
-    let class_name_str = class_name.to_string();
-    let method_name_str = if let Some(rename) = func_definition.rename {
-        rename
+    let method_name_str = if let Some(rename) = &func_definition.rename {
+        rename.clone()
     } else {
         method_name.to_string()
     };
 
-    let call_ctx = make_call_context(&class_name_str, &method_name_str);
+    // Transport #[cfg] attrs to the FFI glue to ensure functions which were conditionally
+    // removed from compilation don't cause errors.
+    let cfg_attrs = util::extract_cfg_attrs(&func_definition.external_attributes)
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if !is_script_virtual {
+        return Ok(make_registration_block(
+            class_name,
+            signature_info,
+            &method_name_str,
+            &method_flags,
+            &cfg_attrs,
+        ));
+    }
+
+    let script_name_str = func_definition
+        .rename
+        .clone()
+        .unwrap_or_else(|| format!("_{method_name}"));
+
+    let mut early_bound_info = signature_info.clone();
+    early_bound_info.method_name = format_ident!("__earlybound_{}", method_name);
+
+    let default_registration = make_registration_block(
+        class_name,
+        &early_bound_info,
+        &script_name_str,
+        &method_flags,
+        &cfg_attrs,
+    );
+
+    if script_name_str == method_name_str {
+        return Ok(default_registration);
+    }
+
+    let dispatch_registration = make_registration_block(
+        class_name,
+        signature_info,
+        &method_name_str,
+        &method_flags,
+        &cfg_attrs,
+    );
+
+    Ok(quote! {
+        #dispatch_registration
+        #default_registration
+    })
+}
+
+/// Registers a single Godot method `method_name_str`, which forwards to the Rust method `signature_info.method_name`.
+fn make_registration_block(
+    class_name: &Ident,
+    signature_info: &SignatureInfo,
+    method_name_str: &str,
+    method_flags: &TokenStream,
+    cfg_attrs: &[&venial::Attribute],
+) -> TokenStream {
+    let sig_tuple = signature_info.tuple_type();
+
+    let forwarding_closure =
+        make_forwarding_closure(class_name, signature_info, BeforeKind::Without);
+
+    let class_name_str = class_name.to_string();
+    let call_ctx = make_call_context(&class_name_str, method_name_str);
     let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
     let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
 
@@ -97,13 +163,7 @@ pub fn make_method_registration(
         .iter()
         .map(|ident| ident.to_string());
 
-    // Transport #[cfg] attrs to the FFI glue to ensure functions which were conditionally
-    // removed from compilation don't cause errors.
-    let cfg_attrs = util::extract_cfg_attrs(&func_definition.external_attributes)
-        .into_iter()
-        .collect::<Vec<_>>();
-
-    let registration = quote! {
+    quote! {
         #(#cfg_attrs)*
         {
             use ::godot::obj::GodotClass;
@@ -140,9 +200,7 @@ pub fn make_method_registration(
             // Note: information whether the method is virtual is stored in method method_info's flags.
             method_info.register_extension_class_method();
         };
-    };
-
-    Ok(registration)
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -156,13 +214,15 @@ pub enum ReceiverType {
     Static,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SignatureInfo {
     pub method_name: Ident,
     pub receiver_type: ReceiverType,
     pub param_idents: Vec<Ident>,
     pub param_types: Vec<venial::TypeExpr>,
     pub ret_type: TokenStream,
+    /// Whether the declared return type is `Result<T, E>`, in which case `T` is returned to Godot and `E` reported as an error.
+    pub ret_is_result: bool,
 }
 
 impl SignatureInfo {
@@ -173,12 +233,48 @@ impl SignatureInfo {
             param_idents: vec![],
             param_types: vec![],
             ret_type: quote! { () },
+            ret_is_result: false,
         }
     }
 
     pub fn tuple_type(&self) -> TokenStream {
         // Note: for GdSelf receivers, first parameter is not even part of SignatureInfo anymore.
-        util::make_signature_tuple_type(&self.ret_type, &self.param_types)
+        let ret_type = if self.ret_is_result {
+            let ret_type = &self.ret_type;
+            quote! { <#ret_type as ::godot::private::FuncReturnResult>::Ok }
+        } else {
+            let ret_tokens = self.ret_type.clone().into_iter().collect::<Vec<_>>();
+            map_option_type(&ret_tokens).unwrap_or_else(|| self.ret_type.clone())
+        };
+
+        let param_types = self
+            .param_types
+            .iter()
+            .map(|ty| match map_option_type(&ty.tokens) {
+                Some(mapped) => venial::TypeExpr {
+                    tokens: mapped.into_iter().collect(),
+                },
+                None => ty.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        util::make_signature_tuple_type(&ret_type, &param_types)
+    }
+
+    /// Whether the return type is carried as `FuncOption`; see [`map_option_type()`].
+    fn ret_is_option(&self) -> bool {
+        let ret_tokens = self.ret_type.clone().into_iter().collect::<Vec<_>>();
+        !self.ret_is_result && map_option_type(&ret_tokens).is_some()
+    }
+
+    /// Statements converting `FuncOption` parameters back to the `Option` declared by the user.
+    fn unwrap_option_params(&self) -> TokenStream {
+        self.param_idents
+            .iter()
+            .zip(&self.param_types)
+            .filter(|(_, ty)| map_option_type(&ty.tokens).is_some())
+            .map(|(ident, _)| quote! { let #ident = #ident.0; })
+            .collect()
     }
 }
 
@@ -201,6 +297,7 @@ fn make_forwarding_closure(
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
+    let unwrap_option_params = signature_info.unwrap_option_params();
 
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
@@ -225,14 +322,18 @@ fn make_forwarding_closure(
             // Generated default virtual methods (e.g. for ready) may not have an actual implementation (user code), so
             // all they need to do is call the __before_ready() method. This means the actual method call may be optional.
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
-                TokenStream::new()
+                quote! { ::std::result::Result::Ok(()) }
             } else {
-                quote! { instance.#method_name( #(#params),* ) }
+                wrap_result_return(
+                    signature_info,
+                    quote! { instance.#method_name( #(#params),* ) },
+                )
             };
 
             quote! {
                 |instance_ptr, params| {
                     let ( #(#params,)* ) = params;
+                    #unwrap_option_params
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
@@ -246,33 +347,104 @@ fn make_forwarding_closure(
         ReceiverType::GdSelf => {
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
+            let method_call = wrap_result_return(
+                signature_info,
+                quote! { #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*) },
+            );
+
             quote! {
                 |instance_ptr, params| {
                     let ( #(#params,)* ) = params;
+                    #unwrap_option_params
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
+            let method_call = wrap_result_return(
+                signature_info,
+                quote! { #class_name::#method_name(#(#params),*) },
+            );
+
             quote! {
                 |_, params| {
                     let ( #(#params,)* ) = params;
-                    #class_name::#method_name(#(#params),*)
+                    #unwrap_option_params
+                    #method_call
                 }
             }
         }
     }
 }
 
+/// Converts the call's result into `Result<T, String>`, as expected by `in_varcall()` and `in_ptrcall()`.
+///
+/// If the method returns `Result<T, E>`, the error is forwarded to be reported as a failed call. Otherwise, the call cannot fail.
+/// Returns of type `Option<T>` are wrapped in `FuncOption`, see [`map_option_type()`].
+fn wrap_result_return(signature_info: &SignatureInfo, method_call: TokenStream) -> TokenStream {
+    if signature_info.ret_is_result {
+        quote! { ::godot::private::FuncReturnResult::into_func_result(#method_call) }
+    } else if signature_info.ret_is_option() {
+        quote! { ::std::result::Result::Ok(::godot::private::FuncOption(#method_call)) }
+    } else {
+        quote! { ::std::result::Result::Ok(#method_call) }
+    }
+}
+
+/// If a type is syntactically `Option<T>` with `T` not nullable in Godot, returns `::godot::private::FuncOption<T>` to use in its place.
+///
+/// `Option<Gd<T>>` is returned as `None`, as it already converts to a nullable object. Like [`is_result_type()`], this works on tokens
+/// only, so type aliases are not detected.
+fn map_option_type(tokens: &[TokenTree]) -> Option<TokenStream> {
+    let inner = generic_args_of(tokens, "Option")?;
+
+    if generic_args_of(inner, "Gd").is_some() {
+        return None;
+    }
+
+    let inner = inner.iter().cloned().collect::<TokenStream>();
+    Some(quote! { ::godot::private::FuncOption<#inner> })
+}
+
+/// For a type `path::Name<Args>`, returns the tokens of `Args` if the last path segment is `name`.
+fn generic_args_of<'a>(tokens: &'a [TokenTree], name: &str) -> Option<&'a [TokenTree]> {
+    let angle_pos = tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '<'))?;
+
+    let is_name =
+        angle_pos > 0 && matches!(&tokens[angle_pos - 1], TokenTree::Ident(ident) if ident == name);
+    let ends_with_angle =
+        matches!(tokens.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == '>');
+
+    (is_name && ends_with_angle).then(|| &tokens[angle_pos + 1..tokens.len() - 1])
+}
+
+/// Checks whether a type is syntactically a `Result`, e.g. `Result<T, E>`, `std::result::Result<T, E>` or `io::Result<T>`.
+///
+/// Type aliases are not detected, as this works on tokens only.
+fn is_result_type(tokens: &[TokenTree]) -> bool {
+    let angle_pos = tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '<'));
+
+    match angle_pos {
+        Some(pos) if pos > 0 => {
+            matches!(&tokens[pos - 1], TokenTree::Ident(ident) if ident == "Result")
+        }
+        _ => false,
+    }
+}
+
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
-fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
+pub(crate) fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
 where
     In: IntoIterator<Item = TokenTree>,
     Out: FromIterator<TokenTree>,
@@ -308,9 +480,12 @@ pub(crate) fn into_signature_info(
     let num_params = signature.params.inner.len();
     let mut param_idents = Vec::with_capacity(num_params);
     let mut param_types = Vec::with_capacity(num_params);
-    let ret_type = match signature.return_ty {
-        None => quote! { () },
-        Some(ty) => map_self_to_class_name(ty.tokens, class_name),
+    let (ret_type, ret_is_result) = match signature.return_ty {
+        None => (quote! { () }, false),
+        Some(ty) => {
+            let is_result = is_result_type(&ty.tokens);
+            (map_self_to_class_name(ty.tokens, class_name), is_result)
+        }
     };
 
     let mut next_unnamed_index = 0;
@@ -348,6 +523,7 @@ pub(crate) fn into_signature_info(
         param_idents,
         param_types,
         ret_type,
+        ret_is_result,
     }
 }
 
@@ -439,14 +615,10 @@ fn make_ptrcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             ret: sys::GDExtensionTypePtr,
         ) {
             let call_ctx = #call_ctx;
-            let _success = ::godot::private::handle_panic(
-                || &call_ctx,
+            ::godot::private::handle_ptrcall_panic(
+                &call_ctx,
                 || #invocation
             );
-
-            // if success.is_err() {
-            //     // TODO set return value to T::default()?
-            // }
         }
     }
 }
@@ -6,15 +6,18 @@
  */
 
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, SignalDefinition, SignatureInfo,
+    into_signature_info, make_constant_registration, make_constant_shadow_checks,
+    make_method_registration, make_signal_accessors, make_signal_emitters,
+    make_signal_registrations, map_self_to_class_name, ConstDefinition, ConstGroup, FuncDefinition,
+    SignalDefinition, SignatureInfo,
 };
 use crate::util::{bail, require_api_version, KvParser};
 use crate::{util, ParseResult};
 
 use proc_macro2::{Delimiter, Group, Ident, TokenStream};
 use quote::spanned::Spanned;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
+use std::collections::HashMap;
 
 /// Attribute for user-declared function.
 enum ItemAttrType {
@@ -22,9 +25,12 @@ enum ItemAttrType {
         rename: Option<String>,
         is_virtual: bool,
         has_gd_self: bool,
+        shadow_virtual: bool,
     },
     Signal(venial::AttributeValue),
-    Const(#[allow(dead_code)] venial::AttributeValue),
+    Const {
+        group: Option<ConstGroup>,
+    },
 }
 
 struct ItemAttr {
@@ -41,8 +47,11 @@ impl ItemAttr {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Codegen for `#[godot_api] impl MyType`
-pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<TokenStream> {
+/// Codegen for `#[godot_api] impl MyType` and `#[godot_api(secondary)] impl MyType`.
+pub fn transform_inherent_impl(
+    mut impl_block: venial::Impl,
+    is_secondary: bool,
+) -> ParseResult<TokenStream> {
     let class_name = util::validate_impl(&impl_block, None, "godot_api")?;
     let class_name_obj = util::class_name_obj(&class_name);
     let prv = quote! { ::godot::private };
@@ -56,7 +65,12 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     #[cfg(not(all(feature = "docs", since_api = "4.3")))]
     let docs = quote! {};
 
+    let signal_emitters = make_signal_emitters(&signals, &class_name);
+    let signal_accessors = make_signal_accessors(&signals, &class_name);
     let signal_registrations = make_signal_registrations(signals, &class_name_obj);
+    let method_name_markers = make_method_name_markers(&funcs, &class_name);
+    let virtual_name_checks = make_virtual_name_checks(&funcs, &class_name);
+    let constant_shadow_checks = make_constant_shadow_checks(&consts, &class_name);
 
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
@@ -65,9 +79,56 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
 
     let constant_registration = make_constant_registration(consts, &class_name, &class_name_obj)?;
 
+    if is_secondary {
+        // Secondary blocks cannot implement ImplementsGodotApi (only one impl per class), so they register through a free function.
+        // `Self` is not available there, e.g. in signal parameter types.
+        let registrations: TokenStream = map_self_to_class_name(
+            quote! {
+                #( #method_registrations )*
+                #( #signal_registrations )*
+                #constant_registration
+            },
+            &class_name,
+        );
+
+        let result = quote! {
+            #impl_block
+
+            #signal_emitters
+            #signal_accessors
+            #method_name_markers
+            #virtual_name_checks
+            #constant_shadow_checks
+
+            ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+                class_name: #class_name_obj,
+                item: #prv::PluginItem::SecondaryImpl {
+                    register_methods_constants_fn: #prv::ErasedRegisterFn {
+                        raw: {
+                            fn __register_secondary(_class_builder: &mut dyn ::std::any::Any) {
+                                #registrations
+                            }
+                            __register_secondary
+                        },
+                    },
+                    #docs
+                },
+                init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+            });
+        };
+
+        return Ok(result);
+    }
+
     let result = quote! {
         #impl_block
 
+        #signal_emitters
+        #signal_accessors
+        #method_name_markers
+        #virtual_name_checks
+        #constant_shadow_checks
+
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
                 #( #method_registrations )*
@@ -94,6 +155,83 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     Ok(result)
 }
 
+/// Declares a hidden associated constant for each Godot method name.
+///
+/// Multiple `#[godot_api]` blocks (primary and secondary) may exist for the same class, but each is expanded in isolation. Inherent impls of the
+/// same type share one namespace, so registering the same Godot method name in two blocks causes a "duplicate definitions" compile error.
+/// `#[cfg]` attributes are carried over, so that only compiled functions participate.
+fn make_method_name_markers(funcs: &[FuncDefinition], class_name: &Ident) -> TokenStream {
+    let markers = funcs.iter().map(|func| {
+        let rust_name = &func.signature_info.method_name;
+        let godot_name = func.rename.clone().unwrap_or_else(|| rust_name.to_string());
+
+        let marker = format_ident!("__godot_method_{}", godot_name, span = rust_name.span());
+        let cfg_attrs = util::extract_cfg_attrs(&func.external_attributes);
+
+        quote! {
+            #(#cfg_attrs)*
+            #[doc(hidden)]
+            #[allow(non_upper_case_globals)]
+            const #marker: () = ();
+        }
+    });
+
+    quote! {
+        impl #class_name {
+            #( #markers )*
+        }
+    }
+}
+
+/// Statically checks that no `#[func]` is named like an engine virtual method of the base class.
+///
+/// Such functions are registered as regular methods, but never called by the engine -- overriding virtuals is only possible through
+/// the `I*` interface traits. Script-virtual functions (`#[func(virtual)]`) are checked with their `_` prefix, as seen by scripts.
+/// Since the macro cannot know the base class's virtuals, the check is deferred to const evaluation. Only names starting with `_` are
+/// checked, as all engine virtuals follow that convention.
+fn make_virtual_name_checks(funcs: &[FuncDefinition], class_name: &Ident) -> TokenStream {
+    let checks = funcs.iter().filter_map(|func| {
+        let rust_name = &func.signature_info.method_name;
+        let godot_name = func.rename.clone().unwrap_or_else(|| rust_name.to_string());
+
+        let (checked_name, message) = if func.is_script_virtual {
+            let script_name = format!("_{godot_name}");
+            let message = format!(
+                "#[func(virtual)] `{godot_name}` would be overridden in scripts as `{script_name}`, which is an engine virtual method. \
+                Choose a different name, or use #[func(shadow_virtual)] to register it anyway."
+            );
+            (script_name, message)
+        } else {
+            let trait_method = godot_name.trim_start_matches('_');
+            let message = format!(
+                "#[func] `{godot_name}` has the name of an engine virtual method, but does not override it. \
+                Implement `fn {trait_method}` in the interface trait of the base class (e.g. `impl INode for {class_name}`) instead, \
+                or use #[func(shadow_virtual)] to register it as a regular method."
+            );
+            (godot_name, message)
+        };
+
+        if func.shadows_engine_virtual || !checked_name.starts_with('_') {
+            return None;
+        }
+
+        let cfg_attrs = util::extract_cfg_attrs(&func.external_attributes);
+
+        Some(quote_spanned! { rust_name.span()=>
+            #(#cfg_attrs)*
+            const _: () = if ::godot::private::is_engine_virtual_method::<
+                <#class_name as ::godot::obj::GodotClass>::Base
+            >(#checked_name) {
+                panic!(#message)
+            };
+        })
+    });
+
+    quote! {
+        #( #checks )*
+    }
+}
+
 fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
@@ -102,6 +240,9 @@ fn process_godot_fns(
     let mut signal_definitions = vec![];
     let mut virtual_functions = vec![];
 
+    // Godot method names, mapped to the Rust function registering them. Used to detect collisions caused by #[func(rename)].
+    let mut godot_method_names: HashMap<String, Ident> = HashMap::new();
+
     let mut removed_indexes = vec![];
     for (index, item) in impl_block.body_items.iter_mut().enumerate() {
         let venial::ImplMember::AssocFunction(function) = item else {
@@ -134,7 +275,36 @@ fn process_godot_fns(
                 rename,
                 is_virtual,
                 has_gd_self,
+                shadow_virtual,
             } => {
+                let godot_method_name = rename.clone().unwrap_or_else(|| function.name.to_string());
+
+                // Functions with #[cfg] may legitimately share a name, as long as only one of them is compiled. Since cfg predicates
+                // cannot be evaluated inside the macro, such functions are exempt from the collision check.
+                let is_conditional = util::extract_cfg_attrs(&function.attributes)
+                    .into_iter()
+                    .next()
+                    .is_some();
+
+                // Non-renamed script-virtual functions are additionally registered under their `_` prefixed script name.
+                let mut registered_names = vec![godot_method_name];
+                if is_virtual && rename.is_none() {
+                    registered_names.push(format!("_{}", function.name));
+                }
+
+                if !is_conditional {
+                    for godot_method_name in registered_names {
+                        if let Some(existing) = godot_method_names
+                            .insert(godot_method_name.clone(), function.name.clone())
+                        {
+                            return bail!(
+                                &function.name,
+                                "#[func]: Godot method name `{godot_method_name}` is already registered by `{existing}`",
+                            );
+                        }
+                    }
+                }
+
                 let external_attributes = function.attributes.clone();
 
                 // Signatures are the same thing without body.
@@ -186,6 +356,7 @@ fn process_godot_fns(
                     external_attributes,
                     rename,
                     is_script_virtual: is_virtual,
+                    shadows_engine_virtual: shadow_virtual,
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
@@ -194,16 +365,18 @@ fn process_godot_fns(
                 }
 
                 let external_attributes = function.attributes.clone();
+                let vis_marker = function.vis_marker.clone();
                 let sig = util::reduce_to_signature(function);
 
                 signal_definitions.push(SignalDefinition {
                     signature: sig,
                     external_attributes,
+                    vis_marker,
                 });
 
                 removed_indexes.push(index);
             }
-            ItemAttrType::Const(_) => {
+            ItemAttrType::Const { .. } => {
                 return attr.bail(
                     "#[constant] can only be used on associated constant",
                     function,
@@ -246,13 +419,14 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
                 ItemAttrType::Signal(_) => {
                     return bail!(constant, "#[signal] can only be used on functions")
                 }
-                ItemAttrType::Const(_) => {
+                ItemAttrType::Const { group } => {
                     if constant.initializer.is_none() {
                         return bail!(constant, "exported constant must have initializer");
                     }
 
                     let definition = ConstDefinition {
                         raw_constant: constant.clone(),
+                        group,
                     };
 
                     constant_signatures.push(definition);
@@ -303,6 +477,24 @@ fn add_virtual_script_call(
         receiver = util::ident("self");
     };
 
+    // Script overrides only return the success value; wrap it again if the Rust function returns a `Result`.
+    let script_call = quote! {
+        unsafe {
+            <CallSig as ::godot::meta::VarcallSignatureTuple>::out_script_virtual_call(
+                #class_name_str,
+                #method_name_str,
+                method_sname_ptr,
+                object_ptr,
+                args,
+            )
+        }
+    };
+    let script_call = if signature_info.ret_is_result {
+        quote! { ::std::result::Result::Ok(#script_call) }
+    } else {
+        script_call
+    };
+
     let code = quote! {
         let object_ptr = #object_ptr;
         let method_sname = ::godot::builtin::StringName::from(#method_name_str);
@@ -313,15 +505,7 @@ fn add_virtual_script_call(
             // Dynamic dispatch.
             type CallSig = #sig_tuple;
             let args = (#( #arg_names, )*);
-            unsafe {
-                <CallSig as ::godot::meta::VarcallSignatureTuple>::out_script_virtual_call(
-                    #class_name_str,
-                    #method_name_str,
-                    method_sname_ptr,
-                    object_ptr,
-                    args,
-                )
-            }
+            #script_call
         } else {
             // Fall back to default implementation.
             Self::#early_bound_name(#receiver, #( #arg_names ),*)
@@ -372,6 +556,9 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(shadow_virtual)]
+                let shadow_virtual = parser.handle_alone("shadow_virtual")?;
+
                 parser.finish()?;
 
                 ItemAttr {
@@ -381,6 +568,7 @@ where
                         rename,
                         is_virtual,
                         has_gd_self,
+                        shadow_virtual,
                     },
                 }
             }
@@ -398,11 +586,39 @@ where
             }
 
             // #[constant]
-            name if name == "constant" => ItemAttr {
-                attr_name: attr_name.clone(),
-                index,
-                ty: ItemAttrType::Const(attr.value.clone()),
-            },
+            name if name == "constant" => {
+                // Safe unwrap since #[constant] must be present if we got to this point
+                let mut parser = KvParser::parse(attributes, "constant")?.unwrap();
+
+                // #[constant(enum = MyEnum)] or #[constant(bitfield = MyFlags)]
+                let enum_name = parser.handle_ident("enum")?;
+                let bitfield_name = parser.handle_ident("bitfield")?;
+                parser.finish()?;
+
+                let group = match (enum_name, bitfield_name) {
+                    (None, None) => None,
+                    (Some(name), None) => Some(ConstGroup {
+                        name,
+                        is_bitfield: false,
+                    }),
+                    (None, Some(name)) => Some(ConstGroup {
+                        name,
+                        is_bitfield: true,
+                    }),
+                    (Some(_), Some(name)) => {
+                        return bail!(
+                            name,
+                            "#[constant]: keys `enum` and `bitfield` are mutually exclusive"
+                        )
+                    }
+                };
+
+                ItemAttr {
+                    attr_name: attr_name.clone(),
+                    index,
+                    ty: ItemAttrType::Const { group },
+                }
+            }
 
             // Ignore unknown attributes.
             _ => continue,
@@ -425,3 +641,84 @@ where
 fn bail_attr<R>(attr_name: Ident, msg: &str, method: &venial::Function) -> ParseResult<R> {
     bail!(&method.name, "#[{}]: {}", attr_name, msg)
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(impl_tokens: TokenStream) -> ParseResult<Vec<FuncDefinition>> {
+        let venial::Item::Impl(mut impl_block) = venial::parse_item(impl_tokens).expect("parse")
+        else {
+            panic!("expected impl block");
+        };
+
+        let class_name = util::ident("MyClass");
+        process_godot_fns(&class_name, &mut impl_block).map(|(funcs, _signals)| funcs)
+    }
+
+    fn expect_collision(impl_tokens: TokenStream, godot_name: &str) {
+        let Err(err) = process(impl_tokens) else {
+            panic!("collision for `{godot_name}` not reported");
+        };
+
+        let message = err.to_compile_error().to_string();
+        assert!(
+            message.contains(&format!(
+                "Godot method name `{godot_name}` is already registered"
+            )),
+            "unexpected error: {message}"
+        );
+    }
+
+    #[test]
+    fn func_rename_collides_with_name() {
+        expect_collision(
+            quote! {
+                impl MyClass {
+                    #[func(rename = "x")]
+                    fn renamed(&self) {}
+
+                    #[func]
+                    fn x(&self) {}
+                }
+            },
+            "x",
+        );
+    }
+
+    #[test]
+    fn func_two_renames_collide() {
+        expect_collision(
+            quote! {
+                impl MyClass {
+                    #[func(rename = "shared")]
+                    fn first(&self) {}
+
+                    #[func(rename = "shared")]
+                    fn second(&self) {}
+                }
+            },
+            "shared",
+        );
+    }
+
+    #[test]
+    fn func_cfg_gated_names_exempt() {
+        let funcs = process(quote! {
+            impl MyClass {
+                #[cfg(feature = "a")]
+                #[func(rename = "x")]
+                fn x_a(&self) {}
+
+                #[cfg(not(feature = "a"))]
+                #[func(rename = "x")]
+                fn x_b(&self) {}
+            }
+        })
+        .expect("cfg-gated functions must not collide");
+
+        assert_eq!(funcs.len(), 2);
+    }
+}
@@ -7,7 +7,7 @@
 
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{Field, FieldGroup, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -73,6 +73,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         } = var;
 
         let export_hint;
+        let export_type_check;
         let registration_fn;
 
         if let Some(export) = export {
@@ -81,9 +82,11 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             }
 
             export_hint = export.to_export_hint();
+            export_type_check = export.to_type_check(field_type);
             registration_fn = quote! { register_export };
         } else {
             export_hint = None;
+            export_type_check = None;
             registration_fn = quote! { register_var };
         }
 
@@ -144,7 +147,22 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             &mut export_tokens,
         );
 
+        // Groups are pseudo-properties; registering them right before the field puts them at the right place in the property list.
+        for (group, registration_fn) in [
+            (&field.group, quote! { register_group }),
+            (&field.subgroup, quote! { register_subgroup }),
+        ] {
+            if let Some(FieldGroup { name, prefix }) = group {
+                let prefix = prefix.clone().unwrap_or_else(|| quote! { "" });
+
+                export_tokens.push(quote! {
+                    ::godot::register::private::#registration_fn::<#class_name>(#name, #prefix);
+                });
+            }
+        }
+
         export_tokens.push(quote! {
+            #export_type_check
             ::godot::register::private::#registration_fn::<#class_name, #field_type>(
                 #field_name,
                 #getter_name,
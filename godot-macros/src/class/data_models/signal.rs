@@ -5,9 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::class::map_self_to_class_name;
 use crate::util;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 
 /// Holds information known from a signal's definition
 pub struct SignalDefinition {
@@ -16,6 +17,9 @@ pub struct SignalDefinition {
 
     /// The signal's non-gdext attributes (all except #[signal]).
     pub external_attributes: Vec<venial::Attribute>,
+
+    /// Visibility of the signal declaration, carried over to the generated `emit_*` method.
+    pub vis_marker: Option<venial::VisMarker>,
 }
 
 pub fn make_signal_registrations(
@@ -28,6 +32,7 @@ pub fn make_signal_registrations(
         let SignalDefinition {
             signature,
             external_attributes,
+            ..
         } = signal;
         let mut param_types: Vec<venial::TypeExpr> = Vec::new();
         let mut param_names: Vec<String> = Vec::new();
@@ -89,3 +94,167 @@ pub fn make_signal_registrations(
     }
     signal_registrations
 }
+
+/// Generates an `emit_<signal>()` method for each signal, which takes the signal's parameters with their declared types.
+pub fn make_signal_emitters(signals: &[SignalDefinition], class_name: &Ident) -> TokenStream {
+    let mut emitters = Vec::new();
+
+    for signal in signals.iter() {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+            vis_marker,
+        } = signal;
+
+        let mut param_names: Vec<&Ident> = Vec::new();
+        let mut param_types: Vec<&venial::TypeExpr> = Vec::new();
+
+        for param in signature.params.inner.iter() {
+            match &param.0 {
+                venial::FnParam::Typed(param) => {
+                    param_names.push(&param.name);
+                    param_types.push(&param.ty);
+                }
+                venial::FnParam::Receiver(_) => {}
+            };
+        }
+
+        let signal_cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+        let signal_name_str = signature.name.to_string();
+        let emitter_name = format_ident!("emit_{}", signature.name);
+        let doc_str = format!("Emits the `{signal_name_str}` signal with the given arguments.");
+
+        // The bound on `&'emit mut Self` is not checked until the method is called, so classes without a `Base<T>` field still compile.
+        let emitter = quote! {
+            #(#signal_cfg_attrs)*
+            #[doc = #doc_str]
+            #vis_marker fn #emitter_name<'emit>(&'emit mut self, #( #param_names: #param_types ),*)
+            where
+                &'emit mut Self: ::godot::private::EmitUserSignal,
+            {
+                ::godot::private::EmitUserSignal::emit_user_signal(
+                    self,
+                    #signal_name_str,
+                    &[ #( ::godot::meta::ToGodot::to_variant(&#param_names) ),* ],
+                );
+            }
+        };
+
+        emitters.push(emitter);
+    }
+
+    if emitters.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        impl #class_name {
+            #( #emitters )*
+        }
+    }
+}
+
+/// Name of the signal collection of a user class, e.g. `SignalsOfMyClass`.
+pub fn make_signal_collection_name(class_name: &Ident) -> Ident {
+    format_ident!("SignalsOf{}", class_name)
+}
+
+/// Generates the signal collection of a user class, returned by `Gd::signals()`.
+///
+/// `#[signal]` accessors are added by `#[godot_api]` blocks (see [`make_signal_accessors`]); signals of the engine base class
+/// are available through `Deref`.
+pub fn make_signal_collection(
+    class_name: &Ident,
+    vis_marker: &Option<venial::VisMarker>,
+) -> TokenStream {
+    let collection_name = make_signal_collection_name(class_name);
+    let base_collection = quote! {
+        <<#class_name as ::godot::obj::GodotClass>::Base as ::godot::obj::WithSignals>::SignalCollection
+    };
+    let doc_str = format!(
+        "Signals of [`{class_name}`], returned by [`Gd::signals()`][::godot::obj::Gd::signals].\n\n\
+        Signals of the base class are available through `Deref`."
+    );
+
+    quote! {
+        #[doc = #doc_str]
+        #vis_marker struct #collection_name {
+            __base: #base_collection,
+        }
+
+        impl ::std::ops::Deref for #collection_name {
+            type Target = #base_collection;
+
+            fn deref(&self) -> &Self::Target {
+                &self.__base
+            }
+        }
+
+        impl ::godot::obj::WithSignals for #class_name {
+            type SignalCollection = #collection_name;
+
+            fn __signals_from(object: &::godot::obj::Gd<Self>) -> Self::SignalCollection {
+                #collection_name {
+                    __base: <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::WithSignals>::__signals_from(
+                        &object.clone().upcast(),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Generates one method per signal on the class's signal collection, returning a `TypedSignal` with the signal's parameter types.
+pub fn make_signal_accessors(signals: &[SignalDefinition], class_name: &Ident) -> TokenStream {
+    let mut accessors = Vec::new();
+
+    for signal in signals.iter() {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+            vis_marker,
+        } = signal;
+
+        let param_types = signature
+            .params
+            .inner
+            .iter()
+            .filter_map(|param| match &param.0 {
+                venial::FnParam::Typed(param) => Some(&param.ty),
+                venial::FnParam::Receiver(_) => None,
+            });
+
+        let signal_cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+        let signal_name = &signature.name;
+        let signal_name_str = signal_name.to_string();
+        let doc_str = format!("Signal `{signal_name_str}`, declared with `#[signal]`.");
+
+        accessors.push(quote! {
+            #(#signal_cfg_attrs)*
+            #[doc = #doc_str]
+            #vis_marker fn #signal_name(&self) -> ::godot::builtin::TypedSignal<( #( #param_types, )* )> {
+                ::godot::builtin::TypedSignal::__new(self.__object(), #signal_name_str)
+            }
+        });
+    }
+
+    if accessors.is_empty() {
+        return TokenStream::new();
+    }
+
+    // Inside the collection's impl, `Self` would refer to the collection instead of the class.
+    let accessors: TokenStream = map_self_to_class_name(quote! { #( #accessors )* }, class_name);
+    let collection_name = make_signal_collection_name(class_name);
+
+    quote! {
+        impl #collection_name {
+            #accessors
+        }
+    }
+}
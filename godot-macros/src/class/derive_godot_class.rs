@@ -9,8 +9,8 @@ use proc_macro2::{Ident, Punct, Span, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldVar, Fields,
-    SignatureInfo,
+    make_property_impl, make_signal_collection, make_virtual_callback, BeforeKind, Field,
+    FieldExport, FieldGroup, FieldVar, Fields, GetterSetter, SignatureInfo,
 };
 use crate::util::{bail, ident, path_ends_with_complex, require_api_version, KvParser};
 use crate::{util, ParseResult};
@@ -117,6 +117,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     };
 
     let is_tool = struct_cfg.is_tool;
+    let signal_collection = make_signal_collection(class_name, &class.vis_marker);
 
     Ok(quote! {
         impl ::godot::obj::GodotClass for #class_name {
@@ -141,6 +142,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             type Exportable = <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::Bounds>::Exportable;
         }
 
+        #signal_collection
         #script_impl
         #godot_init_impl
         #godot_withbase_impl
@@ -153,6 +155,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             class_name: #class_name_obj,
             item: #prv::PluginItem::Struct {
                 base_class_name: #base_class_name_obj,
+                rust_type_name: ::std::concat!(::std::module_path!(), "::", ::std::stringify!(#class_name)),
                 generated_create_fn: #create_fn,
                 generated_recreate_fn: #recreate_fn,
                 register_properties_fn: #prv::ErasedRegisterFn {
@@ -187,15 +190,6 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     })
 }
 
-/// Checks at compile time that a function with the given name exists on `Self`.
-#[must_use]
-pub fn make_existence_check(ident: &Ident) -> TokenStream {
-    quote! {
-        #[allow(path_statements)]
-        Self::#ident;
-    }
-}
-
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -648,6 +642,37 @@ fn make_script_impl(class_name: &Ident, class_name_str: String, base_ty: &Ident,
     });
 }
 
+/// Parses `rename = NewName` or `rename = "NewName"`; the string form must still be a valid identifier.
+fn handle_class_rename(parser: &mut KvParser) -> ParseResult<Option<Ident>> {
+    let Some((key, value)) = parser.handle_any_entry("rename") else {
+        return Ok(None);
+    };
+
+    let Some(value) = value else {
+        return bail!(key, "expected `rename` to be followed by `= identifier` or `= \"string\"`");
+    };
+
+    if let Ok(ident) = value.as_ident() {
+        return Ok(Some(ident));
+    }
+
+    let literal = value.as_literal()?;
+    let lit_str = literal.to_string();
+    let Some(name) = lit_str.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return bail!(literal, "expected identifier or string literal");
+    };
+
+    let mut chars = name.chars();
+    let is_valid_ident = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !is_valid_ident {
+        return bail!(literal, "class name `{name}` is not a valid identifier");
+    }
+
+    Ok(Some(Ident::new(name, literal.span())))
+}
+
 /// Returns the name of the base and the default mode
 fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
@@ -698,8 +723,8 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             }
         }
 
-        // #[class(rename = NewName)]
-        rename = parser.handle_ident("rename")?;
+        // #[class(rename = NewName)] or #[class(rename = "NewName")]
+        rename = handle_class_rename(&mut parser)?;
 
         // #[class(hidden)]
         // TODO consider naming this "internal"; godot-cpp uses that terminology:
@@ -791,6 +816,11 @@ fn parse_fields(
             field.is_onready = true;
         }
 
+        // PhantomVar<T> type inference
+        if path_ends_with_complex(&field.ty, "PhantomVar") {
+            field.is_phantom_var = true;
+        }
+
         // #[init]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
             // #[init] on fields is useless if there is no generated constructor.
@@ -864,6 +894,31 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export_group] and #[export_subgroup]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_group")? {
+            field.group = Some(FieldGroup::new_from_kv(&mut parser)?);
+            parser.finish()?;
+        }
+
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_subgroup")? {
+            field.subgroup = Some(FieldGroup::new_from_kv(&mut parser)?);
+            parser.finish()?;
+        }
+
+        if (field.group.is_some() || field.subgroup.is_some())
+            && field.var.is_none()
+            && field.export.is_none()
+        {
+            return bail!(
+                named_field,
+                "#[export_group] and #[export_subgroup] require the field to be a property, i.e. have #[export] or #[var]"
+            );
+        }
+
+        if field.is_phantom_var {
+            validate_phantom_var(&field, &named_field)?;
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -910,6 +965,27 @@ fn parse_fields(
     })
 }
 
+/// A `PhantomVar<T>` field has no storage, so it must be a property whose accessors are all user-defined.
+fn validate_phantom_var(field: &Field, named_field: &venial::NamedField) -> ParseResult<()> {
+    let Some(var) = &field.var else {
+        return bail!(
+            named_field,
+            "field of type `PhantomVar<T>` requires #[var(get = ..., set = ...)] with at least one custom accessor"
+        );
+    };
+
+    let is_generated = |accessor: &GetterSetter| matches!(accessor, GetterSetter::Generated);
+    if is_generated(&var.getter) || is_generated(&var.setter) {
+        return bail!(
+            named_field,
+            "field of type `PhantomVar<T>` has no storage, so it cannot use generated accessors\n\
+             Help: specify `get = function` and/or `set = function`, and omit the other key"
+        );
+    }
+
+    Ok(())
+}
+
 fn handle_opposite_keys(
     parser: &mut KvParser,
     key: &str,
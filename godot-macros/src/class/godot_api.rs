@@ -8,11 +8,11 @@
 use proc_macro2::TokenStream;
 
 use crate::class::{transform_inherent_impl, transform_trait_impl};
-use crate::util::bail;
+use crate::util::{bail, path_is_single, KvParser};
 use crate::ParseResult;
 
 pub fn attribute_godot_api(input_decl: venial::Item) -> ParseResult<TokenStream> {
-    let decl = match input_decl {
+    let mut decl = match input_decl {
         venial::Item::Impl(decl) => decl,
         _ => bail!(
             input_decl,
@@ -20,6 +20,14 @@ pub fn attribute_godot_api(input_decl: venial::Item) -> ParseResult<TokenStream>
         )?,
     };
 
+    // The attribute itself is re-attached by translate_meta(); parse and remove it, so it isn't expanded again.
+    let mut parser = KvParser::parse_required(&decl.attributes, "godot_api", &decl.self_ty)?;
+    let is_secondary = parser.handle_alone("secondary")?;
+    parser.finish()?;
+
+    decl.attributes
+        .retain(|attr| !path_is_single(&attr.path, "godot_api"));
+
     if decl.impl_generic_params.is_some() {
         bail!(
             &decl,
@@ -32,8 +40,15 @@ pub fn attribute_godot_api(input_decl: venial::Item) -> ParseResult<TokenStream>
     };
 
     if decl.trait_ty.is_some() {
+        if is_secondary {
+            return bail!(
+                &decl.self_ty,
+                "#[godot_api(secondary)] can only be applied on inherent impl blocks, not trait impls",
+            );
+        }
+
         transform_trait_impl(decl)
     } else {
-        transform_inherent_impl(decl)
+        transform_inherent_impl(decl, is_secondary)
     }
 }
@@ -45,29 +45,15 @@ pub fn make_inherent_impl_docs(
         signals: &[SignalDefinition],
         constants: &[ConstDefinition],
     ) -> TokenStream {
-        let to_tagged = |s: String, tag: &str| -> String {
-            if s.is_empty() {
-                s
-            } else {
-                format!("<{tag}>{s}</{tag}>")
-            }
-        };
-
-        let signals_block = to_tagged(
-            signals
-                .iter()
-                .filter_map(make_signal_docs)
-                .collect::<String>(),
-            "signals",
-        );
-        let constants_block = to_tagged(
-            constants
-                .iter()
-                .map(|ConstDefinition { raw_constant }| raw_constant)
-                .filter_map(make_constant_docs)
-                .collect::<String>(),
-            "constants",
-        );
+        let signals = signals
+            .iter()
+            .filter_map(make_signal_docs)
+            .collect::<String>();
+        let constants = constants
+            .iter()
+            .map(|ConstDefinition { raw_constant, .. }| raw_constant)
+            .filter_map(make_constant_docs)
+            .collect::<String>();
 
         let methods = functions
             .iter()
@@ -77,8 +63,8 @@ pub fn make_inherent_impl_docs(
         quote! {
             docs: ::godot::docs::InherentImplDocs {
                 methods: #methods,
-                signals_block: #signals_block,
-                constants_block: #constants_block,
+                signals: #signals,
+                constants: #constants,
             }
         }
     }
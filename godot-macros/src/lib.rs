@@ -79,6 +79,10 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// Fields annotated with `#[init(val = ...)]` don't need to implement `Default`, so there is no need to hand-write an `init`
+/// function just to provide a custom initial value. The expression is evaluated anew each time an instance is constructed.
+/// (The older spelling `#[init(default = ...)]` is deprecated in favor of `val`.)
+///
 /// The given value can be any Rust expression that can be evaluated in the scope where you write
 /// the attribute. However, due to limitations in the parser, some complex expressions must be
 /// surrounded by parentheses. This is the case if the expression includes a `,` that is _not_
@@ -196,6 +200,57 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// The accessors are validated against the field at compile time: a getter must take `&self` (or `&mut self`) and return exactly
+/// the field type, a setter must take `&mut self` (or `&self`) and one value of exactly the field type. Anything else, such as an
+/// extra parameter or an `i32` getter for an `i64` field, is a compile error.
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     #[var(get = get_my_field)]
+///     my_field: i64,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     pub fn get_my_field(&self) -> i32 { // error: property has type i64
+///         self.my_field as i32
+///     }
+/// }
+/// ```
+///
+/// Properties that are entirely computed, without a backing field, are declared with the zero-sized type
+/// [`PhantomVar<T>`](property/struct.PhantomVar.html). Such a field needs custom accessors, which are checked
+/// against `T`:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     celsius: f64,
+///
+///     #[var(get = get_fahrenheit, set = set_fahrenheit)]
+///     fahrenheit: PhantomVar<f64>,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     pub fn get_fahrenheit(&self) -> f64 {
+///         self.celsius * 1.8 + 32.0
+///     }
+///
+///     #[func]
+///     pub fn set_fahrenheit(&mut self, value: f64) {
+///         self.celsius = (value - 32.0) / 1.8;
+///     }
+/// }
+/// ```
+///
 /// If you specify only `get`, no setter is generated, making the field read-only. If you specify
 /// only `set`, no getter is generated, making the field write-only (rarely useful). To add a
 /// generated getter or setter in these cases anyway, use `get` or `set` without a value:
@@ -268,6 +323,18 @@ use crate::util::ident;
 ///     #[export(file = "*.gd")]
 ///     gdscript_file: GString,
 ///
+///     // @export_global_file("*.png", "*.jpg")
+///     #[export(global_file = "*.png,*.jpg")]
+///     image_file: GString,
+///
+///     // @export_dir
+///     #[export(dir)]
+///     folder: GString,
+///
+///     // @export_multiline
+///     #[export(multiline)]
+///     dialogue: GString,
+///
 ///     // @export_flags_3d_physics
 ///     #[export(flags_3d_physics)]
 ///     physics: u32,
@@ -287,6 +354,20 @@ use crate::util::ident;
 ///
 /// ```
 ///
+/// Some hints are only valid for certain field types, which is checked at compile time: `range` requires an integer, float or vector
+/// field (for vectors, the range applies to each component), `exp_easing` a float field, `enum` an integer or string field, and
+/// `file`/`global_file`/`dir`/`global_dir`/`multiline` a string field.
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(range = (0.0, 10.0))]
+///     name: GString, // error: not numeric
+/// }
+/// ```
+///
 /// Most values in expressions like `key = value`, can be an arbitrary expression that evaluates to the
 /// right value. Meaning you can use constants or variables, as well as any other rust syntax you'd like in
 /// the export attributes.
@@ -327,6 +408,36 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Export groups
+///
+/// Exported properties can be organized into groups and subgroups in the inspector. `#[export_group]` and `#[export_subgroup]` work like
+/// GDScript's `@export_group` and `@export_subgroup`: they are placed on the first field of the group, and apply to all subsequent
+/// properties until the next group.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // @export_group("Movement")
+///     #[export_group(name = "Movement")]
+///     #[export]
+///     speed: f32,
+///     #[export]
+///     jump_height: f32,
+///
+///     // @export_group("Combat", "combat_")
+///     // @export_subgroup("Defense")
+///     #[export_group(name = "Combat", prefix = "combat_")]
+///     #[export_subgroup(name = "Defense")]
+///     #[export]
+///     combat_armor: i32,
+/// }
+/// ```
+///
+/// With a `prefix`, only properties whose names start with it are part of the group, and the editor strips the prefix from their names.
+/// A group with an empty name ends the current group.
+///
 /// # Signals
 ///
 /// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
@@ -348,6 +459,50 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// For each signal, an `emit_*` method with the same parameters is generated. It can be called on classes that have a `Base<T>` field:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn damaged(amount: i32);
+///
+///     #[func]
+///     fn take_hit(&mut self) {
+///         self.emit_damaged(10);
+///     }
+/// }
+/// ```
+///
+/// Signals are also accessible through [`Gd::signals()`](../obj/struct.Gd.html#method.signals), next to the signals of the base class.
+/// The returned collection is named `SignalsOf<Class>` (here `SignalsOfPlayer`); it must be in scope where `#[godot_api(secondary)]`
+/// blocks declare further signals.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct Player {
+/// #     base: Base<Node>,
+/// # }
+/// # #[godot_api]
+/// # impl Player {
+/// #     #[signal]
+/// #     fn damaged(amount: i32);
+/// # }
+/// fn connect_player(player: Gd<Player>, on_damaged: Callable) {
+///     player.signals().damaged().connect(on_damaged).done();
+///     player.signals().damaged().emit((10,));
+/// }
+/// ```
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -398,6 +553,9 @@ use crate::util::ident;
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
 ///
+/// The name can also be provided as a string literal, e.g. `#[class(rename = "NpcToad")]`, but it must still be a valid identifier.
+/// Class names must be unique within the process: if two classes end up with the same Godot name, registering one of them fails with an error.
+///
 /// ## Class hiding
 ///
 /// If you want to register a class with Godot, but not have it show up in the editor then you can use `#[class(hidden)]`.
@@ -475,7 +633,20 @@ use crate::util::ident;
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init, signal))]
+#[proc_macro_derive(
+    GodotClass,
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_group,
+        export_subgroup,
+        init,
+        signal
+    )
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -519,6 +690,7 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///   - [Associated functions and methods](#associated-functions-and-methods)
 ///   - [Virtual methods](#virtual-methods)
 /// - [Constants and signals](#signals)
+/// - [Multiple impl blocks](#multiple-impl-blocks)
 ///
 /// # Constructors
 ///
@@ -594,6 +766,24 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// A `#[func]` in an inherent impl does **not** override engine virtuals, even if named like one. Since such a method would silently never
+/// be called by the engine, `#[godot_api]` reports a compile error if a `#[func]` has the Godot name of a virtual method of the base class.
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct MyNode;
+///
+/// #[godot_api]
+/// impl MyNode {
+///     #[func]
+///     fn _process(&mut self, delta: f64) {} // error: implement INode::process() instead
+/// }
+/// ```
+///
+/// If you deliberately want to register such a method (e.g. to be called by name from GDScript), opt out with `#[func(shadow_virtual)]`.
+///
 /// # User-defined functions
 ///
 /// You can use the `#[func]` attribute to declare your own functions. These are exposed to Godot and callable from GDScript.
@@ -639,6 +829,102 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Renaming
+///
+/// By default, functions are registered in Godot under their Rust name. With `#[func(rename = new_name)]`, you can expose them
+/// under a different name, for example to follow a naming convention or to avoid collisions with GDScript keywords.
+/// The Rust function itself keeps its original name.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     // Called as `obj.is_enabled()` from GDScript.
+///     #[func(rename = is_enabled)]
+///     fn enabled(&self) -> bool {
+///         true
+///     }
+/// }
+/// ```
+///
+/// Two functions in the same `impl` block cannot be registered under the same Godot name; this causes a compile error.
+///
+/// ## Fallible functions
+///
+/// A `#[func]` can return `Result<T, E>`, where `T` is a type convertible to Godot and `E` implements `Display`. Godot only sees `T`
+/// as the return type. If the function returns `Err`, the call fails: the error is printed to the Godot console, the caller receives
+/// `null`, and Rust callers using `try_call()` get a `CallError` describing the error. No panic is involved.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn parse_level(&self, text: GString) -> Result<i64, String> {
+///         text.to_string()
+///             .parse()
+///             .map_err(|e| format!("invalid level '{text}': {e}"))
+///     }
+/// }
+/// ```
+///
+/// Detection is purely syntactic: the return type must be spelled as `Result<...>` (possibly with a path, such as `io::Result<T>`);
+/// type aliases with other names are not recognized.
+///
+/// ## Nullable objects
+///
+/// Parameters and return values of type `Gd<T>` are non-nullable: calling such a function with `null` fails the call. To accept or
+/// return `null`, use `Option<Gd<T>>` instead, which maps `null` to `None`. This is how GDScript passes optional node references.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     // Can be called as `obj.set_target(null)` from GDScript.
+///     #[func]
+///     fn set_target(&mut self, target: Option<Gd<Node>>) {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// Other types can be wrapped in `Option` as well, for example `Option<i64>` or `Option<GString>`. Since Godot has no nullable
+/// integers or strings, such parameters and return values are passed as untyped `Variant`: `null` maps to `None` and vice versa.
+/// Like `Result`, this is detected syntactically, so the type must be spelled `Option<...>`. `Result<Option<T>, E>` is not supported.
+///
+/// ## Multiple return values
+///
+/// Tuples with up to 6 elements can be used as return types and parameters. Godot has no tuples, so they are passed as untyped
+/// `Array`, with one element per tuple field. GDScript callers destructure the result by index. Conversely, an array passed to a
+/// tuple parameter must have exactly the right length and element types, otherwise the call fails.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     // In GDScript:
+///     //   var hit = obj.raycast()
+///     //   var distance: int = hit[0]
+///     //   var point: Vector3 = hit[1]
+///     #[func]
+///     fn raycast(&self) -> (i32, Vector3) {
+///         (12, Vector3::UP)
+///     }
+/// }
+/// ```
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -674,14 +960,86 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Now, `obj.language()` from Rust will dynamically dispatch the call.
 ///
+/// The method is registered in the class DB under two names: `language` and `_language` (or only once under its `rename`, which is then
+/// also the name to override in scripts). Calls are dispatched as follows:
+/// 1. Rust calls `obj.language()` check whether the attached script overrides `_language`. If so, the script method is invoked;
+///    otherwise, the Rust implementation runs.
+/// 2. Engine and GDScript calls to `language` behave the same way as Rust calls.
+/// 3. Engine and GDScript calls to `_language` reach the script first, as with any script method. Without an overriding script,
+///    the Rust implementation runs.
+/// 4. Calling `super()` inside a script override always runs the Rust implementation, without dispatching back to the script.
+///
+/// ```gdscript
+/// func _language():
+///    return "GDScript on top of " + super()
+/// ```
+///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
+///
+/// Integer constants can be grouped into a Godot enum or bitfield with `#[constant(enum = Name)]` or `#[constant(bitfield = Name)]`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyClass {}
+/// #[godot_api]
+/// impl MyClass {
+///     #[constant]
+///     const MAX_HEALTH: i32 = 100;
+///
+///     #[constant(enum = Direction)]
+///     const LEFT: i64 = 0;
+///     #[constant(enum = Direction)]
+///     const RIGHT: i64 = 1;
+/// }
+/// ```
+///
+/// Compilation fails if a constant has the same name as an integer constant or enumerator of the engine base class (e.g. `NOTIFICATION_READY`
+/// for a `Node`-based class), or if the same constant is registered twice.
+///
+/// # Multiple impl blocks
+///
+/// Large classes can split their API across several `impl` blocks, possibly in different modules. Exactly one block is the primary
+/// `#[godot_api]`; any number of additional blocks are marked with `#[godot_api(secondary)]`. They can contain `#[func]`, `#[signal]`
+/// and `#[constant]` items, which are registered after those of the primary block.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     fn health(&self) -> i64 {
+///         self.health
+///     }
+/// }
+///
+/// // Can be in another file.
+/// #[godot_api(secondary)]
+/// impl Player {
+///     #[func]
+///     fn heal(&mut self, amount: i64) {
+///         self.health += amount;
+///     }
+/// }
+/// ```
+///
+/// Registering the same Godot method name in two blocks is a compile error ("duplicate definitions with name `__godot_method_...`").
+/// Trait impls (`impl INode for ...`) cannot be secondary. When generating docs, the methods, signals and constants of secondary blocks
+/// are included, following those of the primary block.
 #[proc_macro_attribute]
-pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
-    translate(input, class::attribute_godot_api)
+pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate_meta("godot_api", meta, input, class::attribute_godot_api)
 }
 
 /// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs.
@@ -819,6 +1177,32 @@ pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
 ///
 /// This expects a derived [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) implementation, using a manual
 /// implementation of `GodotConvert` may lead to incorrect values being displayed in Godot.
+///
+/// Enums are registered with `PropertyHint::ENUM`, so the editor shows a dropdown of the variant names:
+/// - With `#[godot(via = i64)]` (or another integer), the property stores the discriminant and the hint string is `Easy:0,Normal:1,Hard:2`.
+/// - With `#[godot(via = GString)]`, the property stores the variant name and the hint string is `Easy,Normal,Hard`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert, Var, Export)]
+/// #[godot(via = GString)]
+/// enum Difficulty {
+///     Easy,
+///     Normal,
+///     Hard,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Game {
+///     #[export]
+///     difficulty: Difficulty,
+/// }
+/// # impl Default for Difficulty {
+/// #     fn default() -> Self { Self::Normal }
+/// # }
+/// ```
 #[proc_macro_derive(Var, attributes(godot))]
 pub fn derive_var(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_var)
@@ -120,6 +120,7 @@
 //!
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
+//!   Math types are serialized as structs with named fields. For bit-identical floats in JSON, enable `serde_json`'s `float_roundtrip` feature.
 //!
 
 #![doc(
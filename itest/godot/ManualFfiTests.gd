@@ -307,6 +307,22 @@ func test_func_rename():
 	assert_eq(func_rename.has_method("spell_static"), true)
 	assert_eq(func_rename.spell_static(), "static")
 
+func test_func_typed_array_return():
+	var obj := FuncObj.new()
+
+	# Assigning to typed variables fails at runtime if the returned arrays are untyped.
+	var nodes: Array[Node] = obj.make_nodes(2)
+	assert_eq(nodes.size(), 2)
+	assert_eq(nodes.get_typed_builtin(), TYPE_OBJECT)
+	assert_eq(nodes.get_typed_class_name(), &"Node")
+	assert_eq(nodes[1].name, &"node_1")
+	for node in nodes:
+		node.free()
+
+	var numbers: Array[int] = obj.make_numbers()
+	assert_eq(numbers.get_typed_builtin(), TYPE_INT)
+	assert_eq(numbers, [1, 2, 3])
+
 var gd_self_obj: GdSelfObj
 func update_self_reference(value):
 	gd_self_obj.update_internal(value)
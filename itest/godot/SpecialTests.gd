@@ -52,3 +52,29 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Test that `Object::set_deferred_as()` assigns the property once deferred calls are flushed.
+#
+# Deferred calls only run after the current frame has been processed, so the standard testing API cannot observe their effect.
+func test_set_deferred_as():
+	var node := Node3D.new()
+	DeferredPropertyTest.set_position_deferred(node, Vector3(4, 5, 6))
+	assert_eq(node.position, Vector3.ZERO, "Assignment should be deferred")
+
+	await await_deferred_flush()
+	assert_eq(node.position, Vector3(4, 5, 6), "Assignment should have run")
+
+	node.free()
+
+# Test that `Object::emit_signal_deferred()` emits the signal once deferred calls are flushed.
+func test_emit_signal_deferred():
+	var emitter := Emitter.new()
+	var received: Array = []
+	emitter.signal_1_arg.connect(func(arg1: int): received.push_back(arg1))
+
+	DeferredSignalTest.emit_signal_1_arg_deferred(emitter, 987)
+	assert_eq(received, [], "Emission should be deferred")
+
+	await await_deferred_flush()
+	assert_eq(received, [987], "Signal should have been emitted")
+
+	emitter.free()
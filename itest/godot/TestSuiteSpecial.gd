@@ -8,6 +8,8 @@ extends TestSuite
 
 var errors: Array[String] = []
 
+signal _deferred_flushed
+
 func print_newline():
 	errors.push_back("")
 
@@ -33,3 +35,10 @@ func clear_errors() -> Array[String]:
 	var old_errors := errors
 	errors = []
 	return old_errors
+
+# Waits until all calls deferred so far (e.g. through call_deferred() or set_deferred()) have been executed.
+#
+# Godot runs deferred calls in the order they were queued, so once this call runs, all previous ones have run, too.
+func await_deferred_flush():
+	call_deferred("emit_signal", "_deferred_flushed")
+	await _deferred_flushed
@@ -468,6 +468,8 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
             export_global_file: GString,
             #[export(global_file = "*.png")]
             export_global_file_wildcard_png: GString,
+            #[export(file = "*.png,*.jpg")]
+            export_file_wildcard_png_jpg: GString,
             #[export(dir)]
             export_dir: GString,
             #[export(global_dir)]
@@ -518,6 +520,7 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
 @export_file("*.txt") var export_file_wildcard_txt: String
 @export_global_file var export_global_file: String
 @export_global_file("*.png") var export_global_file_wildcard_png: String
+@export_file("*.png", "*.jpg") var export_file_wildcard_png_jpg: String
 @export_dir var export_dir: String
 @export_global_dir var export_global_dir: String
 @export_multiline var export_multiline: String
@@ -10,7 +10,10 @@
 use std::hint::black_box;
 
 use godot::builtin::inner::InnerRect2i;
-use godot::builtin::{GString, Rect2i, StringName, Vector2i};
+use godot::builtin::{
+    real, Array, GString, PackedInt64Array, PackedStringArray, PackedVector3Array, Rect2i,
+    StringName, Transform3D, Vector2i, Vector3,
+};
 use godot::classes::{Node3D, Os, RefCounted};
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
 use godot::register::GodotClass;
@@ -29,6 +32,17 @@ fn builtin_stringname_ctor() -> StringName {
     StringName::from("some test string")
 }
 
+#[bench]
+#[cfg(since_api = "4.2")]
+fn builtin_stringname_from_latin1() -> StringName {
+    StringName::from_latin1(b"some test string")
+}
+
+#[bench]
+fn builtin_stringname_intern() -> StringName {
+    StringName::intern("some test string")
+}
+
 #[bench]
 fn builtin_rust_call() -> bool {
     let point = black_box(Vector2i::new(50, 60));
@@ -72,6 +86,44 @@ fn class_singleton_access() -> Gd<Os> {
     Os::singleton()
 }
 
+#[bench]
+fn array_push_loop() -> Array<i32> {
+    let mut array = Array::new();
+    for i in 0..1000 {
+        array.push(i);
+    }
+    array
+}
+
+#[bench]
+fn array_extend_from_slice() -> Array<i32> {
+    let values: Vec<i32> = (0..1000).collect();
+    let mut array = Array::new();
+    array.extend_from_slice(&values);
+    array
+}
+
+#[bench]
+fn packed_string_array_push_loop() -> PackedStringArray {
+    let strings = string_vec();
+    let mut array = PackedStringArray::new();
+    for s in strings.iter() {
+        array.push(GString::from(s));
+    }
+    array
+}
+
+#[bench]
+fn packed_string_array_from_vec_string() -> PackedStringArray {
+    PackedStringArray::from(string_vec())
+}
+
+#[bench]
+fn packed_string_array_to_vec_string() -> Vec<String> {
+    let array = PackedStringArray::from(string_vec());
+    Vec::from(&array)
+}
+
 #[bench]
 fn utilities_allocate_rid() -> i64 {
     godot::global::rid_allocate_id()
@@ -93,9 +145,77 @@ fn utilities_ffi_call() -> f64 {
     godot::global::pow(base, exponent)
 }
 
+#[bench(repeat = 25)]
+fn packed_vector3_transform_all() -> PackedVector3Array {
+    let (mut array, xform) = make_transform_input();
+    array.transform_all(&xform);
+    array
+}
+
+#[bench(repeat = 25)]
+fn packed_vector3_transform_indexed() -> PackedVector3Array {
+    let (mut array, xform) = make_transform_input();
+
+    // Deliberately indexed, to measure the per-element FFI cost.
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..array.len() {
+        array[i] = xform * array[i];
+    }
+    array
+}
+
+#[bench(repeat = 25)]
+fn packed_int64_bitset_count_ones() -> usize {
+    let array = make_bitset_input();
+    array.count_ones()
+}
+
+#[bench(repeat = 25)]
+fn packed_int64_bitset_count_indexed() -> usize {
+    let array = make_bitset_input();
+
+    // Deliberately indexed and shifted by hand, to compare against count_ones().
+    let mut count = 0;
+    for i in 0..array.len() * 64 {
+        if (array[i / 64] >> (i % 64)) & 1 != 0 {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[bench(repeat = 25)]
+fn packed_int64_bitset_set_bit() -> PackedInt64Array {
+    let mut array = make_bitset_input();
+    for i in (0..array.bit_len()).step_by(3) {
+        array.set_bit(i, true);
+    }
+    array
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers for benchmarks above
 
+fn string_vec() -> Vec<String> {
+    (0..1000).map(|i| format!("res://file_{i}.png")).collect()
+}
+
+fn make_bitset_input() -> PackedInt64Array {
+    let elements = (0..1000)
+        .map(|i| black_box(i * 0x9E37_79B9))
+        .collect::<Vec<i64>>();
+    PackedInt64Array::from(elements.as_slice())
+}
+
+fn make_transform_input() -> (PackedVector3Array, Transform3D) {
+    let points = (0..1000)
+        .map(|i| Vector3::new(i as real, 1.0, -(i as real)))
+        .collect::<Vec<_>>();
+
+    let xform = Transform3D::IDENTITY.translated(black_box(Vector3::new(1.0, 2.0, 3.0)));
+    (PackedVector3Array::from(points.as_slice()), xform)
+}
+
 #[derive(GodotClass)]
 #[class(init)]
 struct MyBenchType {}
@@ -6,6 +6,7 @@
  */
 
 use crate::framework::itest;
+use godot::builtin::inner::InnerColor;
 use godot::builtin::math::assert_eq_approx;
 use godot::builtin::{Color, ColorChannelOrder, ColorHsv};
 
@@ -59,6 +60,27 @@ fn color_from_html() {
     );
     assert_eq!(Color::from_string("#abcde"), None);
     assert_eq!(Color::from_string("#abcg"), None);
+
+    // Rust-side parsing must agree with Godot's.
+    for html in ["#abcd", "abc", "#ff8800", "FF8800cc", "#abcde", "#abcg"] {
+        let godot_color =
+            InnerColor::html_is_valid(html.into()).then(|| InnerColor::html(html.into()));
+        assert_eq!(Color::from_html(html).ok(), godot_color, "{html}");
+    }
+    assert!(Color::from_html("#abcde").is_err());
+}
+
+#[itest]
+fn color_to_html_roundtrip() {
+    let color = Color::from_rgba8(0xff, 0x88, 0x00, 0xcc);
+    assert_eq!(color.to_html(), "ff8800cc".into());
+    assert_eq!(color.to_html_without_alpha(), "ff8800".into());
+
+    assert_eq!(Color::from_html(color.to_html().to_string()), Ok(color));
+    assert_eq!(
+        Color::from_html(color.to_html_without_alpha().to_string()),
+        Ok(Color { a: 1.0, ..color })
+    );
 }
 
 #[itest]
@@ -66,6 +66,34 @@ fn array_from_iterator() {
     assert_eq!(array.at(1), 2);
 }
 
+#[itest]
+fn array_collect() {
+    let array: Array<i32> = (0..10).collect();
+    assert_eq!(array.len(), 10);
+    assert_eq!(array.at(0), 0);
+    assert_eq!(array.at(9), 9);
+
+    // Size hint is only a lower bound.
+    let array: Array<i32> = (0..10).filter(|i| i % 2 == 0).collect();
+    assert_eq!(array, array![0, 2, 4, 6, 8]);
+
+    let mut array = array![1, 2];
+    array.extend([3, 4].into_iter().chain(5..7));
+    assert_eq!(array, array![1, 2, 3, 4, 5, 6]);
+}
+
+#[itest]
+fn array_collect_objects() {
+    let objects: Vec<Gd<Object>> = (0..3).map(|_| Object::new_alloc()).collect();
+    let array: Array<Gd<Object>> = objects.iter().cloned().collect();
+
+    assert_eq!(array.len(), 3);
+    for (i, obj) in objects.into_iter().enumerate() {
+        assert_eq!(array.at(i), obj);
+        obj.free();
+    }
+}
+
 #[itest]
 fn array_from_slice() {
     let array = Array::from(&[1, 2]);
@@ -133,6 +161,36 @@ fn array_duplicate_deep() {
     assert_eq!(subarray.at(0), 2);
 }
 
+#[itest]
+fn array_duplicate_nested_dictionary() {
+    let inner = array![1, 2];
+    let dict = dict! { "inner": inner.clone() };
+    let array = varray![dict.clone()];
+
+    let nested = |array: &VariantArray| -> Array<i64> {
+        array
+            .at(0)
+            .to::<Dictionary>()
+            .at("inner")
+            .to::<Array<i64>>()
+    };
+
+    // Shallow: the outer array is new, but the dictionary (and the array inside it) are shared.
+    let mut shallow = array.duplicate_shallow();
+    nested(&shallow).push(3);
+    shallow.push(Variant::nil());
+    assert_eq!(inner, array![1, 2, 3]);
+    assert_eq!(array.len(), 1);
+
+    // Deep: dictionary and nested array are copied too.
+    let deep = array.duplicate_deep();
+    nested(&deep).push(4);
+    deep.at(0).to::<Dictionary>().set("key", "value");
+    assert_eq!(inner, array![1, 2, 3]);
+    assert_eq!(nested(&deep), array![1, 2, 3, 4]);
+    assert!(!dict.contains_key("key"));
+}
+
 #[itest]
 fn array_subarray_shallow() {
     let array = array![0, 1, 2, 3, 4, 5];
@@ -148,6 +206,78 @@ fn array_subarray_shallow() {
     assert_eq!(subarray.at(0), 4);
 }
 
+#[itest]
+fn array_slice() {
+    let array = array![0, 1, 2, 3, 4, 5];
+
+    // Negative indices count from the back.
+    assert_eq!(array.slice(-3, 6, 1), array![3, 4, 5]);
+    assert_eq!(array.slice(0, -1, 1), array![0, 1, 2, 3, 4]);
+    assert_eq!(array.slice(-4, -2, 1), array![2, 3]);
+
+    // Steps greater than 1.
+    assert_eq!(array.slice(0, 6, 2), array![0, 2, 4]);
+    assert_eq!(array.slice(1, 6, 3), array![1, 4]);
+
+    // Negative steps; -7 (below -len) is needed to include the first element.
+    assert_eq!(array.slice(-1, -7, -1), array![5, 4, 3, 2, 1, 0]);
+    assert_eq!(array.slice(5, 0, -2), array![5, 3, 1]);
+
+    // Bounds are clamped.
+    assert_eq!(array.slice(-100, 3, 1), array![0, 1, 2]);
+    assert_eq!(array.slice(4, isize::MAX, 1), array![4, 5]);
+    assert_eq!(array.slice(6, 10, 1), array![]);
+    assert_eq!(Array::<i64>::new().slice(0, 5, 1), array![]);
+
+    // Shallow copy.
+    let inner = array![2, 3];
+    let outer = varray![1, inner];
+    let slice = outer.slice(-1, 2, 1);
+    Array::<i64>::try_from_variant(&slice.at(0))
+        .unwrap()
+        .set(0, 4);
+    assert_eq!(inner.at(0), 4);
+}
+
+#[itest]
+fn array_slice_zero_step() {
+    let array = array![0, 1, 2];
+    expect_panic("slice with step 0", || {
+        array.slice(0, 3, 0);
+    });
+}
+
+#[itest]
+fn array_to_tuple() {
+    let node = Node::new_alloc();
+    let array = varray![7, "seven", node];
+
+    let (number, name, obj) = array.to_tuple::<(i32, GString, Gd<Node>)>().unwrap();
+    assert_eq!(number, 7);
+    assert_eq!(name, GString::from("seven"));
+    assert_eq!(obj, node);
+
+    let () = VariantArray::new().to_tuple::<()>().unwrap();
+
+    let err = array.to_tuple::<(i32, GString)>().unwrap_err();
+    assert_eq!(err.to_string(), "expected 2 element(s) for tuple, got 3");
+
+    let err = array.to_tuple::<(i32, i32, Gd<Node>)>().unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("tuple element #1 (i32) conversion failed"),
+        "unexpected error: {err}"
+    );
+
+    let array = varray![1, 2, 3, 4, 5, 6, 7, 8];
+    let tuple = array
+        .to_tuple::<(i64, i64, i64, i64, i64, i64, i64, i64)>()
+        .unwrap();
+    assert_eq!(tuple, (1, 2, 3, 4, 5, 6, 7, 8));
+
+    node.free();
+}
+
 #[itest]
 fn array_subarray_deep() {
     let array = array![0, 1, 2, 3, 4, 5];
@@ -207,6 +337,40 @@ fn array_binary_search() {
     assert_eq!(array.bsearch(&4), 2);
 }
 
+#[itest]
+fn array_binary_search_rust() {
+    let array = array![1, 3, 5, 7];
+
+    assert_eq!(array.binary_search(&0), Err(0));
+    assert_eq!(array.binary_search(&1), Ok(0));
+    assert_eq!(array.binary_search(&4), Err(2));
+    assert_eq!(array.binary_search(&7), Ok(3));
+    assert_eq!(array.binary_search(&8), Err(4));
+
+    let empty = Array::<i64>::new();
+    assert_eq!(empty.binary_search(&1), Err(0));
+
+    let floats = array![0.5, 1.5, 2.5];
+    assert_eq!(floats.binary_search(&1.5), Ok(1));
+}
+
+#[itest]
+fn array_binary_search_by() {
+    // Sorted descending; search with a reversed comparator.
+    let array = array![7, 5, 3, 1];
+
+    assert_eq!(array.binary_search_by(|e| 5.cmp(e)), Ok(1));
+    assert_eq!(array.binary_search_by(|e| 4.cmp(e)), Err(2));
+
+    let strings: Array<GString> = ["apple", "Banana", "cherry"]
+        .into_iter()
+        .map(GString::from)
+        .collect();
+    let found =
+        strings.binary_search_by(|e| e.to_string().to_lowercase().cmp(&"banana".to_string()));
+    assert_eq!(found, Ok(1));
+}
+
 #[itest]
 fn array_find() {
     let array = array![1, 2, 1];
@@ -304,6 +468,48 @@ fn array_sort() {
     assert_eq!(array, array![1, 2]);
 }
 
+#[itest]
+fn array_sort_unstable_by() {
+    let mut array = array![3, -5, 1, -2, 4];
+    array.sort_unstable_by(|a, b| a.abs().cmp(&b.abs()));
+    assert_eq!(array, array![1, -2, 3, 4, -5]);
+
+    array.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(array, array![4, 3, 1, -2, -5]);
+
+    let mut empty = Array::<i64>::new();
+    empty.sort_unstable_by(|a, b| a.cmp(b));
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn array_retain() {
+    let mut array = array![1, 2, 3, 4, 5, 6];
+    array.retain(|x| x % 2 == 0);
+    assert_eq!(array, array![2, 4, 6]);
+
+    array.retain(|_| true);
+    assert_eq!(array, array![2, 4, 6]);
+
+    array.retain(|_| false);
+    assert!(array.is_empty());
+}
+
+#[itest]
+fn array_retain_panic() {
+    let mut array = array![1, 2, 3, 4, 5];
+
+    expect_panic("retain() predicate panics", || {
+        array.retain(|&x| {
+            assert_ne!(x, 4, "predicate panics");
+            x != 2
+        });
+    });
+
+    // 2 was removed; 4 (being examined) and 5 (not yet examined) are kept.
+    assert_eq!(array, array![1, 3, 4, 5]);
+}
+
 #[itest]
 fn array_reverse() {
     let mut array = array![1, 2];
@@ -511,6 +717,62 @@ fn array_shrink() {
     assert_eq!(a, array![1, 5, 4]);
 }
 
+#[itest]
+fn array_drain() {
+    let mut a = array![1, 2, 3, 4, 5, 6];
+
+    let drained: Vec<i64> = a.drain(1..3).collect();
+    assert_eq!(drained, vec![2, 3]);
+    assert_eq!(a, array![1, 4, 5, 6]);
+
+    let drained: Vec<i64> = a.drain(2..).collect();
+    assert_eq!(drained, vec![5, 6]);
+    assert_eq!(a, array![1, 4]);
+
+    // Elements are removed even if the iterator is not consumed.
+    let _ = a.drain(..=0);
+    assert_eq!(a, array![4]);
+
+    assert_eq!(a.drain(1..1).count(), 0);
+    assert_eq!(a, array![4]);
+
+    let drained: Vec<i64> = a.drain(..).collect();
+    assert_eq!(drained, vec![4]);
+    assert!(a.is_empty());
+}
+
+#[itest]
+fn array_drain_out_of_bounds() {
+    let mut a = array![1, 2, 3];
+
+    expect_panic("drain range end out of bounds", || {
+        let _ = a.clone().drain(1..4);
+    });
+    expect_panic("drain range start greater than end", || {
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = a.clone().drain(2..1);
+    });
+
+    assert_eq!(a.drain(..).count(), 3);
+}
+
+#[itest]
+fn array_extend_from_slice() {
+    let mut a = array![1, 2];
+    a.extend_from_slice(&[3, 4, 5]);
+    assert_eq!(a, array![1, 2, 3, 4, 5]);
+
+    a.extend_from_slice(&[]);
+    assert_eq!(a, array![1, 2, 3, 4, 5]);
+
+    let mut vectors = Array::<Vector2i>::new();
+    let values: Vec<Vector2i> = (0..100).map(|i| Vector2i::new(i, -i)).collect();
+    vectors.extend_from_slice(&values);
+
+    assert_eq!(vectors.len(), 100);
+    assert_eq!(vectors.iter_shared().collect::<Vec<_>>(), values);
+}
+
 #[itest]
 fn array_resize() {
     let mut a = array![
@@ -143,6 +143,18 @@ fn callable_bindv() {
     );
 }
 
+#[itest]
+fn callable_bind() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("bar");
+    let callable_bound = callable.bind(&[10.to_variant()]);
+
+    assert_eq!(
+        callable_bound.callv(varray![]),
+        10.to_variant().stringify().to_variant()
+    );
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/410
 
 #[derive(GodotClass)]
@@ -161,7 +173,7 @@ impl CallableRefcountTest {
 #[cfg(since_api = "4.2")]
 mod custom_callable {
     use super::*;
-    use crate::framework::assert_eq_self;
+    use crate::framework::{assert_eq_self, suppress_godot_print};
     use godot::builtin::Dictionary;
     use std::fmt;
     use std::hash::Hash;
@@ -197,6 +209,51 @@ mod custom_callable {
         assert_ne!(a, c, "same function, different instance -> not equal");
     }
 
+    #[itest]
+    fn callable_from_fn_arg_mismatch() {
+        let callable = Callable::from_fn("takes_one", |args: &[&Variant]| match args {
+            [arg] => Ok(arg.to::<i32>().to_variant()),
+            _ => Err(()),
+        });
+
+        assert_eq!(callable.callv(varray![7]), 7.to_variant());
+
+        // Errors and panics are reported to Godot as failed calls, returning nil.
+        suppress_godot_print(|| {
+            assert_eq!(callable.callv(varray![1, 2]), Variant::nil());
+            assert_eq!(callable.callv(varray!["not an int"]), Variant::nil());
+        });
+    }
+
+    #[itest]
+    fn callable_from_fn_drop() {
+        let captured = Arc::new(());
+        let callable = {
+            let captured = captured.clone();
+            Callable::from_fn("capture", move |_args: &[&Variant]| {
+                Ok((Arc::strong_count(&captured) as i64).to_variant())
+            })
+        };
+
+        let copy = callable.clone();
+        assert_eq!(Arc::strong_count(&captured), 2);
+        assert_eq!(callable.callv(varray![]), 2.to_variant());
+
+        drop(callable);
+        assert_eq!(
+            Arc::strong_count(&captured),
+            2,
+            "closure alive while referenced"
+        );
+
+        drop(copy);
+        assert_eq!(
+            Arc::strong_count(&captured),
+            1,
+            "closure dropped with last reference"
+        );
+    }
+
     fn sum(args: &[&Variant]) -> Result<Variant, ()> {
         let sum: i32 = args.iter().map(|arg| arg.to::<i32>()).sum();
         Ok(sum.to_variant())
@@ -301,6 +301,60 @@ fn dictionary_extend() {
     assert_eq!(dictionary.get("bar"), Some("new".to_variant()));
 }
 
+#[itest]
+fn dictionary_merge() {
+    let mut dictionary = dict! {
+        "foo": 0,
+        "bar": true,
+    };
+    let other = dict! {
+        "bar": "new",
+        "baz": 3,
+    };
+
+    // Keep existing keys.
+    dictionary.merge(&other, false);
+    assert_eq!(dictionary, dict! { "foo": 0, "bar": true, "baz": 3 });
+
+    // Overwrite existing keys.
+    dictionary.merge(&other, true);
+    assert_eq!(dictionary, dict! { "foo": 0, "bar": "new", "baz": 3 });
+
+    // `other` is left untouched.
+    assert_eq!(other, dict! { "bar": "new", "baz": 3 });
+}
+
+#[itest]
+fn dictionary_merge_variant_keys() {
+    // Like in Godot, int and float keys are distinct, even if numerically equal.
+    let mut dictionary = dict! { 1: "int" };
+    dictionary.merge(&dict! { 1.0: "float", 1: "other int" }, false);
+
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get(1), Some("int".to_variant()));
+    assert_eq!(dictionary.get(1.0), Some("float".to_variant()));
+}
+
+#[itest]
+fn dictionary_merged() {
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": true,
+    };
+    let other = dict! {
+        "bar": "new",
+    };
+
+    let kept = dictionary.merged(&other, false);
+    assert_eq!(kept, dict! { "foo": 0, "bar": true });
+
+    let overwritten = dictionary.merged(&other, true);
+    assert_eq!(overwritten, dict! { "foo": 0, "bar": "new" });
+
+    // Original dictionary is not modified.
+    assert_eq!(dictionary, dict! { "foo": 0, "bar": true });
+}
+
 #[itest]
 fn dictionary_remove() {
     let mut dictionary = dict! {
@@ -399,6 +453,26 @@ fn dictionary_iter() {
     assert_eq!(map, map2);
 }
 
+#[itest]
+fn dictionary_values_iter() {
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": 2,
+        "baz": 3,
+    };
+
+    // Values come in the same order as keys.
+    let keys: Vec<Variant> = dictionary.keys_shared().collect();
+    let values: Vec<Variant> = dictionary.values_shared().collect();
+    let pairs: Vec<(Variant, Variant)> = dictionary.iter_shared().collect();
+    assert_eq!(keys.into_iter().zip(values).collect::<Vec<_>>(), pairs);
+
+    let typed: Vec<i64> = dictionary.values_shared().typed().collect();
+    assert_eq!(typed, vec![1, 2, 3]);
+    assert_eq!(dictionary.values_shared().size_hint(), (3, Some(3)));
+    assert_eq!(dictionary.values_shared().array(), varray![1, 2, 3]);
+}
+
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
@@ -685,3 +759,50 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn dictionary_typed_view() {
+    let dictionary = dict! {
+        "width": 1280,
+        "height": 720,
+        "title": "Game",
+    };
+
+    let mut typed = dictionary.typed::<String, i64>();
+    assert_eq!(typed.get("width".into()).unwrap(), Some(1280));
+    assert_eq!(typed.get("missing".into()).unwrap(), None);
+    assert!(typed.get("title".into()).is_err());
+    assert!(typed.contains_key("height".into()));
+
+    assert_eq!(typed.insert("width".into(), 1920).unwrap(), Some(1280));
+    assert_eq!(typed.insert("depth".into(), 32).unwrap(), None);
+    assert!(
+        typed.insert("title".into(), 0).is_err(),
+        "old value has wrong type"
+    );
+    assert_eq!(typed.remove("depth".into()).unwrap(), Some(32));
+    assert_eq!(typed.len(), 3);
+
+    // Changes are visible in the untyped dictionary.
+    assert_eq!(dictionary.at("width"), 1920.to_variant());
+    assert_eq!(dictionary.at("title"), 0.to_variant());
+    assert_eq!(typed.untyped(), &dictionary);
+}
+
+#[itest]
+fn dictionary_typed_view_iter() {
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": 2,
+        3: 3,
+        "baz": "not a number",
+    };
+
+    let typed = dictionary.typed::<String, i64>();
+    let (ok, err): (Vec<_>, Vec<_>) = typed.iter().partition(Result::is_ok);
+    assert_eq!(err.len(), 2);
+
+    let map: HashMap<String, i64> = ok.into_iter().map(Result::unwrap).collect();
+    let expected = HashMap::from([("foo".to_string(), 1), ("bar".to_string(), 2)]);
+    assert_eq!(map, expected);
+}
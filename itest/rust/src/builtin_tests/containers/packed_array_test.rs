@@ -7,9 +7,11 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::{
-    Color, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
-    PackedStringArray,
+    Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
+    PackedInt64Array, PackedStringArray, PackedVector3Array, Transform3D, Vector3,
 };
+use godot::classes::file_access::CompressionMode;
+use godot::global::Error;
 
 #[itest]
 fn packed_array_default() {
@@ -32,13 +34,35 @@ fn packed_array_from_iterator() {
 
 #[itest]
 fn packed_array_from_vec_str() {
-    let string_array = PackedStringArray::from(vec!["hello".into(), "world".into()]);
+    // The element type needs to be spelled out, since there is also `From<Vec<String>>`.
+    let string_array = PackedStringArray::from(vec![GString::from("hello"), "world".into()]);
 
     assert_eq!(string_array.len(), 2);
     assert_eq!(string_array[0], "hello".into());
     assert_eq!(string_array[1], "world".into());
 }
 
+#[itest]
+fn packed_string_array_from_vec_string() {
+    let strings = vec![
+        String::from("hello"),
+        String::new(),
+        String::from("Grüße, 世界 🦀"),
+    ];
+    let string_array = PackedStringArray::from(strings.clone());
+
+    assert_eq!(string_array.len(), 3);
+    assert_eq!(string_array[0], "hello".into());
+    assert_eq!(string_array[1], GString::new());
+    assert_eq!(string_array[2], "Grüße, 世界 🦀".into());
+
+    assert_eq!(Vec::<String>::from(&string_array), strings);
+
+    let empty = PackedStringArray::from(Vec::<String>::new());
+    assert!(empty.is_empty());
+    assert!(Vec::<String>::from(&empty).is_empty());
+}
+
 #[itest]
 fn packed_array_from_vec_i32() {
     let int32_array = PackedInt32Array::from(vec![1, 2]);
@@ -190,6 +214,116 @@ fn packed_array_as_mut_slice() {
     assert_eq!(empty.as_mut_slice(), &mut []);
 }
 
+#[itest]
+fn packed_array_try_to_array() {
+    let array = PackedFloat32Array::from(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(array.try_to_array::<4>(), Some([1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(array.try_to_array::<3>(), None);
+    assert_eq!(array.try_to_array::<5>(), None);
+
+    let empty = PackedInt32Array::new();
+    assert_eq!(empty.try_to_array::<0>(), Some([]));
+
+    let strings = PackedStringArray::from(&[GString::from("a")]);
+    assert_eq!(strings.try_to_array::<1>(), Some([GString::from("a")]));
+}
+
+#[itest]
+fn packed_byte_array_compress_roundtrip() {
+    let original = PackedByteArray::from("compressible ".repeat(50).as_bytes());
+
+    for mode in [
+        CompressionMode::FASTLZ,
+        CompressionMode::DEFLATE,
+        CompressionMode::ZSTD,
+        CompressionMode::GZIP,
+    ] {
+        let compressed = original.compress(mode);
+        assert!(compressed.len() < original.len(), "{mode:?} compresses");
+
+        // Buffer size is the original size; larger sizes are tolerated.
+        assert_eq!(
+            compressed.decompress(original.len(), mode),
+            Ok(original.clone())
+        );
+        assert_eq!(
+            compressed.decompress(original.len() + 100, mode),
+            Ok(original.clone())
+        );
+    }
+}
+
+#[itest]
+fn packed_byte_array_decompress_dynamic() {
+    let original = PackedByteArray::from("dynamic ".repeat(50).as_bytes());
+
+    for mode in [CompressionMode::DEFLATE, CompressionMode::GZIP] {
+        let compressed = original.compress(mode);
+        assert_eq!(
+            compressed.decompress_dynamic(None, mode),
+            Ok(original.clone())
+        );
+        assert_eq!(
+            compressed.decompress_dynamic(Some(original.len()), mode),
+            Ok(original.clone())
+        );
+    }
+}
+
+#[itest]
+fn packed_byte_array_decompress_invalid() {
+    let original = PackedByteArray::from("some data ".repeat(20).as_bytes());
+    let compressed = original.compress(CompressionMode::DEFLATE);
+
+    // Common mistake: passing the compressed instead of the original size.
+    assert_eq!(
+        compressed.decompress(compressed.len(), CompressionMode::DEFLATE),
+        Err(Error::ERR_INVALID_DATA)
+    );
+
+    let garbage = PackedByteArray::from(&[1, 2, 3, 4]);
+    assert_eq!(
+        garbage.decompress_dynamic(None, CompressionMode::GZIP),
+        Err(Error::ERR_INVALID_DATA)
+    );
+}
+
+#[itest]
+fn packed_vector3_array_transform_all() {
+    let original = PackedVector3Array::from(&[Vector3::ZERO, Vector3::new(1.0, 2.0, 3.0)]);
+    let mut array = original.clone();
+
+    let xform = Transform3D::IDENTITY.translated(Vector3::new(10.0, 0.0, -10.0));
+    array.transform_all(&xform);
+
+    assert_eq!(
+        array.as_slice(),
+        &[
+            Vector3::new(10.0, 0.0, -10.0),
+            Vector3::new(11.0, 2.0, -7.0)
+        ]
+    );
+    assert_eq!(original[1], Vector3::new(1.0, 2.0, 3.0), "copy-on-write");
+
+    let mut empty = PackedVector3Array::new();
+    empty.transform_all(&xform);
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn packed_array_as_mut_slice_writes_through() {
+    let mut a = PackedByteArray::from(&[1, 2, 3]);
+    let ptr = a.as_slice().as_ptr();
+
+    let slice = a.as_mut_slice(); // unique, so no copy
+    assert_eq!(slice.as_ptr(), ptr, "unique array must not be copied");
+    slice[0] = 10;
+    slice[2] = 30;
+
+    assert_eq!(a.get(0), Some(10));
+    assert_eq!(a.to_vec(), vec![10, 2, 30]);
+}
+
 #[itest]
 fn packed_array_index() {
     let array = PackedByteArray::from(&[1, 2]);
@@ -296,6 +430,47 @@ fn packed_array_reverse() {
     assert_eq!(array.to_vec(), vec![2, 1]);
 }
 
+#[itest]
+fn packed_int_array_bitset() {
+    let mut array = PackedInt32Array::from(&[0, 0]);
+    assert_eq!(array.bit_len(), 64);
+
+    array.set_bit(0, true);
+    array.set_bit(31, true);
+    array.set_bit(32, true);
+    assert_eq!(array.to_vec(), vec![i32::MIN | 1, 1]);
+    assert!(array.get_bit(31));
+    assert!(!array.get_bit(30));
+    assert_eq!(array.count_ones(), 3);
+
+    array.set_bit(0, false);
+    assert!(!array.get_bit(0));
+    assert_eq!(array.count_ones(), 2);
+
+    let mut array = PackedInt64Array::from(&[-1, 0]);
+    assert_eq!(array.bit_len(), 128);
+    assert_eq!(array.count_ones(), 64);
+
+    array.set_bit(127, true);
+    assert_eq!(array[1], i64::MIN);
+    assert!(array.get_bit(127));
+}
+
+#[itest]
+fn packed_int_array_bitset_bounds() {
+    let mut array = PackedInt64Array::from(&[0]);
+    expect_panic("get_bit() out of bounds", || {
+        array.get_bit(64);
+    });
+    expect_panic("set_bit() out of bounds", || {
+        array.set_bit(64, true);
+    });
+
+    let empty = PackedInt32Array::new();
+    assert_eq!(empty.bit_len(), 0);
+    assert_eq!(empty.count_ones(), 0);
+}
+
 #[itest]
 fn packed_array_format() {
     let a = PackedByteArray::from(&[2, 1]);
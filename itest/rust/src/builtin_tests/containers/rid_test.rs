@@ -6,8 +6,9 @@
  */
 
 use godot::builtin::inner::InnerRid;
-use godot::builtin::Rid;
+use godot::builtin::{Rid, VariantType};
 use godot::classes::RenderingServer;
+use godot::meta::ToGodot;
 
 use crate::framework::{itest, suppress_godot_print};
 
@@ -22,6 +23,26 @@ fn rid_equiv() {
     assert_eq!(InnerRid::from_outer(&valid).get_id(), (10 << 32) | 20);
 }
 
+#[itest]
+fn rid_variant_roundtrip() {
+    assert_eq!(Rid::default(), Rid::Invalid);
+
+    let mut server = RenderingServer::singleton();
+
+    // Dynamic calls transport RIDs through Variant, which must keep the RID type.
+    let variant = server.call("canvas_create".into(), &[]);
+    assert_eq!(variant.get_type(), VariantType::RID);
+
+    let canvas = variant.to::<Rid>();
+    assert!(canvas.is_valid());
+    assert_eq!(canvas.to_variant(), variant);
+
+    let result = server.call("free_rid".into(), &[canvas.to_variant()]);
+    assert!(result.is_nil());
+
+    assert_eq!(Rid::Invalid.to_variant().to::<Rid>(), Rid::Invalid);
+}
+
 #[itest]
 fn canvas_set_parent() {
     // This originally caused UB, but still testing it here in case it breaks.
@@ -7,12 +7,14 @@
 
 use std::cell::Cell;
 
-use godot::builtin::{Callable, GString, Signal, StringName, Variant};
+use godot::builtin::{dict, varray, Callable, GString, Signal, StringName, Variant, VariantType};
 use godot::meta::ToGodot;
 use godot::register::{godot_api, GodotClass};
 
-use godot::classes::{Object, RefCounted};
-use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::classes::object::ConnectFlags;
+use godot::classes::{Object, RefCounted, Resource};
+use godot::global::Error;
+use godot::obj::{Base, EngineBitfield, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::sys;
 
 use crate::framework::itest;
@@ -33,6 +35,19 @@ impl Emitter {
     fn signal_2_arg(arg1: Gd<Object>, arg2: GString);
 }
 
+// Used in `test_emit_signal_deferred` in `SpecialTests.gd`, which lets the deferred emission run.
+#[derive(GodotClass)]
+#[class(init)]
+struct DeferredSignalTest {}
+
+#[godot_api]
+impl DeferredSignalTest {
+    #[func]
+    fn emit_signal_1_arg_deferred(mut emitter: Gd<Emitter>, arg1: i64) {
+        emitter.emit_signal_deferred("signal_1_arg", &[arg1.to_variant()]);
+    }
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Object)]
 struct Receiver {
@@ -62,6 +77,32 @@ impl Receiver {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct BoundReceiver {
+    received: Vec<(i64, i64, GString)>,
+}
+
+#[godot_api]
+impl BoundReceiver {
+    #[func]
+    fn receive_bound(&mut self, emitted: i64, id: i64, tag: GString) {
+        self.received.push((emitted, id, tag));
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedEmitter {
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl TypedEmitter {
+    #[signal]
+    fn value_changed(value: i64);
+}
+
 const SIGNAL_ARG_STRING: &str = "Signal string arg";
 
 #[itest]
@@ -93,6 +134,20 @@ fn signals() {
     emitter.free();
 }
 
+#[itest]
+fn signals_typed_emit() {
+    let mut emitter = TypedEmitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    emitter.connect("value_changed".into(), receiver.callable("receive_1_arg"));
+    emitter.bind_mut().emit_value_changed(987);
+
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
 #[itest]
 fn instantiate_signal() {
     let mut object = RefCounted::new_gd();
@@ -130,6 +185,32 @@ fn emit_signal() {
     receiver.free();
 }
 
+#[itest]
+fn signal_variant_roundtrip() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let variant = signal.to_variant();
+    assert_eq!(variant.get_type(), VariantType::SIGNAL);
+    assert_eq!(variant.to::<Signal>(), signal);
+
+    // Signals can be stored in containers and retrieved with their object intact.
+    let dict = dict! { "on_test": signal.clone() };
+    let stored = dict.get("on_test").unwrap().to::<Signal>();
+    assert_eq!(stored.name(), StringName::from("test_signal"));
+    assert_eq!(stored.object(), Some(object.clone().upcast::<Object>()));
+
+    let receiver = Receiver::new_alloc();
+    stored.connect(receiver.callable("receive_1_arg"), 0);
+    assert!(signal.is_connected(receiver.callable("receive_1_arg")));
+
+    signal.emit(&[987i64.to_variant()]);
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+}
+
 #[itest]
 fn connect_signal() {
     let mut object = RefCounted::new_gd();
@@ -149,3 +230,251 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+#[itest]
+fn connect_signal_bound_args() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let receiver = BoundReceiver::new_alloc();
+
+    // Same handler, distinguished by bound arguments.
+    let callable = receiver.callable("receive_bound");
+    signal.connect(callable.bind(&[1.to_variant(), "first".to_variant()]), 0);
+    signal.connect(callable.bindv(varray![2, "second"]), 0);
+    assert_eq!(signal.connections().len(), 2);
+
+    signal.emit(&[100i64.to_variant()]);
+
+    // Emit-time arguments come first, bound arguments after.
+    let mut received = receiver.bind().received.clone();
+    received.sort();
+    assert_eq!(
+        received,
+        vec![(100, 1, "first".into()), (100, 2, "second".into())]
+    );
+
+    receiver.free();
+}
+
+#[itest]
+fn connect_typed_signal_flags() {
+    let resource = Resource::new_gd();
+    let receiver = Receiver::new_alloc();
+    let callable = Callable::from_object_method(&receiver, "receive_0_arg");
+    let changed = resource.signals().changed();
+
+    let builder = changed.connect(callable.clone()).one_shot();
+    assert_eq!(builder.flags(), ConnectFlags::ONE_SHOT);
+
+    // Nothing is connected before `done()`.
+    let signal = changed.to_signal();
+    assert_eq!(signal.connections().len(), 0);
+
+    assert_eq!(builder.done(), Error::OK);
+    assert_eq!(signal.connections().len(), 1);
+
+    changed.emit(());
+    assert!(receiver.bind().used[0].get());
+
+    // One-shot connection is gone after emission.
+    assert_eq!(signal.connections().len(), 0);
+
+    let error = changed
+        .connect(callable.clone())
+        .deferred()
+        .reference_counted()
+        .done();
+    assert_eq!(error, Error::OK);
+
+    let connections = signal.connections();
+    assert_eq!(connections.len(), 1);
+
+    let expected = ConnectFlags::DEFERRED | ConnectFlags::REFERENCE_COUNTED;
+    assert_eq!(
+        connections.at(0).at("flags"),
+        (expected.ord() as i64).to_variant()
+    );
+
+    changed.disconnect(callable);
+    assert_eq!(signal.connections().len(), 0);
+
+    receiver.free();
+}
+
+#[itest]
+fn connect_typed_signal_reference_counted() {
+    let resource = Resource::new_gd();
+    let receiver = Receiver::new_alloc();
+    let callable = Callable::from_object_method(&receiver, "receive_0_arg");
+    let changed = resource.signals().changed();
+
+    let first = changed.connect(callable.clone()).reference_counted().done();
+    let second = changed.connect(callable.clone()).reference_counted().done();
+    assert_eq!(first, Error::OK);
+    assert_eq!(second, Error::OK);
+
+    // Connected twice, so one disconnect keeps the connection alive.
+    changed.disconnect(callable.clone());
+    assert!(changed.is_connected(callable.clone()));
+    assert_eq!(changed.to_signal().connections().len(), 1);
+
+    changed.disconnect(callable.clone());
+    assert!(!changed.is_connected(callable));
+
+    receiver.free();
+}
+
+#[itest]
+fn connect_typed_signal_user_class() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    // Signals declared with #[signal], with typed parameters.
+    let callable = Callable::from_object_method(&receiver, "receive_1_arg");
+    let error = emitter.signals().signal_1_arg().connect(callable).done();
+    assert_eq!(error, Error::OK);
+
+    emitter.signals().signal_1_arg().emit((987,));
+    assert!(receiver.bind().used[1].get());
+
+    let callable = Callable::from_object_method(&receiver, "receive_2_arg");
+    emitter.signals().signal_2_arg().connect(callable).done();
+
+    emitter
+        .signals()
+        .signal_2_arg()
+        .emit((receiver.clone().upcast(), SIGNAL_ARG_STRING.into()));
+    assert!(receiver.bind().used[2].get());
+
+    // Signals of the engine base class, here Object.
+    let callable = Callable::from_object_method(&receiver, "receive_0_arg");
+    emitter
+        .signals()
+        .property_list_changed()
+        .connect(callable)
+        .done();
+
+    emitter.signals().property_list_changed().emit(());
+    assert!(receiver.bind().used[0].get());
+
+    emitter.free();
+    receiver.free();
+}
+
+#[cfg(since_api = "4.2")]
+mod signal_future {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use godot::builtin::{Signal, SignalFutureError, StringName};
+    use godot::classes::Object;
+    use godot::meta::ToGodot;
+    use godot::obj::NewAlloc;
+
+    use crate::framework::{expect_panic, itest};
+
+    /// Waker which only counts how often it has been woken; there is no executor in the tests.
+    #[derive(Default)]
+    struct CountingWaker {
+        wakes: AtomicUsize,
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wakes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn make_signal(object: &mut godot::obj::Gd<Object>) -> Signal {
+        object.add_user_signal("future_signal".into());
+        Signal::from_object_signal(object, "future_signal")
+    }
+
+    #[itest]
+    fn signal_future_resolves_on_emit() {
+        let mut object = Object::new_alloc();
+        let signal = make_signal(&mut object);
+
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = pin!(signal.to_future());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(signal.connections().len(), 1);
+
+        object.emit_signal(
+            StringName::from("future_signal"),
+            &[7.to_variant(), "hi".to_variant()],
+        );
+        assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+
+        let Poll::Ready(result) = future.as_mut().poll(&mut cx) else {
+            panic!("future should be ready after emit");
+        };
+        assert_eq!(result, Ok(vec![7.to_variant(), "hi".to_variant()]));
+
+        // One-shot connection is gone after emission.
+        assert_eq!(signal.connections().len(), 0);
+        object.free();
+    }
+
+    #[itest]
+    fn signal_future_object_freed() {
+        let mut object = Object::new_alloc();
+        let signal = make_signal(&mut object);
+
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(Arc::clone(&counter));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = pin!(signal.to_future());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        object.free();
+        assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+
+        let result = future.as_mut().poll(&mut cx);
+        assert_eq!(result, Poll::Ready(Err(SignalFutureError::ObjectFreed)));
+    }
+
+    #[itest]
+    fn signal_future_poll_after_completion() {
+        let mut object = Object::new_alloc();
+        let signal = make_signal(&mut object);
+
+        let waker = Waker::from(Arc::new(CountingWaker::default()));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = pin!(signal.to_future());
+        signal.emit(&[]);
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(Ok(vec![])));
+
+        expect_panic("poll after Ready", || {
+            let _ = future.as_mut().poll(&mut cx);
+        });
+
+        object.free();
+    }
+
+    #[itest]
+    fn signal_future_drop_disconnects() {
+        let mut object = Object::new_alloc();
+        let signal = make_signal(&mut object);
+
+        let future = signal.to_future();
+        assert_eq!(signal.connections().len(), 1);
+
+        drop(future);
+        assert_eq!(signal.connections().len(), 0);
+
+        // Emitting afterwards has no receiver left.
+        signal.emit(&[]);
+        object.free();
+    }
+}
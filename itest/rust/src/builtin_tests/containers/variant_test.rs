@@ -11,10 +11,13 @@ use std::fmt::Display;
 use godot::builtin::{
     array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
+use godot::builtin::{
+    Basis, Dictionary, PackedStringArray, Rid, VariantArray, VariantOperator, VariantType, Vector2i,
+};
 use godot::classes::{Node, Node2D};
+use godot::meta::error::ConvertError;
 use godot::meta::{FromGodot, ToGodot};
-use godot::obj::{Gd, InstanceId, NewAlloc};
+use godot::obj::{EngineEnum, Gd, InstanceId, NewAlloc};
 use godot::sys::GodotFfi;
 
 use crate::common::roundtrip;
@@ -157,6 +160,43 @@ fn variant_array_bad_conversions() {
     }
 }
 
+#[itest]
+fn variant_try_from_conversions() {
+    fn extract(dict: &Dictionary) -> Result<(Vector3, GString, i32), ConvertError> {
+        let position: Vector3 = dict.at("position").try_into()?;
+        let name = GString::try_from(dict.at("name"))?;
+        let count: i32 = (&dict.at("count")).try_into()?;
+
+        Ok((position, name, count))
+    }
+
+    let dict = dict! {
+        "position": Vector3::new(1.0, 2.0, 3.0),
+        "name": "Godot",
+        "count": 7,
+    };
+    let extracted = extract(&dict).expect("all conversions should succeed");
+    assert_eq!(extracted, (Vector3::new(1.0, 2.0, 3.0), gstr("Godot"), 7));
+
+    let bad_dict = dict! {
+        "position": "not a vector",
+        "name": "Godot",
+        "count": 7,
+    };
+    let err = extract(&bad_dict).expect_err("String -> Vector3 conversion should fail");
+    let _: &dyn std::error::Error = &err;
+
+    let array: Array<i64> = array![1, 2, 3];
+    let back: Array<i64> = array.to_variant().try_into().expect("Array<i64> roundtrip");
+    assert_eq!(back, array);
+
+    let node = Node::new_alloc();
+    let back: Gd<Node> = node.to_variant().try_into().expect("Gd<Node> roundtrip");
+    assert_eq!(back, node);
+    assert!(Gd::<Node2D>::try_from(node.to_variant()).is_err());
+    node.free();
+}
+
 #[itest]
 fn variant_special_conversions() {
     // See https://github.com/godot-rust/gdext/pull/598.
@@ -183,6 +223,61 @@ fn variant_get_type() {
     assert_eq!(variant.get_type(), VariantType::BASIS)
 }
 
+#[itest]
+fn variant_type_name() {
+    assert_eq!(Variant::nil().type_name(), "Nil");
+    assert_eq!(74.to_variant().type_name(), "int");
+    assert_eq!(1.5.to_variant().type_name(), "float");
+    assert_eq!(gstr("hello").to_variant().type_name(), "String");
+    assert_eq!(TEST_BASIS.to_variant().type_name(), "Basis");
+    assert_eq!(Rid::Invalid.to_variant().type_name(), "RID");
+    assert_eq!(
+        PackedStringArray::new().to_variant().type_name(),
+        "PackedStringArray"
+    );
+
+    let node = Node::new_alloc();
+    assert_eq!(node.to_variant().type_name(), "Object");
+    node.free();
+
+    // Matches the engine's own naming.
+    for variant in [
+        true.to_variant(),
+        Vector2i::ZERO.to_variant(),
+        varray![].to_variant(),
+    ] {
+        let engine_name = godot::global::type_string(variant.get_type().ord() as i64);
+        assert_eq!(variant.type_name(), engine_name.to_string());
+    }
+}
+
+#[itest]
+fn variant_option_conversions() {
+    assert!(Variant::from_option(None::<i64>).is_nil());
+    assert_eq!(Variant::from_option(Some(7)), 7.to_variant());
+
+    assert_eq!(Variant::nil().try_to_option::<i64>().unwrap(), None);
+    assert_eq!(7.to_variant().try_to_option::<i64>().unwrap(), Some(7));
+    assert!(gstr("text").to_variant().try_to_option::<i64>().is_err());
+
+    // Null objects are nil, too.
+    let null_node = Variant::from_option(None::<Gd<Node>>);
+    assert!(null_node.is_nil());
+    assert_eq!(null_node.try_to_option::<Gd<Node>>().unwrap(), None);
+
+    // Methods without return value yield nil from dynamic calls.
+    let mut node = Node::new_alloc();
+    let result = node.call("set_name".into(), &[gstr("renamed").to_variant()]);
+    assert_eq!(result.try_to_option::<GString>().unwrap(), None);
+
+    let result = node.call("get_name".into(), &[]);
+    assert_eq!(
+        result.try_to_option::<StringName>().unwrap(),
+        Some(StringName::from("renamed"))
+    );
+    node.free();
+}
+
 #[itest]
 fn variant_equal() {
     assert_eq!(Variant::nil(), ().to_variant());
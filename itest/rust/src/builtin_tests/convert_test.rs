@@ -6,7 +6,8 @@
  */
 
 use godot::builtin::{
-    array, dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    array, dict, Array, Dictionary, GString, StringName, Variant, VariantArray, Vector2,
+    Vector2Axis,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
@@ -88,6 +89,27 @@ fn error_maintains_value() {
     assert_eq!(format!("{value:?}"), format!("{:?}", err.value().unwrap()));
 }
 
+#[itest]
+fn error_names_source_and_target() {
+    let err = "hello".to_variant().try_to::<i64>().unwrap_err();
+    assert_eq!(err.to_string(), r#"expected type INT, got STRING: "hello""#);
+
+    let mut node = Node::new_alloc();
+    let err = node.to_variant().try_to::<Gd<Resource>>().unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("expected class Resource, got Node"),
+        "{err}"
+    );
+
+    // Dynamic call results can be validated without panicking.
+    let name = node.call("get_name".into(), &[]);
+    assert!(name.try_to::<i64>().is_err());
+    assert!(name.try_to::<StringName>().is_ok());
+
+    node.free();
+}
+
 // Manual implementation of `GodotConvert` and related traits to ensure conversion works.
 #[derive(PartialEq, Debug)]
 struct Foo {
@@ -157,6 +157,24 @@ fn basis_equiv() {
     )
 }
 
+#[itest]
+fn basis_looking_at_same() {
+    let targets = [
+        Vector3::new(1.0, 0.0, -1.0),
+        Vector3::new(0.0, -1.0, -1.0),
+        Vector3::new(-2.0, 3.0, 0.5),
+        Vector3::FORWARD,
+    ];
+
+    for target in targets {
+        for use_model_front in [false, true] {
+            let rust_basis = Basis::new_looking_at(target, Vector3::UP, use_model_front);
+            let godot_basis = InnerBasis::looking_at(target, Vector3::UP, use_model_front);
+            assert_eq_approx!(rust_basis, godot_basis, "target: {target:?}\n");
+        }
+    }
+}
+
 fn deg_to_rad(rotation: Vector3) -> Vector3 {
     Vector3::new(
         rotation.x.to_radians(),
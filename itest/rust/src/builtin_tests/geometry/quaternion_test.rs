@@ -6,8 +6,9 @@
  */
 
 use crate::framework::{expect_panic, itest};
+use godot::builtin::inner::{InnerBasis, InnerQuaternion};
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{Quaternion, Vector3};
+use godot::builtin::{EulerOrder, Quaternion, RealConv, Vector3};
 
 #[itest]
 fn quaternion_default() {
@@ -178,4 +179,65 @@ fn quaternion_spherical_cubic_interpolate_in_time() {
     );
     assert_eq!(outcome, Quaternion::default())
 }
+#[itest]
+fn quaternion_slerp_inner_equivalence() {
+    let a = Quaternion::from_axis_angle(Vector3::RIGHT, 0.3);
+    let quats = [
+        a,
+        -a,
+        Quaternion::from_axis_angle(Vector3::RIGHT, 0.3 + 1e-4),
+        Quaternion::from_axis_angle(Vector3::UP, 2.5),
+        Quaternion::new(-1.0, -1.0, -1.0, 10.0).normalized(),
+        Quaternion::new(3.0, 3.0, 3.0, 5.0).normalized(),
+    ];
+    let weights = [0.0, 0.25, 0.5, 1.0];
+
+    for from in quats {
+        let inner = from.as_inner();
+
+        for to in quats {
+            for weight in weights {
+                let ctx = format!("{from:?} -> {to:?}, weight {weight}");
+                assert_eq_approx!(
+                    from.slerp(to, weight),
+                    inner.slerp(to, weight.as_f64()),
+                    "slerp {ctx}"
+                );
+                assert_eq_approx!(
+                    from.slerpni(to, weight),
+                    inner.slerpni(to, weight.as_f64()),
+                    "slerpni {ctx}"
+                );
+            }
+        }
+    }
+}
+
+#[itest]
+fn quaternion_euler_inner_equivalence() {
+    let euler = Vector3::new(0.3, -0.7, 1.1);
+
+    let quat = Quaternion::from_euler(euler);
+    assert_eq_approx!(quat, InnerQuaternion::from_euler(euler));
+
+    for order in [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ] {
+        let quat = Quaternion::from_euler_with_order(order, euler);
+        let from_basis = InnerBasis::from_euler(euler, order as i64).get_rotation_quaternion();
+
+        assert_eq_approx!(quat, from_basis, "{order:?}");
+        assert_eq_approx!(
+            quat.to_euler(order),
+            quat.as_inner().get_euler(order as i64),
+            "{order:?}"
+        );
+    }
+}
+
 // TODO more tests
@@ -21,6 +21,10 @@ fn rect2i_equiv_unary() {
         Rect2i::from_components(4, 4, 1, 1),
         Rect2i::from_components(8, 8, 2, 2),
         Rect2i::from_components(8, 8, 2, 3),
+        // Rects touching each other only at an edge or a corner.
+        Rect2i::from_components(0, 0, 2, 2),
+        Rect2i::from_components(2, 0, 1, 1),
+        Rect2i::from_components(2, 2, 1, 1),
     ];
     let test_vectors = [
         Vector2i::ZERO,
@@ -54,6 +58,11 @@ fn rect2i_equiv_unary() {
                 a.intersection(b).unwrap_or_default(),
                 inner_a.intersection(b),
             );
+            evaluate_mappings(
+                "intersection_or_empty",
+                a.intersection_or_empty(b),
+                inner_a.intersection(b),
+            );
             evaluate_mappings("merge", a.merge(b), inner_a.merge(b));
         }
 
@@ -6,9 +6,11 @@
  */
 
 use std::collections::HashSet;
+use std::fmt::Write;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::strings::GStringBuilder;
+use godot::builtin::{dict, varray, GString};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -95,6 +97,53 @@ fn string_hash() {
     assert_eq!(set.len(), 5);
 }
 
+#[itest]
+fn string_format_array() {
+    let template = GString::from("{0} has {1} HP, {0}!");
+    let text = template.format_array(&varray!["Goblin", 12]);
+    assert_eq!(text, GString::from("Goblin has 12 HP, Goblin!"));
+
+    // Placeholders without a matching index are kept.
+    let text = GString::from("{0} and {2}").format_array(&varray!["a", "b"]);
+    assert_eq!(text, GString::from("a and {2}"));
+
+    // Godot also accepts [key, value] pairs.
+    let text = GString::from("{name}").format_array(&varray![varray!["name", "Godot"]]);
+    assert_eq!(text, GString::from("Godot"));
+}
+
+#[itest]
+fn string_format_dict() {
+    let template = GString::from("{name} has {hp} HP");
+    let text = template.format_dict(&dict! { "name": "Goblin", "hp": 12 });
+    assert_eq!(text, GString::from("Goblin has 12 HP"));
+
+    let text = GString::from("{name} {missing}").format_dict(&dict! { "name": "Godot" });
+    assert_eq!(text, GString::from("Godot {missing}"));
+}
+
+#[itest]
+fn string_split() {
+    let string = GString::from(",a,b,,c");
+    assert_eq!(
+        string.split(",", true),
+        ["", "a", "b", "", "c"].map(GString::from)
+    );
+    assert_eq!(string.split(",", false), ["a", "b", "c"].map(GString::from));
+    assert_eq!(
+        GString::from("abc").split(";", true),
+        [GString::from("abc")]
+    );
+    assert_eq!(GString::new().split(",", false), Vec::<GString>::new());
+}
+
+#[itest]
+fn string_split_floats() {
+    let string = GString::from("1.5,2,,-3");
+    assert_eq!(string.split_floats(",", false), [1.5, 2.0, -3.0]);
+    assert_eq!(string.split_floats(",", true), [1.5, 2.0, 0.0, -3.0]);
+}
+
 #[itest]
 fn string_with_null() {
     // Godot always ignores bytes after a null byte.
@@ -113,3 +162,22 @@ fn string_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_builder() {
+    let mut builder = GStringBuilder::new();
+    assert!(builder.is_empty());
+
+    builder.push_str("Hello");
+    builder.push(',');
+    write!(builder, " {}! {}", GString::from("Gödot"), 4.3).unwrap();
+    writeln!(builder).unwrap();
+    assert_eq!(builder.as_str(), "Hello, Gödot! 4.3\n");
+
+    let string = builder.build();
+    assert_eq!(string, GString::from("Hello, Gödot! 4.3\n"));
+    assert_eq!(string.len(), 18);
+
+    let empty = GString::from(GStringBuilder::with_capacity(16));
+    assert_eq!(empty, GString::new());
+}
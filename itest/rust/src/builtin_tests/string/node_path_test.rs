@@ -7,8 +7,8 @@
 
 use std::collections::HashSet;
 
-use crate::framework::itest;
-use godot::builtin::{GString, NodePath};
+use crate::framework::{expect_panic, itest};
+use godot::builtin::{GString, NodePath, StringName};
 
 #[itest]
 fn node_path_default() {
@@ -83,3 +83,79 @@ fn node_path_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn node_path_names_subnames() {
+    let path = NodePath::from("/root/Player/Sprite:modulate:a");
+
+    assert!(path.is_absolute());
+    assert_eq!(path.get_name_count(), 3);
+    assert_eq!(path.get_name(0), StringName::from("root"));
+    assert_eq!(path.get_name(2), StringName::from("Sprite"));
+    assert_eq!(path.get_subname_count(), 2);
+    assert_eq!(path.get_subname(0), StringName::from("modulate"));
+    assert_eq!(path.get_subname(1), StringName::from("a"));
+    assert_eq!(
+        path.get_concatenated_names(),
+        StringName::from("root/Player/Sprite")
+    );
+    assert_eq!(
+        path.get_concatenated_subnames(),
+        StringName::from("modulate:a")
+    );
+
+    // Property-only path, relative to the current node.
+    let property = NodePath::from(":position:x");
+    assert!(!property.is_absolute());
+    assert_eq!(property.get_name_count(), 0);
+    assert_eq!(property.get_subname_count(), 2);
+
+    expect_panic("name index out of bounds", || {
+        path.get_name(3);
+    });
+    expect_panic("subname index out of bounds", || {
+        property.get_subname(2);
+    });
+}
+
+#[itest]
+fn node_path_string_roundtrip() {
+    let cases = [
+        "",
+        "Node",
+        "../Sibling",
+        "/root/Main/Player",
+        "Player:position",
+        "Player/Sprite:modulate:a",
+        ":position:x",
+    ];
+
+    for case in cases {
+        let path = NodePath::from(case);
+        assert_eq!(path.to_string(), case);
+        assert_eq!(NodePath::from(GString::from(&path)), path);
+    }
+}
+
+#[itest]
+fn node_path_join() {
+    let parent = NodePath::from("/root/Player");
+
+    assert_eq!(
+        parent.join(&NodePath::from("Sprite:modulate:a")),
+        NodePath::from("/root/Player/Sprite:modulate:a")
+    );
+    assert_eq!(
+        NodePath::from("Player").join(&NodePath::from("../Enemy")),
+        NodePath::from("Player/../Enemy")
+    );
+    assert_eq!(parent.join(&NodePath::default()), parent);
+    assert_eq!(NodePath::default().join(&parent), parent);
+
+    expect_panic("join absolute path", || {
+        parent.join(&NodePath::from("/root"));
+    });
+    expect_panic("join to path with subnames", || {
+        NodePath::from("Player:position").join(&NodePath::from("x"));
+    });
+}
@@ -144,6 +144,41 @@ fn string_name_from_cstr() {
     }
 }
 
+#[itest]
+#[cfg(since_api = "4.2")]
+fn string_name_from_latin1() {
+    let long = "long_identifier_".repeat(10);
+    let cases: [(&[u8], &str); 4] = [
+        (b"", ""),
+        (b"get_position", "get_position"),
+        (b"Latin-1 \xA3 \xB1", "Latin-1 £ ±"),
+        (long.as_bytes(), &long),
+    ];
+
+    for (bytes, string) in cases.into_iter() {
+        assert_eq!(StringName::from_latin1(bytes), StringName::from(string));
+    }
+}
+
+#[itest]
+fn string_name_intern() {
+    let a = StringName::intern("interned_name");
+    let b = StringName::intern("interned_name");
+    let other = StringName::intern("other_interned_name");
+
+    assert_eq!(a, StringName::from("interned_name"));
+    assert_eq!(a, b);
+    assert_ne!(a, other);
+
+    StringName::clear_interned();
+
+    // Previously returned names stay valid, and interning works again after clearing.
+    assert_eq!(a, StringName::from("interned_name"));
+    assert_eq!(StringName::intern("interned_name"), a);
+
+    StringName::clear_interned();
+}
+
 #[itest]
 fn string_name_with_null() {
     // Godot always ignores bytes after a null byte.
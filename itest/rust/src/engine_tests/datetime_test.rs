@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::dict;
+use godot::classes::time::{Month, Weekday};
+use godot::classes::Time;
+use godot::meta::{FromGodot, ToGodot};
+use godot::tools::Datetime;
+
+use crate::framework::itest;
+
+#[itest]
+fn datetime_from_unix_time() {
+    let datetime = Datetime::from_unix_time(1_000_000_000);
+
+    assert_eq!(
+        datetime,
+        Datetime {
+            year: 2001,
+            month: Month::SEPTEMBER,
+            day: 9,
+            weekday: Weekday::SUNDAY,
+            hour: 1,
+            minute: 46,
+            second: 40,
+        }
+    );
+
+    let epoch = Datetime::from_unix_time(0);
+    assert_eq!(epoch.year, 1970);
+    assert_eq!(epoch.month, Month::JANUARY);
+    assert_eq!(epoch.weekday, Weekday::THURSDAY);
+}
+
+#[itest]
+fn datetime_dictionary_roundtrip() {
+    let datetime = Datetime::from_unix_time(1_700_000_000);
+    let dict = datetime.to_dictionary();
+
+    // Same layout as Godot's own dictionary.
+    assert_eq!(
+        dict,
+        Time::singleton().get_datetime_dict_from_unix_time(1_700_000_000)
+    );
+    assert_eq!(
+        Time::singleton().get_unix_time_from_datetime_dict(dict.clone()),
+        1_700_000_000
+    );
+
+    assert_eq!(Datetime::try_from_dictionary(&dict).unwrap(), datetime);
+    assert_eq!(Datetime::from_variant(&datetime.to_variant()), datetime);
+}
+
+#[itest]
+fn datetime_from_system() {
+    let datetime = Datetime::from_system(true);
+    assert!(datetime.year >= 2024);
+
+    // Extra `dst` key of the system dictionary is ignored.
+    let dict = Time::singleton().get_datetime_dict_from_system();
+    assert!(dict.contains_key("dst"));
+    assert!(Datetime::try_from_dictionary(&dict).is_ok());
+}
+
+#[itest]
+fn datetime_invalid_dictionary() {
+    let valid = Datetime::from_unix_time(0).to_dictionary();
+
+    let mut missing = valid.duplicate_shallow();
+    missing.remove("hour");
+    assert!(Datetime::try_from_dictionary(&missing).is_err());
+
+    let mut out_of_range = valid.duplicate_shallow();
+    out_of_range.set("minute", 60);
+    assert!(Datetime::try_from_dictionary(&out_of_range).is_err());
+
+    let mut bad_month = valid;
+    bad_month.set("month", 13);
+    assert!(Datetime::try_from_dictionary(&bad_month).is_err());
+
+    let wrong_type = dict! { "year": "2024" };
+    assert!(Datetime::try_from_dictionary(&wrong_type).is_err());
+}
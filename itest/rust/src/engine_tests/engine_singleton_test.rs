@@ -0,0 +1,25 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Engine, Node, ProjectSettings};
+
+use crate::framework::itest;
+
+#[itest]
+fn engine_get_singleton_as() {
+    let engine = Engine::singleton();
+
+    let settings = engine
+        .get_singleton_as::<ProjectSettings>("ProjectSettings")
+        .expect("ProjectSettings singleton exists");
+    assert_eq!(settings, ProjectSettings::singleton());
+
+    assert!(engine.get_singleton_as::<Node>("ProjectSettings").is_none()); // Type mismatch.
+    assert!(engine
+        .get_singleton_as::<ProjectSettings>("NoSuchSingleton")
+        .is_none());
+}
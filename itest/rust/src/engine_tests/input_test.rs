@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{StringName, Vector2};
+use godot::classes::Input;
+
+use crate::framework::itest;
+
+#[itest]
+fn input_named_actions() {
+    let mut input = Input::singleton();
+
+    // Built-in UI actions exist in every project, and nothing is pressed in headless test runs.
+    assert_eq!(
+        input.get_vector_named("ui_left", "ui_right", "ui_up", "ui_down"),
+        Vector2::ZERO
+    );
+    assert_eq!(input.get_axis_named("ui_left", "ui_right"), 0.0);
+    assert!(!input.is_action_pressed_named("ui_right"));
+
+    input.action_press(StringName::from("ui_right"));
+    assert_eq!(
+        input.get_vector_named("ui_left", "ui_right", "ui_up", "ui_down"),
+        Vector2::RIGHT
+    );
+    assert_eq!(input.get_axis_named("ui_left", "ui_right"), 1.0);
+    assert!(input.is_action_pressed_named("ui_right"));
+    assert!(!input.is_action_just_released_named("ui_right"));
+
+    input.action_release(StringName::from("ui_right"));
+    assert!(!input.is_action_pressed_named("ui_right"));
+    assert!(!input.is_action_just_pressed_named("ui_right"));
+    assert_eq!(input.get_axis_named("ui_left", "ui_right"), 0.0);
+}
@@ -10,9 +10,10 @@ use std::str::FromStr;
 use godot::builtin::{NodePath, Variant};
 use godot::classes::{Node, Node3D, PackedScene, SceneTree};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{expect_panic, itest, TestContext};
 
 #[itest]
 fn node_get_node() {
@@ -48,9 +49,67 @@ fn node_get_node_fail() {
     let found = child.try_get_node_as::<Node3D>(NodePath::from("non-existent"));
     assert!(found.is_none());
 
+    expect_panic("get_node_as() with non-existent path", || {
+        child.get_node_as::<Node3D>("non-existent");
+    });
+
     child.free();
 }
 
+#[itest]
+fn node_get_node_bad_type() {
+    let mut child = Node::new_alloc();
+    child.set_name("child".into());
+
+    let mut parent = Node::new_alloc();
+    parent.add_child(child);
+
+    let found = parent.try_get_node_as::<Node3D>("child");
+    assert!(found.is_none());
+
+    let found = parent.try_get_node_as::<Node>("child");
+    assert!(found.is_some());
+
+    expect_panic("get_node_as() with wrong type", || {
+        parent.get_node_as::<Node3D>("child");
+    });
+
+    parent.free();
+}
+
+#[itest]
+fn node_children_descendants() {
+    let mut root = Node::new_alloc();
+    let mut a = Node::new_alloc();
+    a.set_name("a".into());
+    let mut a1 = Node::new_alloc();
+    a1.set_name("a1".into());
+    let mut b = Node::new_alloc();
+    b.set_name("b".into());
+
+    a.add_child(a1);
+    root.add_child(a);
+    root.add_child(b);
+
+    let names = |iter: &mut dyn Iterator<Item = Gd<Node>>| {
+        iter.map(|node| node.get_name().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names(&mut root.children()), ["a", "b"]);
+    assert_eq!(names(&mut root.descendants()), ["a", "a1", "b"]);
+
+    // Children are snapshotted on creation.
+    let mut children = root.children();
+    let mut c = Node::new_alloc();
+    c.set_name("c".into());
+    root.add_child(c);
+    assert_eq!(names(&mut children), ["a", "b"]);
+    assert_eq!(root.children().count(), 3);
+
+    root.free();
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.clone();
@@ -60,6 +119,21 @@ fn node_path_from_str(ctx: &TestContext) {
     );
 }
 
+#[itest]
+fn node_get_tree_safe(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.clone();
+    let child = Node::new_alloc();
+    assert_eq!(child.get_tree_safe(), None);
+
+    root.add_child(&child);
+    assert_eq!(child.get_tree_safe(), root.get_tree());
+
+    root.remove_child(&child);
+    assert_eq!(child.get_tree_safe(), None);
+
+    child.free();
+}
+
 #[itest(skip)]
 fn node_scene_tree() {
     let mut child = Node::new_alloc();
@@ -92,3 +166,36 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group".into());
     tree.call_group("group".into(), "set_name".into(), &[Variant::from("name")]);
 }
+
+#[itest]
+fn node_call_group_broadcast(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.clone();
+    let mut tree = root.get_tree().unwrap();
+
+    let mut plain = Node::new_alloc();
+    let mut spatial = Node3D::new_alloc();
+    let outsider = Node3D::new_alloc();
+    plain.add_to_group("enemies".into());
+    spatial.add_to_group("enemies".into());
+    root.add_child(&plain);
+    root.add_child(&spatial);
+    root.add_child(&outsider);
+
+    assert_eq!(tree.get_nodes_in_group("enemies".into()).len(), 2);
+
+    let spatials = tree.get_nodes_in_group_as::<Node3D>("enemies");
+    assert_eq!(spatials, vec![spatial.clone()]);
+
+    tree.call_group(
+        "enemies".into(),
+        "set_meta".into(),
+        &["hit".to_variant(), 3.to_variant()],
+    );
+    assert_eq!(plain.get_meta("hit".into()), 3.to_variant());
+    assert_eq!(spatial.get_meta("hit".into()), 3.to_variant());
+    assert!(!outsider.has_meta("hit".into()));
+
+    plain.free();
+    spatial.free();
+    outsider.free();
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::ProjectSettings;
+use godot::meta::ToGodot;
+
+use crate::framework::itest;
+
+#[itest]
+fn project_settings_get_setting_as() {
+    let mut settings = ProjectSettings::singleton();
+    let name = "gdext_itest/custom/level_count";
+
+    assert_eq!(settings.get_setting_as::<i64>(name), None);
+
+    settings.set_setting(name.into(), 42.to_variant());
+    assert_eq!(settings.get_setting_as::<i64>(name), Some(42));
+    assert_eq!(settings.get_setting_as::<GString>(name), None); // Type mismatch.
+
+    settings.clear(name.into());
+    assert_eq!(settings.get_setting_as::<i64>(name), None);
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector2;
+use godot::classes::Node2D;
+use godot::obj::NewAlloc;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn tween_property_checked(ctx: &TestContext) {
+    let node = Node2D::new_alloc();
+    let mut tween = ctx.scene_tree.clone().create_tween().expect("tween");
+
+    let target = Vector2::new(10.0, 20.0);
+    assert!(tween
+        .tween_property_checked(&node, "position", target, 1.0)
+        .is_some());
+    assert!(tween
+        .tween_property_checked(&node, "position:x", 5.0, 1.0)
+        .is_some());
+    assert!(tween
+        .tween_property_checked(&node, "no_such_property", 5.0, 1.0)
+        .is_none());
+    assert!(tween.tween_property_checked(&node, "", 5.0, 1.0).is_none());
+
+    tween.kill();
+    node.free();
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn tween_method_fn(ctx: &TestContext) {
+    let mut tween = ctx.scene_tree.clone().create_tween().expect("tween");
+
+    let tweener = tween.tween_method_fn(0.0, 1.0, 0.5, |_progress| {});
+    assert!(tweener.is_some());
+
+    tween.kill();
+}
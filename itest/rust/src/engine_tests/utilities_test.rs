@@ -83,3 +83,14 @@ fn utilities_is_instance_valid() {
     node.free();
     assert!(!is_instance_valid(variant));
 }
+
+#[itest]
+fn utilities_print_macros_format_args() {
+    // Only checks that all format!() syntax is accepted; output is not captured.
+    let value = 5;
+    godot_print!("[print test] inline {value}");
+    godot_print!("[print test] positional {}, {}", value, value + 1);
+    godot_print!("[print test] named {name}", name = "arg");
+    godot_print!("[print test] trailing comma {}", value,);
+    godot_print_rich!("[print test] [b]rich[/b] {value:>4}");
+}
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::{expect_panic, itest};
+use crate::framework::{expect_panic, itest, TestContext};
 use godot::prelude::*;
 
 #[itest(skip)]
@@ -72,7 +72,7 @@ fn base_debug() {
 
         // We expect the dynamic type to be part of Godot's to_string(), so Based and not Node2D
         let actual = format!(".:{:?}:.", guard.base);
-        let expected = format!(".:Base {{ id: {id}, class: Based }}:.");
+        let expected = format!(".:Base<Node2D> {{ id: {id}, class: Based }}:.");
 
         assert_eq!(actual, expected);
     }
@@ -94,6 +94,42 @@ fn base_with_init() {
     obj.free();
 }
 
+#[itest]
+fn base_with_init_instance_id() {
+    // Node-derived, manually managed.
+    let node = Gd::<Based>::from_init_fn(|base| Based { base, i: 0 });
+    assert_eq!(node.bind().base().instance_id(), node.instance_id());
+    node.free();
+
+    // RefCounted-derived.
+    let refc = Gd::from_init_fn(|base| RefcBased { base, value: 1 });
+    assert_eq!(refc.bind().base().instance_id(), refc.instance_id());
+    assert_eq!(refc.bind().value, 1);
+
+    // Object-derived, manually managed.
+    let obj = Gd::from_init_fn(|base| ObjectBased { base, value: 2 });
+    assert_eq!(obj.bind().base().instance_id(), obj.instance_id());
+    assert_eq!(obj.bind().value, 2);
+    obj.free();
+}
+
+#[itest]
+fn base_with_init_before_ready(ctx: &TestContext) {
+    let obj = Gd::from_init_fn(|base| InitBeforeReady {
+        initial: 42,
+        seen_in_ready: None,
+        base,
+    });
+    assert_eq!(obj.bind().seen_in_ready, None);
+
+    // Fields are already initialized when the node enters the tree.
+    let mut tree = ctx.scene_tree.clone();
+    tree.add_child(&obj);
+    assert_eq!(obj.bind().seen_in_ready, Some(42));
+
+    obj.free();
+}
+
 #[itest]
 fn base_gd_self() {
     let obj = Based::new_alloc();
@@ -229,3 +265,32 @@ impl Baseless {
         })
     }
 }
+
+#[derive(GodotClass)]
+#[class(no_init, base=Node)]
+struct InitBeforeReady {
+    initial: i32,
+    seen_in_ready: Option<i32>,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for InitBeforeReady {
+    fn ready(&mut self) {
+        self.seen_in_ready = Some(self.initial);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(no_init, base=RefCounted)]
+struct RefcBased {
+    base: Base<RefCounted>,
+    value: i32,
+}
+
+#[derive(GodotClass)]
+#[class(no_init, base=Object)]
+struct ObjectBased {
+    base: Base<Object>,
+    value: i32,
+}
@@ -24,6 +24,14 @@ pub mod rename {
     pub struct RepeatMe {}
 }
 
+pub mod rename_string {
+    use super::*;
+
+    #[derive(GodotClass)]
+    #[class(rename = "NoRepeatString", no_init)]
+    pub struct RepeatMe {}
+}
+
 #[itest]
 fn renaming_changes_the_name() {
     assert_ne!(
@@ -33,3 +41,15 @@ fn renaming_changes_the_name() {
     assert_eq!(dont_rename::RepeatMe::class_name().to_string(), "RepeatMe");
     assert_eq!(rename::RepeatMe::class_name().to_string(), "NoRepeat");
 }
+
+#[itest]
+fn renaming_with_string_literal() {
+    assert_eq!(
+        rename_string::RepeatMe::class_name().to_string(),
+        "NoRepeatString"
+    );
+
+    let class_db = godot::classes::ClassDb::singleton();
+    assert!(class_db.class_exists("NoRepeatString".into()));
+    assert!(class_db.class_exists("NoRepeat".into()));
+}
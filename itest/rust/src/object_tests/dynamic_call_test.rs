@@ -5,11 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{StringName, Variant, Vector3};
-use godot::classes::{Node, Node3D, Object};
+use godot::builtin::{Array, Dictionary, StringName, Variant, VariantArray, Vector3};
+use godot::classes::{GDScript, Node, Node3D, Object};
 use godot::meta::error::CallError;
 use godot::meta::{FromGodot, ToGodot};
-use godot::obj::{InstanceId, NewAlloc};
+use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
 use std::error::Error;
 
 use crate::framework::{expect_panic, itest, runs_release};
@@ -45,6 +46,49 @@ fn dynamic_call_with_args() {
     node.free();
 }
 
+#[itest]
+fn dynamic_call_reflection() {
+    let mut obj = ObjPayload::new_alloc();
+
+    // Engine and Rust-defined members.
+    assert!(obj.has_method("free".into()));
+    assert!(obj.has_method("take_1_int".into()));
+    assert!(!obj.has_method("no_such_method".into()));
+    assert!(obj.has_signal("script_changed".into()));
+    assert!(obj.has_signal("do_use".into()));
+    assert!(!obj.has_signal("no_such_signal".into()));
+
+    let methods: Array<Dictionary> = obj.get_method_list();
+    let take_1_int = methods
+        .iter_shared()
+        .find(|info| info.at("name") == "take_1_int".to_variant())
+        .expect("take_1_int in method list");
+    assert_eq!(take_1_int.at("args").to::<VariantArray>().len(), 1);
+
+    // Script-defined members are visible too.
+    let code = r#"
+extends Object
+
+signal from_script
+
+func script_method():
+    pass
+"#;
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code.into());
+    script.reload();
+    obj.set_script(script.to_variant());
+
+    assert!(obj.has_method("script_method".into()));
+    assert!(obj.has_signal("from_script".into()));
+    assert!(obj
+        .get_method_list()
+        .iter_shared()
+        .any(|info| info.at("name") == "script_method".to_variant()));
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Erroneous dynamic calls to #[func]
 
@@ -263,6 +307,82 @@ fn dynamic_call_parameter_mismatch_engine() {
     node.free();
 }
 
+#[itest]
+fn dynamic_call_deferred_checked() {
+    let mut node = Node::new_alloc();
+
+    node.call_deferred_checked("set_name", &["Deferred".to_variant()])
+        .expect("valid deferred call");
+
+    node.deferred_call("set_name")
+        .arg("Deferred")
+        .done()
+        .expect("valid deferred call via builder");
+
+    let call_error = node
+        .call_deferred_checked("no_such_method", &[])
+        .expect_err("expected failed call");
+    assert_eq!(call_error.class_name(), Some("Node"));
+    assert_eq!(call_error.method_name(), "call_deferred_checked");
+    assert_eq!(
+        call_error.to_string(),
+        "godot-rust function call failed: Node::call_deferred_checked()\
+        \n    Reason: method `no_such_method` not found"
+    );
+
+    #[cfg(since_api = "4.3")]
+    {
+        let call_error = node
+            .deferred_call("set_name")
+            .arg("Deferred")
+            .arg(1)
+            .done()
+            .expect_err("expected failed call");
+        assert_eq!(
+            call_error.to_string(),
+            "godot-rust function call failed: Node::call_deferred_checked()\
+            \n    Reason: function has 1 parameter, but received 2 arguments"
+        );
+    }
+
+    // Freeing the node drops the queued calls.
+    node.free();
+}
+
+#[itest]
+fn dynamic_call_add_child_deferred() {
+    let mut parent = Node::new_alloc();
+    let child = Node3D::new_alloc();
+
+    parent.add_child_deferred(child.clone());
+
+    // Not applied until the end of the frame.
+    assert_eq!(parent.get_child_count(), 0);
+
+    // Freeing the parent drops the queued call.
+    parent.free();
+    child.free();
+}
+
+#[itest]
+fn dynamic_property_get_as() {
+    let mut node = Node3D::new_alloc();
+    node.set_position(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(
+        node.get_as::<Vector3>("position"),
+        Some(Vector3::new(1.0, 2.0, 3.0))
+    );
+    assert_eq!(node.get_as::<i64>("position"), None);
+    assert_eq!(node.get_as::<Vector3>("no_such_property"), None);
+
+    // Nil-valued properties are distinguished from absent ones.
+    assert_eq!(node.get_as::<Option<Gd<Node>>>("owner"), Some(None));
+    assert_eq!(node.get_as::<Option<Gd<Node>>>("no_such_property"), None);
+
+    node.free();
+}
+
 #[itest(skip)]
 fn dynamic_call_return_mismatch() {
     // Cannot easily test this, as both calls to #[func] and Godot APIs are either strongly typed and correct (ensured by codegen),
@@ -275,3 +395,18 @@ fn dynamic_call_return_mismatch() {
     // The parser will fail since it knows the signature of take_1_int(). And if we enforce `: Variant` type hints, it will just
     // cause a runtime error, but that's entirely handled in GDScript.
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// Used in `test_set_deferred_as` in `SpecialTests.gd`, which lets the deferred assignment run.
+#[derive(GodotClass)]
+#[class(init)]
+pub struct DeferredPropertyTest {}
+
+#[godot_api]
+impl DeferredPropertyTest {
+    #[func]
+    fn set_position_deferred(mut node: Gd<Node3D>, position: Vector3) {
+        node.set_deferred_as("position", position);
+    }
+}
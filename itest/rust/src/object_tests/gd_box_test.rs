@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Node, Object};
+use godot::obj::{Gd, GdBox, NewAlloc};
+use godot::register::GodotClass;
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn gd_box_frees_on_drop() {
+    let boxed = GdBox::new(Node::new_alloc());
+    let weak = boxed.downgrade();
+    assert!(boxed.is_instance_valid());
+
+    drop(boxed);
+    assert!(weak.upgrade().is_none());
+}
+
+#[itest]
+fn gd_box_already_freed() {
+    let boxed = GdBox::new(Object::new_alloc());
+    boxed.clone().free();
+    assert!(!boxed.is_instance_valid());
+
+    // Dropping does not free again.
+    drop(boxed);
+
+    let boxed = GdBox::new(Object::new_alloc());
+    boxed.clone().free();
+    expect_panic("explicit free() of dead object", || {
+        boxed.free();
+    });
+}
+
+#[itest]
+fn gd_box_into_inner() {
+    let boxed = GdBox::new(Node::new_alloc());
+    let node = boxed.into_inner();
+
+    // Ownership was given up, object still alive.
+    assert!(node.is_instance_valid());
+    node.free();
+}
+
+#[itest]
+fn gd_box_in_user_class() {
+    let holder = GdBoxHolder::new_holder();
+    let weak = holder.bind().child.downgrade();
+    assert!(weak.upgrade().is_some());
+
+    drop(holder);
+    assert!(weak.upgrade().is_none());
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(no_init)]
+struct GdBoxHolder {
+    child: GdBox<Node>,
+}
+
+impl GdBoxHolder {
+    fn new_holder() -> Gd<Self> {
+        Gd::from_object(Self {
+            child: GdBox::new(Node::new_alloc()),
+        })
+    }
+}
@@ -9,6 +9,7 @@ mod base_test;
 mod class_name_test;
 mod class_rename_test;
 mod dynamic_call_test;
+mod gd_box_test;
 // `get_property_list` is only supported in Godot 4.3+
 #[cfg(since_api = "4.3")]
 mod get_property_list_test;
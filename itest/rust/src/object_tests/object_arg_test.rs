@@ -33,6 +33,20 @@ fn object_arg_borrowed() {
     });
 }
 
+#[itest]
+fn object_arg_borrowed_keeps_refcount() {
+    let refc = user_refc_instance();
+    let refcount_before = refc.get_reference_count();
+
+    // Borrowing passes the object without cloning (and thus without incrementing) the handle.
+    let db = ClassDb::singleton();
+    let error = db.class_set_property(&refc, "value".into(), Variant::from(-123));
+    assert_eq!(error, global::Error::OK);
+
+    assert_eq!(refc.get_reference_count(), refcount_before);
+    assert_eq!(refc.bind().value, -123);
+}
+
 #[itest]
 fn object_arg_borrowed_mut() {
     with_objects(|mut manual, mut refc| {
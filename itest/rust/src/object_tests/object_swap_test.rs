@@ -14,7 +14,8 @@
 #![cfg(debug_assertions)]
 
 use godot::builtin::GString;
-use godot::classes::{Node, Node3D, Object};
+use godot::classes::{Node, Node3D, Object, RefCounted, Resource};
+use godot::meta::error::BindError;
 use godot::obj::{Gd, NewAlloc, NewGd};
 use godot::register::{godot_api, GodotClass};
 
@@ -89,6 +90,117 @@ fn object_subtype_swap_clone() {
     swapped_free!(obj, node);
 }
 
+#[itest]
+fn object_subtype_swap_validate_type_cached() {
+    let mut obj: Gd<Object> = Object::new_alloc();
+    let mut node: Gd<Node> = Node::new_alloc();
+    node.validate_type();
+
+    std::mem::swap(&mut *obj, &mut *node);
+
+    // `obj` now points to a Node, which is still a valid Object.
+    obj.validate_type();
+    assert!(obj.check_type().is_ok());
+
+    let err = node
+        .check_type()
+        .expect_err("check_type() on Gd<Node> pointing to Object");
+    let cause = err
+        .cause()
+        .and_then(|cause| cause.downcast_ref::<BindError>());
+    assert!(
+        matches!(cause, Some(BindError::WrongType { .. })),
+        "unexpected error: {err:?}"
+    );
+
+    expect_panic("validate_type() on Gd<Node> pointing to Object", || {
+        node.validate_type();
+    });
+
+    swapped_free!(obj, node);
+}
+
+#[itest]
+fn object_subtype_swap_validate_type() {
+    let mut node: Gd<Node> = Node::new_alloc();
+    let mut node_3d: Gd<Node3D> = Node3D::new_alloc();
+    node.validate_type();
+    node_3d.validate_type();
+
+    // Upcasts are fine, as the dynamic class still inherits the static one.
+    node_3d.clone().upcast::<Node>().validate_type();
+
+    std::mem::swap(&mut *node, &mut *node_3d);
+
+    // `node` now points to a Node3D, which is still a valid Node.
+    node.validate_type();
+    assert!(node.check_type().is_ok());
+    assert!(node_3d.check_type().is_err());
+
+    expect_panic("validate_type() on Gd<Node3D> pointing to Node", || {
+        node_3d.validate_type();
+    });
+
+    swapped_free!(node, node_3d);
+}
+
+#[itest]
+fn object_subtype_swap_free_if_valid() {
+    let mut obj: Gd<Object> = Object::new_alloc();
+    let mut node: Gd<Node> = Node::new_alloc();
+    let obj_copy = obj.clone();
+    let node_copy = node.clone();
+
+    std::mem::swap(&mut *obj, &mut *node);
+
+    // `node` now points to an Object, which cannot be freed as Gd<Node>. `obj` points to a Node, which is a valid Object.
+    assert!(!node.free_if_valid());
+    assert!(obj.free_if_valid());
+
+    assert!(!node_copy.is_instance_valid());
+    obj_copy.free();
+}
+
+#[itest]
+fn object_subtype_swap_clone_weak_typed() {
+    let mut obj: Gd<Object> = Object::new_alloc();
+    let mut node: Gd<Node> = Node::new_alloc();
+
+    let obj_id = obj.instance_id();
+    let node_id = node.instance_id();
+
+    std::mem::swap(&mut *obj, &mut *node);
+
+    // No RTTI check, so this works even though `node` now points to an Object.
+    let node_copy: Gd<Object> = node.clone_weak_typed();
+    let obj_copy: Gd<Object> = obj.clone_weak_typed();
+    assert_eq!(node_copy.instance_id(), obj_id);
+    assert_eq!(obj_copy.instance_id(), node_id);
+    assert_eq!(node_copy.get_class(), GString::from("Object"));
+    assert_eq!(obj_copy.get_class(), GString::from("Node"));
+
+    // Copies have correct types, so they can be freed directly.
+    node_copy.free();
+    obj_copy.free();
+}
+
+#[itest]
+fn object_subtype_swap_clone_weak_typed_refcounted() {
+    let mut obj: Gd<RefCounted> = RefCounted::new_gd();
+    let mut res: Gd<Resource> = Resource::new_gd();
+
+    std::mem::swap(&mut *obj, &mut *res);
+
+    // `res` points to the RefCounted object, which is now also referenced by the copy.
+    let copy = res.clone_weak_typed().cast::<RefCounted>();
+    assert_eq!(copy.get_class(), GString::from("RefCounted"));
+    assert_eq!(copy.get_reference_count(), 2);
+
+    // Swap handles back, so that destructors run with matching types (see swapped_free! for DerefMut limitations).
+    #[allow(clippy::missing_transmute_annotations)]
+    std::mem::swap(&mut res, unsafe { std::mem::transmute(&mut obj) });
+}
+
 #[itest]
 fn object_subtype_swap_free() {
     let mut obj: Gd<Object> = Object::new_alloc();
@@ -109,6 +221,28 @@ fn object_subtype_swap_free() {
     node_copy.free();
 }
 
+#[itest]
+fn object_subtype_swap_is_instance_valid() {
+    let mut obj: Gd<Object> = Object::new_alloc();
+    let mut node: Gd<Node> = Node::new_alloc();
+    let obj_copy = obj.clone();
+    let node_copy = node.clone();
+
+    std::mem::swap(&mut *obj, &mut *node);
+
+    // Validity check only looks at the instance ID, so it works even for badly typed Gd<T>.
+    assert!(obj.is_instance_valid());
+    assert!(node.is_instance_valid());
+
+    // Free with original type; `obj` now points to the node.
+    node_copy.free();
+    assert!(!obj.is_instance_valid());
+    assert!(node.is_instance_valid());
+
+    obj_copy.free();
+    assert!(!node.is_instance_valid());
+}
+
 #[itest]
 fn object_subtype_swap_argument_passing(ctx: &TestContext) {
     let mut obj: Gd<Object> = Object::new_alloc();
@@ -125,6 +259,22 @@ fn object_subtype_swap_argument_passing(ctx: &TestContext) {
     swapped_free!(obj, node2);
 }
 
+#[itest]
+fn object_subtype_swap_argument_passing_borrowed(ctx: &TestContext) {
+    let mut obj: Gd<Object> = Object::new_alloc();
+    let mut node: Gd<Node> = Node::new_alloc();
+
+    std::mem::swap(&mut *obj, &mut *node);
+
+    // Borrowed arguments (`&Gd<T>` as `impl AsObjectArg<T>`) are checked just like owned ones.
+    let mut tree = ctx.scene_tree.clone();
+    expect_panic("pass badly typed &Gd<T> to Godot engine API", || {
+        tree.add_child(&node);
+    });
+
+    swapped_free!(obj, node);
+}
+
 #[itest]
 fn object_subtype_swap_bind() {
     let mut obj: Gd<Object> = Object::new_alloc();
@@ -153,6 +303,16 @@ fn object_subtype_swap_bind() {
         let _ = user.bind_mut();
     });
 
+    // Non-panicking versions report the type mismatch.
+    let err = user
+        .try_bind()
+        .expect_err("try_bind() on badly typed Gd<T>");
+    assert!(matches!(err, BindError::WrongType { .. }), "{err}");
+    let err = user
+        .try_bind_mut()
+        .expect_err("try_bind_mut() on badly typed Gd<T>");
+    assert!(matches!(err, BindError::WrongType { .. }), "{err}");
+
     swapped_free!(obj, user);
 }
 
@@ -194,9 +354,50 @@ fn object_subtype_swap_casts() {
         let _ = node3d.clone().cast::<Node3D>();
     });
 
+    // Same for the unified cast_to().
+    expect_panic("cast_to() on Gd<T> with invalid runtime type", || {
+        let _ = node3d.clone().cast_to::<Node3D>();
+    });
+    let to_node3d = obj.clone().cast_to::<Node3D>();
+    assert_eq!(to_node3d.instance_id(), node3d_id);
+
     swapped_free!(obj, node3d);
 }
 
+#[itest]
+fn object_subtype_swap_dynamic_cast() {
+    let mut obj: Gd<Object> = Object::new_alloc();
+    let mut node3d: Gd<Node3D> = Node3D::new_alloc();
+
+    let obj_id = obj.instance_id();
+    let node3d_id = node3d.instance_id();
+
+    std::mem::swap(&mut *obj, &mut *node3d);
+
+    // Dynamic casts only look at the runtime class, so they succeed on badly typed Gd<T>.
+    let real_node3d = obj
+        .dynamic_cast::<Node3D>()
+        .expect("dynamic_cast to Node3D");
+    assert_eq!(real_node3d.instance_id(), node3d_id);
+
+    let real_node = obj.dynamic_cast::<Node>().expect("dynamic_cast to Node");
+    assert_eq!(real_node.instance_id(), node3d_id);
+
+    let real_obj = node3d
+        .dynamic_cast::<Object>()
+        .expect("dynamic_cast to Object");
+    assert_eq!(real_obj.instance_id(), obj_id);
+
+    assert!(node3d.dynamic_cast::<Node3D>().is_none());
+    assert!(node3d.dynamic_cast::<Node>().is_none());
+
+    real_node3d.free();
+    real_obj.free();
+
+    // Dead objects cannot be cast.
+    assert!(obj.dynamic_cast::<Object>().is_none());
+}
+
 #[itest]
 fn object_subtype_swap_func_return() {
     let mut holder = SwapHolder::new_gd();
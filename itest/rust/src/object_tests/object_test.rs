@@ -10,12 +10,13 @@ use std::rc::Rc;
 
 use godot::builtin::{GString, StringName, Variant, Vector3};
 use godot::classes::{
-    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
+    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node2D, Node3D, Object,
     RefCounted,
 };
 use godot::global::instance_from_id;
+use godot::meta::error::BindError;
 use godot::meta::{FromGodot, GodotType, ToGodot};
-use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
+use godot::obj::{Base, Gd, GdRef, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::{self, interface_fn, GodotFfi};
 
@@ -123,12 +124,37 @@ fn object_debug() {
     let id = obj.instance_id();
 
     let actual = format!(".:{obj:?}:.");
-    let expected = format!(".:Gd {{ id: {id}, class: Node3D }}:.");
+    let expected = format!(".:Gd<Node3D> {{ id: {id}, class: Node3D }}:.");
 
     assert_eq!(actual, expected);
     obj.free();
 }
 
+#[itest]
+fn object_debug_upcast() {
+    let obj = Node3D::new_alloc().upcast::<Node>();
+    let id = obj.instance_id();
+
+    let actual = format!(".:{obj:?}:.");
+    let expected = format!(".:Gd<Node> {{ id: {id}, class: Node3D }}:.");
+
+    assert_eq!(actual, expected);
+    obj.free();
+}
+
+#[itest]
+fn object_format_freed() {
+    let obj = Node3D::new_alloc();
+    let id = obj.instance_id();
+    obj.clone().free();
+
+    assert_eq!(format!("{obj}"), "<freed>");
+    assert_eq!(
+        format!("{obj:?}"),
+        format!("Gd<Node3D> {{ id: {id}, <freed> }}")
+    );
+}
+
 #[itest]
 fn object_instance_id() {
     let value: i16 = 17943;
@@ -154,6 +180,22 @@ fn object_instance_id_when_freed() {
     });
 }
 
+#[itest]
+fn object_instance_id_unchecked_when_freed() {
+    let node: Gd<Node3D> = Node3D::new_alloc();
+    let id = node.instance_id();
+    let other = Node3D::new_alloc();
+
+    node.clone().free();
+
+    // Still returns the cached ID, which can be compared against other (live or dead) objects.
+    assert_eq!(node.instance_id_unchecked(), id);
+    assert_ne!(node.instance_id_unchecked(), other.instance_id_unchecked());
+    assert!(Gd::<Node3D>::try_from_instance_id(id).is_err());
+
+    other.free();
+}
+
 #[itest]
 fn object_from_invalid_instance_id() {
     let id = InstanceId::try_from_i64(0xDEADBEEF).unwrap();
@@ -217,6 +259,84 @@ fn object_from_instance_id_unrelated_type() {
     node.free();
 }
 
+#[itest]
+fn object_from_instance_id_user_class() {
+    let obj = ObjPayload::new_alloc();
+    let id = obj.instance_id();
+
+    let rehydrated = Gd::<ObjPayload>::try_from_instance_id(id).expect("same user class");
+    assert_eq!(rehydrated, obj);
+
+    let as_base = Gd::<Object>::try_from_instance_id(id).expect("base class of user class");
+    assert_eq!(as_base.instance_id(), id);
+
+    Gd::<Node>::try_from_instance_id(id)
+        .expect_err("try_from_instance_id() with unrelated engine class should fail");
+
+    obj.free();
+    Gd::<ObjPayload>::try_from_instance_id(id)
+        .expect_err("try_from_instance_id() on freed object should fail");
+}
+
+#[itest]
+fn object_weak_upgrade_manual() {
+    let obj = ObjPayload::new_alloc();
+    let weak = obj.downgrade();
+    assert_eq!(weak.instance_id(), obj.instance_id());
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded, obj);
+
+    obj.free();
+    assert!(weak.upgrade().is_none());
+}
+
+#[itest]
+fn object_weak_does_not_keep_refcounted_alive() {
+    let obj = RefcPayload::new_gd();
+    let weak = obj.downgrade();
+    assert_eq!(obj.get_reference_count(), 1);
+
+    {
+        let upgraded = weak.upgrade().expect("object is alive");
+        assert_eq!(upgraded.bind().value, obj.bind().value);
+        assert_eq!(obj.get_reference_count(), 2);
+    }
+    assert_eq!(obj.get_reference_count(), 1);
+
+    drop(obj);
+    assert!(weak.upgrade().is_none());
+}
+
+// The leak tracker is only compiled in Debug mode.
+#[cfg(debug_assertions)]
+#[itest]
+fn object_leak_tracker_reports_leaked_refcounted() {
+    let was_enabled = godot::private::set_leak_tracking(true);
+
+    let obj = RefCounted::new_gd();
+    let id = obj.instance_id();
+
+    // Deliberately leak one reference, like a reference cycle would.
+    std::mem::forget(obj.clone());
+    drop(obj);
+
+    let mut leaked = vec![];
+    crate::framework::suppress_godot_print(|| leaked = godot::private::report_leaks_debug());
+    godot::private::set_leak_tracking(was_enabled);
+
+    let class_name = leaked
+        .iter()
+        .find(|(leaked_id, _)| *leaked_id == id)
+        .map(|(_, class_name)| class_name.to_string());
+    assert_eq!(class_name.as_deref(), Some("RefCounted"));
+
+    // Give back the leaked reference, so that dropping `obj` frees the object.
+    let mut obj = Gd::<RefCounted>::from_instance_id(id);
+    obj.call("unreference".into(), &[]);
+    assert_eq!(obj.get_reference_count(), 1);
+}
+
 #[itest]
 fn object_new_has_instance_id() {
     let obj = ObjPayload::new_alloc();
@@ -235,6 +355,31 @@ fn object_dynamic_free() {
         .expect_err("dynamic free() call must destroy object");
 }
 
+#[itest]
+fn object_free_if_valid() {
+    let node = Node::new_alloc();
+    let copy = node.clone();
+    assert!(node.free_if_valid());
+    assert!(!copy.free_if_valid(), "already destroyed");
+
+    let refc = RefCounted::new_gd();
+    let refc_obj: Gd<Object> = refc.clone().upcast();
+    assert!(!refc.free_if_valid(), "RefCounted is never freed");
+    assert!(
+        !refc_obj.free_if_valid(),
+        "dynamic RefCounted is never freed"
+    );
+
+    let obj = Gd::from_object(ObjPayload {});
+    let copy = obj.clone();
+    {
+        let _guard = obj.bind();
+        assert!(!copy.clone().free_if_valid(), "bound object is not freed");
+    }
+    assert!(copy.free_if_valid());
+    assert!(!obj.is_instance_valid());
+}
+
 #[itest]
 fn object_user_bind_after_free() {
     let obj = Gd::from_object(ObjPayload {});
@@ -246,6 +391,83 @@ fn object_user_bind_after_free() {
     });
 }
 
+#[itest]
+fn object_user_try_bind_conflicts() {
+    let mut obj = Gd::from_object(ObjPayload {});
+    let mut copy = obj.clone();
+
+    {
+        let _guard = obj.bind();
+        assert!(copy.try_bind().is_ok(), "multiple shared binds are allowed");
+
+        let err = copy.try_bind_mut().expect_err("bind_mut() while bound");
+        assert!(matches!(err, BindError::AlreadyBound { .. }), "{err}");
+    }
+
+    {
+        let _guard = obj.bind_mut();
+        let err = copy.try_bind().expect_err("bind() while bound mutably");
+        assert!(matches!(err, BindError::AlreadyBound { .. }), "{err}");
+    }
+
+    // No guards left.
+    assert!(copy.try_bind_mut().is_ok());
+    obj.free();
+}
+
+#[itest]
+fn object_user_with_bind_mut() {
+    let mut obj = RefcPayload::new_gd();
+    let copy = obj.clone();
+
+    let doubled = obj.with_bind_mut(|payload| {
+        payload.value = 21;
+        payload.value * 2
+    });
+    assert_eq!(doubled, 42);
+
+    // Guard has been released.
+    assert_eq!(copy.bind().value, 21);
+    assert!(copy.try_bind().is_ok());
+}
+
+#[itest]
+fn object_user_bind_map() {
+    let mut obj = RefcPayload::new_gd();
+    let mut copy = obj.clone();
+
+    {
+        let value = GdRef::map(obj.bind(), |payload| &payload.value);
+        assert_eq!(*value, 111);
+
+        // Usable as a match scrutinee; the object stays bound while the mapped guard lives.
+        match *value {
+            111 => assert!(copy.try_bind_mut().is_err()),
+            other => panic!("unexpected value {other}"),
+        }
+    }
+
+    // Dropping the mapped guard releases the borrow.
+    obj.bind_mut().value = 5;
+    assert_eq!(*GdRef::map(copy.bind(), |payload| &payload.value), 5);
+}
+
+#[itest]
+fn object_user_try_bind_after_free() {
+    let obj = Gd::from_object(ObjPayload {});
+    let id = obj.instance_id();
+    let mut copy = obj.clone();
+    obj.free();
+
+    let err = copy.try_bind().expect_err("try_bind() on dead user object");
+    assert!(matches!(err, BindError::Dead { instance_id } if instance_id == id));
+
+    let err = copy
+        .try_bind_mut()
+        .expect_err("try_bind_mut() on dead user object");
+    assert!(matches!(err, BindError::Dead { .. }), "{err}");
+}
+
 #[itest]
 fn object_user_free_during_bind() {
     let obj = Gd::from_object(ObjPayload {});
@@ -408,20 +630,33 @@ fn object_dead_eq() {
     // Destroy b1 without consuming it
     b.clone().free();
 
-    {
-        let lhs = a.clone();
-        expect_panic("Gd::eq() panics when one operand is dead", move || {
-            let _ = lhs == b;
-        });
-    }
-    {
-        let rhs = a.clone();
-        expect_panic("Gd::ne() panics when one operand is dead", move || {
-            let _ = b2 != rhs;
-        });
-    }
+    // Dead objects are compared by their cached instance ID, without panicking.
+    assert_ne!(a, b);
+    assert_ne!(b2, a);
+    assert_eq!(b, b2);
+
+    a.free();
+}
+
+#[itest]
+fn object_dead_hash() {
+    use std::collections::HashSet;
+
+    let a = Node3D::new_alloc();
+    let a2 = a.clone();
+    let b = Node3D::new_alloc();
+
+    let mut set = HashSet::new();
+    set.insert(a.clone());
+    set.insert(b.clone());
 
     a.free();
+    b.free();
+
+    // Lookup of a dead object works, based on its cached instance ID.
+    assert!(set.contains(&a2));
+    assert!(!set.insert(a2));
+    assert_eq!(set.len(), 2);
 }
 
 #[itest]
@@ -573,6 +808,21 @@ fn object_engine_upcast_ref() {
     node3d.free();
 }
 
+#[itest]
+fn object_engine_upcast_gd_ref() {
+    let node3d: Gd<Node3D> = Node3D::new_alloc();
+    let id = node3d.instance_id();
+
+    let node: &Gd<Node> = node3d.upcast_gd_ref();
+    assert_eq!(node.instance_id(), id);
+    assert_eq!(node.get_class(), GString::from("Node3D"));
+
+    let object_copy: Gd<Object> = node3d.upcast_gd_ref::<Object>().clone();
+    assert_eq!(object_copy, node3d.clone().upcast::<Object>());
+
+    node3d.free();
+}
+
 #[itest]
 fn object_engine_upcast_reflexive() {
     let node3d: Gd<Node3D> = Node3D::new_alloc();
@@ -602,6 +852,40 @@ fn object_engine_downcast() {
     node3d.free();
 }
 
+#[itest]
+fn object_engine_cast_to() {
+    let node3d: Gd<Node3D> = Node3D::new_alloc();
+    let id = node3d.instance_id();
+
+    // Upcast, then downcast back.
+    let object = node3d.cast_to::<Object>();
+    let node = object.cast_to::<Node>();
+    let node3d = node.cast_to::<Node3D>();
+    assert_eq!(node3d.instance_id(), id);
+
+    // Reflexive.
+    let node3d = node3d.cast_to::<Node3D>();
+
+    // Downcast to wrong runtime type.
+    let node = node3d.cast_to::<Node>();
+    let node = node
+        .try_cast_to::<Node2D>()
+        .expect_err("Node3D is not a Node2D");
+    expect_panic("cast_to() to wrong runtime type", || {
+        node.clone().cast_to::<Node2D>();
+    });
+
+    // Unrelated classes.
+    let node = node
+        .try_cast_to::<RefCounted>()
+        .expect_err("Node and RefCounted are unrelated");
+    expect_panic("cast_to() to unrelated class", || {
+        node.clone().cast_to::<RefCounted>();
+    });
+
+    node.free();
+}
+
 #[derive(GodotClass)]
 #[class(no_init)]
 struct CustomClassA {}
@@ -721,6 +1005,25 @@ fn object_user_upcast_ref() {
     assert_eq!(object.get_class(), GString::from("RefcPayload"));
 }
 
+#[itest]
+fn object_user_upcast_gd_ref() {
+    let obj = user_refc_instance();
+    let id = obj.instance_id();
+    assert_eq!(obj.get_reference_count(), 1);
+
+    // Borrowing upcast does not touch the reference count.
+    let refc: &Gd<RefCounted> = obj.upcast_gd_ref();
+    assert_eq!(refc.instance_id(), id);
+    assert_eq!(refc.get_class(), GString::from("RefcPayload"));
+    assert_eq!(refc.get_reference_count(), 1);
+
+    // Cloning the upcast reference yields a regular strong reference.
+    let refc_copy = refc.clone();
+    assert_eq!(obj.get_reference_count(), 2);
+    drop(refc_copy);
+    assert_eq!(obj.get_reference_count(), 1);
+}
+
 #[itest]
 fn object_user_upcast_mut() {
     let mut obj = user_refc_instance();
@@ -760,6 +1063,25 @@ fn object_user_bad_downcast() {
     assert_eq!(node3d, Err(object2));
 }
 
+#[itest]
+fn object_user_downcast_refcount() {
+    let obj = user_refc_instance();
+    let id = obj.instance_id();
+    assert_eq!(obj.get_reference_count(), 1);
+
+    // Failed cast hands back the original object, without touching the reference count.
+    let object = obj.upcast::<Object>();
+    let object = object.try_cast::<Node>().expect_err("try_cast to Node");
+    assert_eq!(object.instance_id(), id);
+
+    // Successful cast moves the reference into the new pointer.
+    let refc = object
+        .try_cast::<RefCounted>()
+        .expect("try_cast to RefCounted");
+    assert_eq!(refc.instance_id(), id);
+    assert_eq!(refc.get_reference_count(), 1);
+}
+
 #[itest]
 fn object_engine_manual_free() {
     // Tests if no panic or memory leak
@@ -5,12 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
+use godot::builtin::{dict, Color, Dictionary, GString, VariantType};
 use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
 use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
-use godot::register::property::{Export, Var};
+use godot::register::property::{Export, PhantomVar, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
 use godot::test::itest;
 
@@ -46,7 +46,7 @@ struct HasProperty {
     #[var]
     texture_val: Gd<Texture>,
 
-    #[var(get = get_texture_val, set = set_texture_val, hint = RESOURCE_TYPE, hint_string = "Texture")]
+    #[var(get = get_texture_val_rw, set = set_texture_val_rw, hint = RESOURCE_TYPE, hint_string = "Texture")]
     texture_val_rw: Option<Gd<Texture>>,
 }
 
@@ -99,31 +99,23 @@ impl HasProperty {
     }
 
     #[func]
-    pub fn get_object_val(&self) -> Variant {
-        if let Some(object_val) = self.object_val.as_ref() {
-            object_val.to_variant()
-        } else {
-            Variant::nil()
-        }
+    pub fn get_object_val(&self) -> Option<Gd<Object>> {
+        self.object_val.clone()
     }
 
     #[func]
-    pub fn set_object_val(&mut self, val: Gd<Object>) {
-        self.object_val = Some(val);
+    pub fn set_object_val(&mut self, val: Option<Gd<Object>>) {
+        self.object_val = val;
     }
 
     #[func]
-    pub fn get_texture_val_rw(&self) -> Variant {
-        if let Some(texture_val) = self.texture_val_rw.as_ref() {
-            texture_val.to_variant()
-        } else {
-            Variant::nil()
-        }
+    pub fn get_texture_val_rw(&self) -> Option<Gd<Texture>> {
+        self.texture_val_rw.clone()
     }
 
     #[func]
-    pub fn set_texture_val_rw(&mut self, val: Gd<Texture>) {
-        self.texture_val_rw = Some(val);
+    pub fn set_texture_val_rw(&mut self, val: Option<Gd<Texture>>) {
+        self.texture_val_rw = val;
     }
 }
 
@@ -246,6 +238,9 @@ struct CheckAllExports {
     #[export(range = (0.0, 10.0, 0.2, or_greater, or_less, exp, radians_as_degrees, hide_slider))]
     range_exported_with_step: f64,
 
+    #[export(range = (-1.0, 1.0, 0.1))]
+    range_exported_vector: Vector3,
+
     #[export(enum = (A = 10, B, C, D = 20))]
     enum_exported: i64,
 
@@ -364,6 +359,35 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
+#[godot(via = GString)]
+pub enum TestStringEnum {
+    Easy,
+    Normal,
+    Hard,
+}
+
+#[derive(GodotClass)]
+#[class(no_init)]
+pub struct DeriveExportString {
+    #[export]
+    pub difficulty: TestStringEnum,
+}
+
+#[itest]
+fn derive_export_string_enum() {
+    let mut class = DeriveExportString {
+        difficulty: TestStringEnum::Normal,
+    };
+    assert_eq!(class.get_difficulty(), GString::from("Normal"));
+    class.set_difficulty("Hard".into());
+    assert_eq!(class.difficulty, TestStringEnum::Hard);
+
+    let property = <TestStringEnum as Var>::var_hint();
+    assert_eq!(property.hint, PropertyHint::ENUM);
+    assert_eq!(property.hint_string, GString::from("Easy,Normal,Hard"));
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -445,6 +469,180 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportMultiline {
+    #[export(multiline)]
+    dialogue: GString,
+
+    #[export(multiline)]
+    json: String,
+}
+
+#[itest]
+fn export_multiline() {
+    let class = ExportMultiline::new_gd();
+
+    for name in ["dialogue", "json"] {
+        let property = class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap();
+
+        check_property(&property, "type", VariantType::STRING.ord());
+        check_property(&property, "hint", PropertyHint::MULTILINE_TEXT.ord());
+        check_property(&property, "hint_string", "");
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ValidatedProperty {
+    #[var(get = get_health, set = set_health)]
+    health: i32,
+}
+
+#[godot_api]
+impl ValidatedProperty {
+    #[func]
+    fn get_health(&self) -> i32 {
+        self.health
+    }
+
+    #[func]
+    fn set_health(&mut self, health: i32) {
+        self.health = health.clamp(0, 100);
+    }
+}
+
+#[itest]
+fn property_custom_accessors() {
+    let mut obj = ValidatedProperty::new_gd();
+
+    obj.set("health".into(), 150.to_variant());
+    assert_eq!(obj.get("health".into()), 100.to_variant());
+    assert_eq!(obj.bind().health, 100);
+
+    obj.set("health".into(), (-5).to_variant());
+    assert_eq!(obj.get("health".into()), 0.to_variant());
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportGroups {
+    #[export]
+    ungrouped: i32,
+
+    #[export_group(name = "Movement")]
+    #[export]
+    speed: f32,
+    #[export]
+    jump_height: f32,
+
+    #[export_group(name = "Combat", prefix = "combat_")]
+    #[export_subgroup(name = "Defense", prefix = "combat_armor")]
+    #[export]
+    combat_armor: i32,
+}
+
+#[itest]
+fn export_groups() {
+    let obj = ExportGroups::new_alloc();
+
+    let names_and_usages: Vec<(GString, i64)> = obj
+        .get_property_list()
+        .iter_shared()
+        .map(|p| (p.at("name").to(), p.at("usage").to()))
+        .skip_while(|(name, _)| name != &GString::from("ungrouped"))
+        .collect();
+
+    let group = PropertyUsageFlags::GROUP.ord() as i64;
+    let subgroup = PropertyUsageFlags::SUBGROUP.ord() as i64;
+    let export = PropertyUsageFlags::DEFAULT.ord() as i64;
+
+    let expected = [
+        ("ungrouped", export),
+        ("Movement", group),
+        ("speed", export),
+        ("jump_height", export),
+        ("Combat", group),
+        ("Defense", subgroup),
+        ("combat_armor", export),
+    ]
+    .map(|(name, usage)| (GString::from(name), usage));
+
+    assert_eq!(names_and_usages[..expected.len()], expected);
+
+    let combat = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|p| p.at("name") == "Combat".to_variant())
+        .unwrap();
+    check_property(&combat, "hint_string", "combat_");
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ComputedProperty {
+    celsius: f64,
+
+    #[var(get = get_fahrenheit, set = set_fahrenheit)]
+    fahrenheit: PhantomVar<f64>,
+
+    #[export]
+    #[var(get = is_freezing)]
+    freezing: PhantomVar<bool>,
+}
+
+#[godot_api]
+impl ComputedProperty {
+    #[func]
+    fn get_fahrenheit(&self) -> f64 {
+        self.celsius * 1.8 + 32.0
+    }
+
+    #[func]
+    fn set_fahrenheit(&mut self, fahrenheit: f64) {
+        self.celsius = (fahrenheit - 32.0) / 1.8;
+    }
+
+    #[func]
+    fn is_freezing(&self) -> bool {
+        self.celsius <= 0.0
+    }
+}
+
+#[itest]
+fn property_phantom_var() {
+    let mut obj = ComputedProperty::new_gd();
+
+    obj.set("fahrenheit".into(), 212.0.to_variant());
+    assert_eq!(obj.bind().celsius, 100.0);
+    assert_eq!(obj.get("fahrenheit".into()), 212.0.to_variant());
+    assert_eq!(obj.get("freezing".into()), false.to_variant());
+
+    obj.bind_mut().celsius = -10.0;
+    assert_eq!(obj.get("freezing".into()), true.to_variant());
+
+    let property = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "fahrenheit".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::FLOAT.ord());
+
+    let property = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "freezing".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::BOOL.ord());
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
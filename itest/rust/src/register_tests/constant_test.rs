@@ -114,6 +114,57 @@ fn cfg_removes_or_keeps_constants() {
     ));
 }
 
+#[derive(GodotClass)]
+#[class(no_init)]
+struct HasGroupedConstants {}
+
+#[godot_api]
+impl HasGroupedConstants {
+    const ENUM_NAME: &'static str = "Direction";
+    const BITFIELD_NAME: &'static str = "Layers";
+
+    #[constant(enum = Direction)]
+    const UP: i64 = 0;
+
+    #[constant(bitfield = Layers)]
+    const LAYER_WORLD: i64 = 1;
+
+    #[constant(enum = Direction)]
+    const DOWN: i64 = 1;
+
+    #[constant(bitfield = Layers)]
+    const LAYER_UI: i64 = 2;
+
+    #[constant(enum = Direction)]
+    #[cfg(any())]
+    const REMOVED: i64 = compile_error!("Removed by #[cfg]");
+}
+
+#[itest]
+fn grouped_constants_not_loose() {
+    let constants = ClassDb::singleton()
+        .class_get_integer_constant_list_ex(HasGroupedConstants::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    assert_eq!(constants.len(), 4);
+    assert!(!class_has_integer_constant::<HasGroupedConstants>(
+        "REMOVED"
+    ));
+}
+
+#[itest]
+fn constant_shadow_check() {
+    use godot::private::is_engine_constant;
+
+    assert!(is_engine_constant::<Node>("NOTIFICATION_READY"));
+    assert!(is_engine_constant::<Node>("PROCESS_MODE_INHERIT")); // enumerator
+    assert!(is_engine_constant::<Node3D>("NOTIFICATION_READY")); // inherited
+    assert!(is_engine_constant::<RefCounted>("NOTIFICATION_PREDELETE")); // from Object
+    assert!(!is_engine_constant::<RefCounted>("NOTIFICATION_READY"));
+    assert!(!is_engine_constant::<Node>("UP"));
+}
+
 #[derive(GodotClass)]
 #[class(no_init)]
 struct HasOtherConstants {}
@@ -242,3 +293,15 @@ test_enum_export!(
     #[itest]
     fn bitfield_export_correct_values() { .. }
 );
+
+test_enum_export!(
+    HasGroupedConstants, ENUM_NAME, [UP, DOWN];
+    #[itest]
+    fn enum_export_via_macro() { .. }
+);
+
+test_enum_export!(
+    HasGroupedConstants, BITFIELD_NAME, [LAYER_WORLD, LAYER_UI];
+    #[itest]
+    fn bitfield_export_via_macro() { .. }
+);
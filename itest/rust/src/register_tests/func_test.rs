@@ -33,6 +33,67 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func]
+    fn checked_div(&self, a: i64, b: i64) -> Result<i64, String> {
+        a.checked_div(b)
+            .ok_or_else(|| format!("cannot divide {a} by {b}"))
+    }
+
+    #[func]
+    fn parse_static(text: GString) -> std::result::Result<i64, std::num::ParseIntError> {
+        text.to_string().parse()
+    }
+
+    // Collections are returned as typed arrays: Array[Node] and Array[int] in GDScript.
+    #[func]
+    fn make_nodes(&self, count: i32) -> Vec<Gd<Node>> {
+        (0..count)
+            .map(|i| {
+                let mut node = Node::new_alloc();
+                node.set_name(format!("node_{i}").into());
+                node
+            })
+            .collect()
+    }
+
+    #[func]
+    fn make_numbers(&self) -> Array<i64> {
+        array![1, 2, 3]
+    }
+
+    // Tuples are passed as untyped arrays.
+    #[func]
+    fn min_max(&self, numbers: Array<i64>) -> (i64, i64) {
+        let min = numbers.iter_shared().min().unwrap_or(0);
+        let max = numbers.iter_shared().max().unwrap_or(0);
+        (min, max)
+    }
+
+    #[func]
+    fn describe_pair(&self, pair: (i32, GString)) -> GString {
+        let (number, name) = pair;
+        format!("{name}={number}").into()
+    }
+
+    // Non-nullable types in Option are passed as Variant, with nil for None.
+    #[func]
+    fn increment_optional(&self, value: Option<i64>) -> Option<i64> {
+        value.map(|value| value + 1)
+    }
+
+    #[func]
+    fn first_node_name(&self, node: Option<Gd<Node>>, fallback: Option<GString>) -> GString {
+        node.map(|node| node.get_name().into())
+            .or(fallback)
+            .unwrap_or_default()
+    }
+
+    // Leading underscore, but not an engine virtual: must not trigger the virtual name check.
+    #[func]
+    fn _internal_helper(&self) -> i32 {
+        3
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -251,6 +312,54 @@ impl IRefCounted for GdSelfObj {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct SplitApiObj {
+    value: i64,
+}
+
+#[godot_api]
+impl SplitApiObj {
+    #[func]
+    fn get_value(&self) -> i64 {
+        self.value
+    }
+}
+
+mod split_api {
+    use super::SplitApiObj;
+    use godot::prelude::*;
+
+    #[godot_api(secondary)]
+    impl SplitApiObj {
+        #[constant]
+        const STEP: i64 = 5;
+
+        #[func]
+        fn increment(&mut self) {
+            self.value += Self::STEP;
+        }
+
+        #[func(rename = make_with_value)]
+        fn with_value(value: i64) -> Gd<Self> {
+            Gd::from_object(Self { value })
+        }
+
+        #[signal]
+        fn value_changed(obj: Gd<Self>);
+    }
+
+    #[godot_api(secondary)]
+    impl SplitApiObj {
+        #[func]
+        fn is_secondary_twice(&self) -> bool {
+            true
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -296,6 +405,160 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_gd_self_not_bound_by_trampoline() {
+    let obj = GdSelfObj::new_gd();
+
+    // A `&self` or `&mut self` #[func] would need to bind the instance. With `gd_self`, the trampoline only passes on the `Gd`,
+    // so the method itself can bind mutably, even when invoked through Godot.
+    let result = obj.clone().call("takes_gd_as_equivalent".into(), &[]);
+    assert_eq!(result, true.to_variant());
+
+    // An outstanding shared guard does not conflict with the call either; the method only needs a shared bind.
+    let guard = obj.bind();
+    let result = obj
+        .clone()
+        .call("takes_gd_as_self_no_return_type".into(), &[]);
+    assert_eq!(result, Variant::nil());
+    drop(guard);
+}
+
+#[itest]
+fn func_result_return() {
+    let mut obj = FuncObj::new_gd();
+
+    let result = obj.call("checked_div".into(), &[7.to_variant(), 2.to_variant()]);
+    assert_eq!(result, 3.to_variant());
+
+    let call_error = obj
+        .try_call("checked_div".into(), &[7.to_variant(), 0.to_variant()])
+        .expect_err("Err return should cause a call error");
+
+    assert_eq!(
+        call_error.to_string(),
+        "godot-rust function call failed: Object::call(&\"checked_div\", [va] 7, [va] 0)\
+        \n  Source: FuncObj::checked_div()\
+        \n    Reason: function returned Err: cannot divide 7 by 0"
+    );
+}
+
+#[itest]
+fn func_result_return_static() {
+    let mut obj = FuncObj::new_gd();
+
+    // Godot only sees the `Ok` type.
+    let result = obj.call("parse_static".into(), &[GString::from("42").to_variant()]);
+    assert_eq!(result, 42.to_variant());
+
+    let result = obj.try_call(
+        "parse_static".into(),
+        &[GString::from("forty-two").to_variant()],
+    );
+    assert!(result.is_err());
+}
+
+#[itest]
+fn func_secondary_impl_blocks() {
+    assert!(class_has_method::<SplitApiObj>("get_value"));
+    assert!(class_has_method::<SplitApiObj>("increment"));
+    assert!(class_has_method::<SplitApiObj>("make_with_value"));
+    assert!(class_has_method::<SplitApiObj>("is_secondary_twice"));
+    assert!(class_has_signal::<SplitApiObj>("value_changed"));
+    assert!(ClassDb::singleton()
+        .class_has_integer_constant(SplitApiObj::class_name().to_string_name(), "STEP".into()));
+
+    let mut obj = SplitApiObj::new_gd();
+    obj.call("increment".into(), &[]);
+    assert_eq!(obj.call("get_value".into(), &[]), 5.to_variant());
+
+    let made = obj.call("make_with_value".into(), &[7.to_variant()]);
+    assert_eq!(made.to::<Gd<SplitApiObj>>().bind().value, 7);
+}
+
+#[itest]
+fn func_typed_array_return() {
+    let mut obj = FuncObj::new_gd();
+
+    // Conversion to Array<T> checks the runtime element type, so it fails for untyped arrays.
+    let result = obj.call("make_nodes".into(), &[2.to_variant()]);
+    assert!(result.try_to::<Array<Gd<Node3D>>>().is_err());
+
+    let nodes = result
+        .try_to::<Array<Gd<Node>>>()
+        .expect("Vec<Gd<Node>> is returned as Array[Node]");
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes.at(1).get_name(), StringName::from("node_1"));
+
+    for node in nodes.iter_shared() {
+        node.free();
+    }
+
+    let result = obj.call("make_numbers".into(), &[]);
+    assert!(result.try_to::<VariantArray>().is_err());
+    assert_eq!(result.to::<Array<i64>>(), array![1, 2, 3]);
+}
+
+#[itest]
+fn func_tuple_return_and_param() {
+    let mut obj = FuncObj::new_gd();
+
+    let result = obj.call("min_max".into(), &[array![3, -1, 7].to_variant()]);
+    let array = result.to::<VariantArray>();
+    assert_eq!(array, varray![-1, 7]);
+    assert_eq!(result.to::<(i64, i64)>(), (-1, 7));
+
+    let result = obj.call("describe_pair".into(), &[(5, "five").to_variant()]);
+    assert_eq!(result, "five=5".to_variant());
+
+    // Wrong arity fails the call.
+    let result = obj.try_call("describe_pair".into(), &[varray![5].to_variant()]);
+    assert!(result.is_err());
+}
+
+#[itest]
+fn func_option_param_and_return() {
+    let mut obj = FuncObj::new_gd();
+
+    let result = obj.call("increment_optional".into(), &[41.to_variant()]);
+    assert_eq!(result, 42.to_variant());
+
+    let result = obj.call("increment_optional".into(), &[Variant::nil()]);
+    assert!(result.is_nil());
+
+    // Values of other types are not mapped to None.
+    let result = obj.try_call("increment_optional".into(), &["41".to_variant()]);
+    assert!(result.is_err());
+
+    // Option<Gd<T>> keeps the object type and can be mixed with other Option parameters.
+    let mut node = Node::new_alloc();
+    node.set_name("named".into());
+    let result = obj.call(
+        "first_node_name".into(),
+        &[node.to_variant(), Variant::nil()],
+    );
+    assert_eq!(result, "named".to_variant());
+
+    let result = obj.call(
+        "first_node_name".into(),
+        &[Variant::nil(), "fallback".to_variant()],
+    );
+    assert_eq!(result, "fallback".to_variant());
+    node.free();
+}
+
+#[itest]
+fn func_virtual_name_check() {
+    use godot::private::is_engine_virtual_method;
+
+    assert!(is_engine_virtual_method::<Node>("_process"));
+    assert!(is_engine_virtual_method::<Node3D>("_ready")); // inherited
+    assert!(is_engine_virtual_method::<RefCounted>("_to_string")); // special virtual
+    assert!(!is_engine_virtual_method::<Node>("_internal_helper"));
+    assert!(!is_engine_virtual_method::<RefCounted>("_process"));
+
+    assert!(class_has_method::<FuncObj>("_internal_helper"));
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
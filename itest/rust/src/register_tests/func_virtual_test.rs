@@ -118,6 +118,36 @@ fn func_virtual_stateful() {
     assert_eq!(retrieved, variant);
 }
 
+#[itest]
+fn func_virtual_super_call() {
+    // Without script, the `_` prefixed name reaches the Rust default.
+    let mut object = VirtualScriptCalls::new_gd();
+    let result = object.call("_greet_lang".into(), &[5.to_variant()]);
+    assert_eq!(result, "Rust#5".to_variant());
+
+    // With script calling super(), the Rust default is invoked exactly once, without recursing into the script.
+    let code = r#"
+extends VirtualScriptCalls
+
+func _greet_lang(i: int) -> String:
+    return str("GDScript+", super(i))
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code.into());
+    script.reload();
+    object.set_script(script.to_variant());
+
+    let expected = GString::from("GDScript+Rust#5");
+    assert_eq!(object.bind().greet_lang(5), expected);
+
+    let result = object.call("_greet_lang".into(), &[5.to_variant()]);
+    assert_eq!(result, expected.to_variant());
+
+    let result = object.call("greet_lang".into(), &[5.to_variant()]);
+    assert_eq!(result, expected.to_variant());
+}
+
 fn make_script() -> Gd<GDScript> {
     let code = r#"
 extends VirtualScriptCalls
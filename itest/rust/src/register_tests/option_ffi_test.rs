@@ -5,8 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::builtin::Variant;
 use godot::classes::{Node, Object, RefCounted};
-use godot::meta::GodotType;
+use godot::meta::{GodotType, ToGodot};
 use godot::obj::{Gd, NewAlloc, NewGd, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::GodotFfi;
@@ -39,6 +40,30 @@ fn option_none_sys_conversion() {
     assert_eq!(v2, v);
 }
 
+#[itest]
+fn option_func_param_varcall() {
+    let mut ffi = OptionFfiTest::new_gd();
+    let node = Node::new_alloc();
+
+    let result = ffi.call("accept_option_node_none".into(), &[Variant::nil()]);
+    assert_eq!(result, true.to_variant());
+
+    let result = ffi.call("accept_option_node_some".into(), &[node.to_variant()]);
+    assert_eq!(result, true.to_variant());
+
+    let result = ffi.call("mirror_option_node".into(), &[Variant::nil()]);
+    assert!(result.is_nil());
+
+    // Non-nullable parameters reject null.
+    ffi.try_call("accept_node".into(), &[Variant::nil()])
+        .expect_err("null passed to Gd<T> parameter");
+
+    let result = ffi.call("accept_node".into(), &[node.to_variant()]);
+    assert_eq!(result, true.to_variant());
+
+    node.free();
+}
+
 #[derive(GodotClass, Debug)]
 #[class(base = RefCounted, init)]
 struct OptionFfiTest;
@@ -94,6 +119,11 @@ impl OptionFfiTest {
     fn mirror_option_node(&self, value: Option<Gd<Node>>) -> Option<Gd<Node>> {
         value
     }
+
+    #[func]
+    fn accept_node(&self, value: Gd<Node>) -> bool {
+        value.is_instance_valid()
+    }
 }
 
 #[derive(GodotClass)]
@@ -7,6 +7,8 @@
 
 use godot::prelude::*;
 
+use crate::framework::itest;
+
 #[derive(GodotClass)]
 #[class(init)]
 struct WithInitDefaults {
@@ -21,3 +23,29 @@ struct WithInitDefaults {
     #[init(val = -42)]
     expr_int: i64,
 }
+
+// Deliberately no Default impl: #[init(val)] must not require one.
+struct NoDefault(i64);
+
+fn initial_health() -> i32 {
+    100
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct WithInitNonDefault {
+    #[init(val = NoDefault(7))]
+    no_default: NoDefault,
+
+    #[init(val = initial_health())]
+    health: i32,
+}
+
+#[itest]
+fn init_val_without_default() {
+    let obj = WithInitNonDefault::new_gd();
+
+    let guard = obj.bind();
+    assert_eq!(guard.no_default.0, 7);
+    assert_eq!(guard.health, 100);
+}